@@ -1,8 +1,32 @@
 use ephemeral_rollups_sdk::consts::DELEGATION_PROGRAM_ID;
+use ephemeral_rollups_wrapper::state::validator_registry::ValidatorStatus;
+use solana_program_test::ProgramTest;
+use solana_sdk::account::Account;
+use solana_sdk::bpf_loader_upgradeable;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::keypair_from_seed;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
 use solana_toolbox_endpoint::ToolboxEndpoint;
 
+use crate::api::program_wrapper::process_admin_set_validator::process_admin_set_validator;
+use crate::api::program_wrapper::process_config_init::process_config_init;
+use crate::api::program_wrapper::process_validator_registry_init::process_validator_registry_init;
+
+pub fn localnet_config_admin() -> Keypair {
+    keypair_from_seed(b"ephemeral-rollups-localnet-config-admin").unwrap()
+}
+
+pub fn localnet_validator() -> Pubkey {
+    keypair_from_seed(b"ephemeral-rollups-localnet-validator").unwrap().pubkey()
+}
+
 pub async fn create_localnet_toolbox_endpoint() -> ToolboxEndpoint {
-    ToolboxEndpoint::new_program_test_with_preloaded_programs(&[
+    let admin = localnet_config_admin();
+
+    let mut program_test = ProgramTest::default();
+    program_test.prefer_bpf(true);
+    for (program_id, program_name) in [
         (DELEGATION_PROGRAM_ID, "./binaries/dlp"),
         (mpl_bubblegum::ID, "./binaries/bubblegum"),
         (spl_noop::ID, "./binaries/noop"),
@@ -11,6 +35,57 @@ pub async fn create_localnet_toolbox_endpoint() -> ToolboxEndpoint {
             ephemeral_rollups_wrapper::ID,
             "../target/deploy/ephemeral_rollups_wrapper",
         ),
-    ])
+    ] {
+        program_test.add_program(program_name, program_id, None);
+    }
+
+    // Only the program's upgrade authority can create the config, so give the
+    // preloaded program the ProgramData of an upgradeable deployment (tag 3,
+    // deploy slot, upgrade authority) naming the localnet admin
+    let mut program_data = Vec::new();
+    program_data.extend_from_slice(&3u32.to_le_bytes());
+    program_data.extend_from_slice(&0u64.to_le_bytes());
+    program_data.push(1);
+    program_data.extend_from_slice(admin.pubkey().as_ref());
+    program_test.add_account(
+        Pubkey::find_program_address(
+            &[ephemeral_rollups_wrapper::ID.as_ref()],
+            &bpf_loader_upgradeable::ID,
+        )
+        .0,
+        Account {
+            lamports: 1_000_000_000,
+            data: program_data,
+            owner: bpf_loader_upgradeable::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut toolbox_endpoint =
+        ToolboxEndpoint::from(program_test.start_with_context().await);
+
+    // Every instruction reads the global config, so it must exist first
+    toolbox_endpoint
+        .process_airdrop(&admin.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    process_config_init(&mut toolbox_endpoint, &admin, &admin).await.unwrap();
+
+    // Escrows can only be created for validators approved by the admin
+    process_validator_registry_init(&mut toolbox_endpoint, &admin, &admin)
+        .await
+        .unwrap();
+    process_admin_set_validator(
+        &mut toolbox_endpoint,
+        &admin,
+        &admin,
+        &localnet_validator(),
+        "localnet",
+        ValidatorStatus::Active,
+    )
     .await
+    .unwrap();
+
+    toolbox_endpoint
 }
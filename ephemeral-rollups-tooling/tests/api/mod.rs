@@ -1,4 +1,5 @@
 pub mod create_localnet_toolbox_endpoint;
 pub mod program_bubblegum;
 pub mod program_delegation;
+pub mod program_token_2022;
 pub mod program_wrapper;
@@ -0,0 +1,3 @@
+pub mod process_account_init;
+pub mod process_mint_init_with_permanent_delegate;
+pub mod process_mint_to;
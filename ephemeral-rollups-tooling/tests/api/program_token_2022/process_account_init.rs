@@ -0,0 +1,47 @@
+use ephemeral_rollups_wrapper::util::token::TOKEN_2022_PROGRAM_ID;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction::create_account;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+use spl_token::instruction::TokenInstruction;
+use spl_token::state::Account;
+
+pub async fn process_account_init(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    token_account: &Keypair,
+    token_mint: &Pubkey,
+    owner: &Pubkey,
+) -> Result<(), ToolboxEndpointError> {
+    let create_account_instruction = create_account(
+        &payer.pubkey(),
+        &token_account.pubkey(),
+        toolbox_endpoint.get_sysvar_rent().await?.minimum_balance(Account::LEN),
+        Account::LEN as u64,
+        &TOKEN_2022_PROGRAM_ID,
+    );
+
+    let account_init_instruction = Instruction {
+        program_id: TOKEN_2022_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(token_account.pubkey(), false),
+            AccountMeta::new_readonly(*token_mint, false),
+        ],
+        data: TokenInstruction::InitializeAccount3 { owner: *owner }.pack(),
+    };
+
+    toolbox_endpoint
+        .process_instructions_with_signers(
+            &[create_account_instruction, account_init_instruction],
+            payer,
+            &[token_account],
+        )
+        .await?;
+
+    Ok(())
+}
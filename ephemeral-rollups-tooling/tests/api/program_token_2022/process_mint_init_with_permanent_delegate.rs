@@ -0,0 +1,69 @@
+use ephemeral_rollups_wrapper::util::token::TOKEN_2022_PROGRAM_ID;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::program_option::COption;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction::create_account;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+use spl_token::instruction::TokenInstruction;
+
+// Token-2022 mint layout: the base mint padded to the size of a token account,
+// the account type, then the PermanentDelegate extension (type 12) as TLV
+const MINT_WITH_PERMANENT_DELEGATE_LEN: usize = 165 + 1 + 2 + 2 + 32;
+const INITIALIZE_PERMANENT_DELEGATE_TAG: u8 = 35;
+
+pub async fn process_mint_init_with_permanent_delegate(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    token_mint: &Keypair,
+    mint_authority: &Pubkey,
+    permanent_delegate: &Pubkey,
+    decimals: u8,
+) -> Result<(), ToolboxEndpointError> {
+    let create_account_instruction = create_account(
+        &payer.pubkey(),
+        &token_mint.pubkey(),
+        toolbox_endpoint
+            .get_sysvar_rent()
+            .await?
+            .minimum_balance(MINT_WITH_PERMANENT_DELEGATE_LEN),
+        MINT_WITH_PERMANENT_DELEGATE_LEN as u64,
+        &TOKEN_2022_PROGRAM_ID,
+    );
+
+    let mut permanent_delegate_data = vec![INITIALIZE_PERMANENT_DELEGATE_TAG];
+    permanent_delegate_data.extend_from_slice(permanent_delegate.as_ref());
+    let permanent_delegate_instruction = Instruction {
+        program_id: TOKEN_2022_PROGRAM_ID,
+        accounts: vec![AccountMeta::new(token_mint.pubkey(), false)],
+        data: permanent_delegate_data,
+    };
+
+    let mint_init_instruction = Instruction {
+        program_id: TOKEN_2022_PROGRAM_ID,
+        accounts: vec![AccountMeta::new(token_mint.pubkey(), false)],
+        data: TokenInstruction::InitializeMint2 {
+            decimals,
+            mint_authority: *mint_authority,
+            freeze_authority: COption::None,
+        }
+        .pack(),
+    };
+
+    toolbox_endpoint
+        .process_instructions_with_signers(
+            &[
+                create_account_instruction,
+                permanent_delegate_instruction,
+                mint_init_instruction,
+            ],
+            payer,
+            &[token_mint],
+        )
+        .await?;
+
+    Ok(())
+}
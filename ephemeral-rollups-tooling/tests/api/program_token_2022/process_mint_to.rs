@@ -0,0 +1,36 @@
+use ephemeral_rollups_wrapper::util::token::TOKEN_2022_PROGRAM_ID;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+use spl_token::instruction::TokenInstruction;
+
+pub async fn process_mint_to(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    token_mint: &Pubkey,
+    mint_authority: &Keypair,
+    token_account: &Pubkey,
+    amount: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let mint_to_instruction = Instruction {
+        program_id: TOKEN_2022_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*token_mint, false),
+            AccountMeta::new(*token_account, false),
+            AccountMeta::new_readonly(mint_authority.pubkey(), true),
+        ],
+        data: TokenInstruction::MintTo { amount }.pack(),
+    };
+    toolbox_endpoint
+        .process_instruction_with_signers(
+            mint_to_instruction,
+            payer,
+            &[mint_authority],
+        )
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_transfer_eth;
+use libsecp256k1::Message;
+use libsecp256k1::PublicKey;
+use libsecp256k1::SecretKey;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::keccak;
+
+pub fn get_eth_address(secret_key: &SecretKey) -> [u8; 20] {
+    let public_key = PublicKey::from_secret_key(secret_key).serialize();
+    let hash = keccak::hash(&public_key[1..]).to_bytes();
+    <[u8; 20]>::try_from(&hash[12..]).unwrap()
+}
+
+// What an Ethereum wallet would produce with personal_sign, wrapped in the
+// precompile IX that must be placed first in the transaction
+pub fn get_eth_signed_instruction(
+    secret_key: &SecretKey,
+    message: &[u8],
+) -> Instruction {
+    let (signature, recovery_id) = libsecp256k1::sign(
+        &Message::parse(&keccak::hash(message).to_bytes()),
+        secret_key,
+    );
+    token_escrow_transfer_eth::secp256k1_instruction(
+        &get_eth_address(secret_key),
+        &signature.serialize(),
+        recovery_id.serialize(),
+        message,
+        0,
+    )
+}
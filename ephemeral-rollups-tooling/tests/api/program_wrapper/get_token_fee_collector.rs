@@ -0,0 +1,23 @@
+use ephemeral_rollups_wrapper::state::token_fee::TokenFee;
+use solana_sdk::pubkey::Pubkey;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn get_token_fee_collector(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+) -> Result<(Pubkey, u64), ToolboxEndpointError> {
+    let token_fee_pda = TokenFee::generate_pda(
+        validator,
+        token_mint,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    Ok(toolbox_endpoint
+        .get_account_data_borsh_deserialized::<TokenFee>(&token_fee_pda)
+        .await?
+        .map(|token_fee| {
+            (token_fee.collector_authority, token_fee.collector_slot)
+        })
+        .unwrap_or_default())
+}
@@ -1,16 +1,76 @@
+pub mod get_eth_wallet;
+pub mod get_token_fee_collector;
+pub mod process_admin_set_config;
+pub mod process_admin_set_token_fee;
+pub mod process_admin_set_validator;
 pub mod process_bubblegum_escrow_delegate;
 pub mod process_bubblegum_escrow_deposit;
 pub mod process_bubblegum_escrow_transfer;
 pub mod process_bubblegum_escrow_undelegate;
 pub mod process_bubblegum_escrow_withdraw;
+pub mod process_config_init;
 pub mod process_lamport_escrow_claim;
 pub mod process_lamport_escrow_create;
 pub mod process_lamport_escrow_delegate;
+pub mod process_lamport_escrow_realloc;
+pub mod process_lamport_escrow_set_spending_limit;
 pub mod process_lamport_escrow_undelegate;
+pub mod process_market_create;
+pub mod process_market_delegate;
+pub mod process_market_order_cancel;
+pub mod process_market_order_match;
+pub mod process_market_order_place;
+pub mod process_market_undelegate;
+pub mod process_multisig_create;
+pub mod process_nonce_account_create;
+pub mod process_nonce_account_delegate;
+pub mod process_nonce_account_delegate_eth;
+pub mod process_nonce_account_undelegate;
+pub mod process_nonce_account_undelegate_eth;
+pub mod process_pool_add_liquidity;
+pub mod process_pool_create;
+pub mod process_pool_delegate;
+pub mod process_pool_remove_liquidity;
+pub mod process_pool_swap;
+pub mod process_pool_undelegate;
+pub mod process_recovery_close;
+pub mod process_recovery_execute;
+pub mod process_recovery_propose;
+pub mod process_recovery_set;
+pub mod process_recovery_veto;
+pub mod process_split_config_create;
+pub mod process_token_escrow_burn;
+pub mod process_token_escrow_clawback;
 pub mod process_token_escrow_create;
 pub mod process_token_escrow_delegate;
+pub mod process_token_escrow_delegate_eth;
 pub mod process_token_escrow_deposit;
+pub mod process_token_escrow_deposit_delegated;
+pub mod process_token_escrow_freeze_undelegate;
+pub mod process_token_escrow_merge;
+pub mod process_token_escrow_migrate_validator;
+pub mod process_token_escrow_mint_to;
+pub mod process_token_escrow_realloc;
+pub mod process_token_escrow_set_frozen;
+pub mod process_token_escrow_set_spending_limit;
+pub mod process_token_escrow_split;
+pub mod process_token_escrow_swap;
+pub mod process_token_escrow_swap_signed;
 pub mod process_token_escrow_transfer;
+pub mod process_token_escrow_transfer_batch;
+pub mod process_token_escrow_transfer_create;
+pub mod process_token_escrow_transfer_eth;
+pub mod process_token_escrow_transfer_multisig;
+pub mod process_token_escrow_transfer_signed;
+pub mod process_token_escrow_transfer_split;
 pub mod process_token_escrow_undelegate;
+pub mod process_token_escrow_undelegate_eth;
 pub mod process_token_escrow_withdraw;
+pub mod process_token_escrow_withdraw_create;
+pub mod process_token_escrow_withdraw_eth;
+pub mod process_token_vault_audit;
 pub mod process_token_vault_init;
+pub mod process_token_vault_state_init;
+pub mod process_token_vesting_create;
+pub mod process_token_vesting_release;
+pub mod process_validator_registry_init;
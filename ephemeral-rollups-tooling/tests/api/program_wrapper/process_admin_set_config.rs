@@ -0,0 +1,26 @@
+use ephemeral_rollups_wrapper::instruction::admin_set_config;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_admin_set_config(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    admin: &Keypair,
+    new_admin: &Pubkey,
+    paused: u64,
+    allowed_token_programs: &[Pubkey],
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = admin_set_config::instruction(
+        &admin.pubkey(),
+        new_admin,
+        paused,
+        allowed_token_programs,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[admin])
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use ephemeral_rollups_wrapper::instruction::admin_set_token_fee;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_admin_set_token_fee(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    admin: &Keypair,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    fee_basis_points: u16,
+    fee_minimum: u64,
+    collector_authority: &Pubkey,
+    collector_slot: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = admin_set_token_fee::instruction(
+        &payer.pubkey(),
+        &admin.pubkey(),
+        validator,
+        token_mint,
+        fee_basis_points,
+        fee_minimum,
+        collector_authority,
+        collector_slot,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[admin])
+        .await?;
+    Ok(())
+}
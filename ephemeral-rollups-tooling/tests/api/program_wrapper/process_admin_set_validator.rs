@@ -0,0 +1,27 @@
+use ephemeral_rollups_wrapper::instruction::admin_set_validator;
+use ephemeral_rollups_wrapper::state::validator_registry::ValidatorStatus;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_admin_set_validator(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    admin: &Keypair,
+    validator: &Pubkey,
+    label: &str,
+    status: ValidatorStatus,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = admin_set_validator::instruction(
+        &admin.pubkey(),
+        validator,
+        label,
+        status,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[admin])
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use ephemeral_rollups_wrapper::instruction::config_init;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_config_init(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    admin: &Keypair,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction =
+        config_init::instruction(&payer.pubkey(), &admin.pubkey());
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[admin])
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use ephemeral_rollups_wrapper::instruction::lamport_escrow_set_spending_limit;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_lamport_escrow_set_spending_limit(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    authority: &Keypair,
+    validator: &Pubkey,
+    slot: u64,
+    max_per_transaction: u64,
+    max_per_window: u64,
+    window_seconds: i64,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = lamport_escrow_set_spending_limit::instruction(
+        &authority.pubkey(),
+        validator,
+        slot,
+        max_per_transaction,
+        max_per_window,
+        window_seconds,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[authority])
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use ephemeral_rollups_wrapper::instruction::market_create;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_market_create(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    admin: &Keypair,
+    authority: &Keypair,
+    validator: &Pubkey,
+    base_token_mint: &Pubkey,
+    quote_token_mint: &Pubkey,
+    base_lot_size: u64,
+    min_order_lots: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = market_create::instruction(
+        &payer.pubkey(),
+        &admin.pubkey(),
+        &authority.pubkey(),
+        validator,
+        base_token_mint,
+        quote_token_mint,
+        base_lot_size,
+        min_order_lots,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(
+            instruction,
+            payer,
+            &[admin, authority],
+        )
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use ephemeral_rollups_wrapper::instruction::market_delegate;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_market_delegate(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    authority: &Keypair,
+    validator: &Pubkey,
+    base_token_mint: &Pubkey,
+    quote_token_mint: &Pubkey,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = market_delegate::instruction(
+        &payer.pubkey(),
+        &authority.pubkey(),
+        validator,
+        base_token_mint,
+        quote_token_mint,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[authority])
+        .await?;
+    Ok(())
+}
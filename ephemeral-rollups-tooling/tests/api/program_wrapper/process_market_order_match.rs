@@ -0,0 +1,35 @@
+use ephemeral_rollups_wrapper::instruction::market_order_match;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_market_order_match(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    bid_maker: &Pubkey,
+    ask_maker: &Pubkey,
+    validator: &Pubkey,
+    base_token_mint: &Pubkey,
+    quote_token_mint: &Pubkey,
+    bid_maker_base_slot: u64,
+    bid_maker_quote_slot: u64,
+    ask_maker_quote_slot: u64,
+    bid_order_id: u64,
+    ask_order_id: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = market_order_match::instruction(
+        bid_maker,
+        ask_maker,
+        validator,
+        base_token_mint,
+        quote_token_mint,
+        bid_maker_base_slot,
+        bid_maker_quote_slot,
+        ask_maker_quote_slot,
+        bid_order_id,
+        ask_order_id,
+    );
+    toolbox_endpoint.process_instruction(instruction, payer).await?;
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use ephemeral_rollups_wrapper::instruction::market_order_place;
+use ephemeral_rollups_wrapper::state::market::MarketOrderSide;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_market_order_place(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    maker: &Keypair,
+    validator: &Pubkey,
+    base_token_mint: &Pubkey,
+    quote_token_mint: &Pubkey,
+    maker_base_slot: u64,
+    maker_quote_slot: u64,
+    side: MarketOrderSide,
+    price: u64,
+    quantity: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = market_order_place::instruction(
+        &maker.pubkey(),
+        validator,
+        base_token_mint,
+        quote_token_mint,
+        maker_base_slot,
+        maker_quote_slot,
+        side,
+        price,
+        quantity,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[maker])
+        .await?;
+    Ok(())
+}
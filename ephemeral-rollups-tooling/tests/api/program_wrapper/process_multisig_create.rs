@@ -0,0 +1,27 @@
+use ephemeral_rollups_wrapper::instruction::multisig_create;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_multisig_create(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    creator: &Keypair,
+    slot: u64,
+    threshold: u8,
+    signers: &[Pubkey],
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = multisig_create::instruction(
+        &payer.pubkey(),
+        &creator.pubkey(),
+        slot,
+        threshold,
+        signers,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[creator])
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use ephemeral_rollups_wrapper::instruction::nonce_account_create;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_nonce_account_create(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    authority: &Pubkey,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction =
+        nonce_account_create::instruction(&payer.pubkey(), authority);
+    toolbox_endpoint.process_instruction(instruction, payer).await?;
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use ephemeral_rollups_wrapper::instruction::nonce_account_delegate;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_nonce_account_delegate(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    authority: &Keypair,
+    validator: &Pubkey,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = nonce_account_delegate::instruction(
+        &payer.pubkey(),
+        &authority.pubkey(),
+        validator,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[authority])
+        .await?;
+    Ok(())
+}
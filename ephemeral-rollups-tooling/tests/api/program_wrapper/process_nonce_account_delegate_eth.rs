@@ -0,0 +1,41 @@
+use ephemeral_rollups_wrapper::instruction::nonce_account_delegate_eth;
+use libsecp256k1::SecretKey;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::program_wrapper::get_eth_wallet::get_eth_address;
+use crate::api::program_wrapper::get_eth_wallet::get_eth_signed_instruction;
+
+pub async fn process_nonce_account_delegate_eth(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    relayer: &Keypair,
+    eth_wallet: &SecretKey,
+    validator: &Pubkey,
+    nonce: u64,
+    expiry: i64,
+) -> Result<(), ToolboxEndpointError> {
+    // The Ethereum wallet only signs the intent off-chain, the relayer pays
+    let eth_address = get_eth_address(eth_wallet);
+    let message =
+        nonce_account_delegate_eth::message(&eth_address, nonce, expiry);
+    let secp256k1_instruction =
+        get_eth_signed_instruction(eth_wallet, &message);
+    let instruction = nonce_account_delegate_eth::instruction(
+        &relayer.pubkey(),
+        &eth_address,
+        validator,
+        nonce,
+        expiry,
+    );
+    toolbox_endpoint
+        .process_instructions_with_signers(
+            &[secp256k1_instruction, instruction],
+            relayer,
+            &[],
+        )
+        .await?;
+    Ok(())
+}
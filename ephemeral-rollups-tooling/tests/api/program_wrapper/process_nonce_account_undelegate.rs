@@ -0,0 +1,20 @@
+use ephemeral_rollups_wrapper::instruction::nonce_account_undelegate;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_nonce_account_undelegate(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    authority: &Keypair,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = nonce_account_undelegate::instruction(
+        &payer.pubkey(),
+        &authority.pubkey(),
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[authority])
+        .await?;
+    Ok(())
+}
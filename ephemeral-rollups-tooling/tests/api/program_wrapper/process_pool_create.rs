@@ -0,0 +1,37 @@
+use ephemeral_rollups_wrapper::instruction::pool_create;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_pool_create(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    admin: &Keypair,
+    authority: &Keypair,
+    validator: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    fee_basis_points: u16,
+    min_position_shares: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = pool_create::instruction(
+        &payer.pubkey(),
+        &admin.pubkey(),
+        &authority.pubkey(),
+        validator,
+        token_mint_a,
+        token_mint_b,
+        fee_basis_points,
+        min_position_shares,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(
+            instruction,
+            payer,
+            &[admin, authority],
+        )
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use ephemeral_rollups_wrapper::instruction::pool_delegate;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_pool_delegate(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    authority: &Keypair,
+    validator: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = pool_delegate::instruction(
+        &payer.pubkey(),
+        &authority.pubkey(),
+        validator,
+        token_mint_a,
+        token_mint_b,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[authority])
+        .await?;
+    Ok(())
+}
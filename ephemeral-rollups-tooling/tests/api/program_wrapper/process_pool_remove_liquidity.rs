@@ -0,0 +1,34 @@
+use ephemeral_rollups_wrapper::instruction::pool_remove_liquidity;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_pool_remove_liquidity(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    provider: &Keypair,
+    validator: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    provider_slot: u64,
+    shares: u64,
+    minimum_amount_a: u64,
+    minimum_amount_b: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = pool_remove_liquidity::instruction(
+        &provider.pubkey(),
+        validator,
+        token_mint_a,
+        token_mint_b,
+        provider_slot,
+        shares,
+        minimum_amount_a,
+        minimum_amount_b,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[provider])
+        .await?;
+    Ok(())
+}
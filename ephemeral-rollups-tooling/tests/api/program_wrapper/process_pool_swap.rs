@@ -0,0 +1,36 @@
+use ephemeral_rollups_wrapper::instruction::pool_swap;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_pool_swap(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    trader: &Keypair,
+    validator: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    source_slot: u64,
+    destination_slot: u64,
+    a_to_b: bool,
+    amount_in: u64,
+    minimum_amount_out: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = pool_swap::instruction(
+        &trader.pubkey(),
+        validator,
+        token_mint_a,
+        token_mint_b,
+        source_slot,
+        destination_slot,
+        a_to_b,
+        amount_in,
+        minimum_amount_out,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[trader])
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use ephemeral_rollups_wrapper::instruction::recovery_close;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_recovery_close(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    authority: &Pubkey,
+    proposed_authority: &Keypair,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction =
+        recovery_close::instruction(authority, &proposed_authority.pubkey());
+    toolbox_endpoint
+        .process_instruction_with_signers(
+            instruction,
+            payer,
+            &[proposed_authority],
+        )
+        .await?;
+    Ok(())
+}
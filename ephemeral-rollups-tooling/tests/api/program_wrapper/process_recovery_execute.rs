@@ -0,0 +1,28 @@
+use ephemeral_rollups_wrapper::instruction::recovery_execute;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_recovery_execute(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    authority: &Pubkey,
+    proposed_authority: &Keypair,
+    token_escrows: &[(Pubkey, Pubkey, u64)],
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = recovery_execute::instruction(
+        authority,
+        &proposed_authority.pubkey(),
+        token_escrows,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(
+            instruction,
+            payer,
+            &[proposed_authority],
+        )
+        .await?;
+    Ok(())
+}
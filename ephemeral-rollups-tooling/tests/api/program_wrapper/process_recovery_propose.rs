@@ -0,0 +1,24 @@
+use ephemeral_rollups_wrapper::instruction::recovery_propose;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_recovery_propose(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    guardians: &[&Keypair],
+    authority: &Pubkey,
+    proposed_authority: &Pubkey,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = recovery_propose::instruction(
+        &guardians.iter().map(|guardian| guardian.pubkey()).collect::<Vec<_>>(),
+        authority,
+        proposed_authority,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, guardians)
+        .await?;
+    Ok(())
+}
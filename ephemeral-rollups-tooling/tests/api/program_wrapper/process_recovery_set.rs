@@ -0,0 +1,27 @@
+use ephemeral_rollups_wrapper::instruction::recovery_set;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_recovery_set(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    authority: &Keypair,
+    threshold: u8,
+    guardians: &[Pubkey],
+    delay_seconds: i64,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = recovery_set::instruction(
+        &payer.pubkey(),
+        &authority.pubkey(),
+        threshold,
+        guardians,
+        delay_seconds,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[authority])
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use ephemeral_rollups_wrapper::instruction::recovery_veto;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_recovery_veto(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    authority: &Keypair,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = recovery_veto::instruction(&authority.pubkey());
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[authority])
+        .await?;
+    Ok(())
+}
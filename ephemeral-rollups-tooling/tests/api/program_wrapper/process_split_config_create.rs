@@ -0,0 +1,25 @@
+use ephemeral_rollups_wrapper::instruction::split_config_create;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_split_config_create(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    creator: &Keypair,
+    slot: u64,
+    recipients: &[(Pubkey, u64, u16)],
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = split_config_create::instruction(
+        &payer.pubkey(),
+        &creator.pubkey(),
+        slot,
+        recipients,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[creator])
+        .await?;
+    Ok(())
+}
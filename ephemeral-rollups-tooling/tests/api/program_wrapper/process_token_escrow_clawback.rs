@@ -0,0 +1,36 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_clawback;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_token_escrow_clawback(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    permanent_delegate: &Keypair,
+    authority: &Pubkey,
+    destination_token_account: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    slot: u64,
+    amount: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = token_escrow_clawback::instruction(
+        &permanent_delegate.pubkey(),
+        authority,
+        destination_token_account,
+        validator,
+        token_mint,
+        slot,
+        amount,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(
+            instruction,
+            payer,
+            &[permanent_delegate],
+        )
+        .await?;
+    Ok(())
+}
@@ -16,12 +16,15 @@ pub async fn process_token_escrow_deposit(
     slot: u64,
     amount: u64,
 ) -> Result<(), ToolboxEndpointError> {
+    let token_program_id =
+        toolbox_endpoint.get_account_owner(token_mint).await?;
     let instruction = token_escrow_deposit::instruction(
         &source_authority.pubkey(),
         source_token_account,
         authority,
         validator,
         token_mint,
+        &token_program_id,
         slot,
         amount,
     );
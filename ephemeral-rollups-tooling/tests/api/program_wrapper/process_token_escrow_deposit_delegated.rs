@@ -0,0 +1,60 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_deposit_delegated;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_token_escrow_deposit_approve(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    source_authority: &Keypair,
+    source_token_account: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    amount: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let token_program_id =
+        toolbox_endpoint.get_account_owner(token_mint).await?;
+    let instruction = token_escrow_deposit_delegated::approve_instruction(
+        &source_authority.pubkey(),
+        source_token_account,
+        validator,
+        token_mint,
+        &token_program_id,
+        amount,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(
+            instruction,
+            payer,
+            &[source_authority],
+        )
+        .await?;
+    Ok(())
+}
+
+pub async fn process_token_escrow_deposit_delegated(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    crank: &Keypair,
+    source_token_account: &Pubkey,
+    authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    slot: u64,
+    amount: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let token_program_id =
+        toolbox_endpoint.get_account_owner(token_mint).await?;
+    let instruction = token_escrow_deposit_delegated::instruction(
+        source_token_account,
+        authority,
+        validator,
+        token_mint,
+        &token_program_id,
+        slot,
+        amount,
+    );
+    toolbox_endpoint.process_instruction(instruction, crank).await?;
+    Ok(())
+}
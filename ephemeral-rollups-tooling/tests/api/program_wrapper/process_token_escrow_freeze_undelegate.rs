@@ -0,0 +1,33 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_freeze_undelegate;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_token_escrow_freeze_undelegate(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    freeze_authority: &Keypair,
+    authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    slot: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = token_escrow_freeze_undelegate::instruction(
+        &payer.pubkey(),
+        &freeze_authority.pubkey(),
+        authority,
+        validator,
+        token_mint,
+        slot,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(
+            instruction,
+            payer,
+            &[freeze_authority],
+        )
+        .await?;
+    Ok(())
+}
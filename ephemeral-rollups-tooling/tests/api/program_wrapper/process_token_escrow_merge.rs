@@ -0,0 +1,32 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_merge;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_token_escrow_merge(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    authority: &Keypair,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    source_slot: u64,
+    destination_slot: u64,
+    close_source: bool,
+    spill: &Pubkey,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = token_escrow_merge::instruction(
+        &authority.pubkey(),
+        validator,
+        token_mint,
+        source_slot,
+        destination_slot,
+        close_source,
+        spill,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[authority])
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_migrate_validator;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_token_escrow_migrate_validator(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    authority: &Keypair,
+    source_validator: &Pubkey,
+    destination_validator: &Pubkey,
+    token_mint: &Pubkey,
+    source_slot: u64,
+    destination_slot: u64,
+    amount: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let token_program_id =
+        toolbox_endpoint.get_account_owner(token_mint).await?;
+    let instruction = token_escrow_migrate_validator::instruction(
+        &authority.pubkey(),
+        source_validator,
+        destination_validator,
+        token_mint,
+        &token_program_id,
+        source_slot,
+        destination_slot,
+        amount,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[authority])
+        .await?;
+    Ok(())
+}
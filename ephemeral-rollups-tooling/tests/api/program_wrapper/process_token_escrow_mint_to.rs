@@ -0,0 +1,33 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_mint_to;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_token_escrow_mint_to(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    mint_authority: &Keypair,
+    authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    slot: u64,
+    amount: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let token_program_id =
+        toolbox_endpoint.get_account_owner(token_mint).await?;
+    let instruction = token_escrow_mint_to::instruction(
+        &mint_authority.pubkey(),
+        authority,
+        validator,
+        token_mint,
+        &token_program_id,
+        slot,
+        amount,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[mint_authority])
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_set_frozen;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_token_escrow_set_frozen(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    freeze_authority: &Keypair,
+    authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    slot: u64,
+    frozen: bool,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = token_escrow_set_frozen::instruction(
+        &freeze_authority.pubkey(),
+        authority,
+        validator,
+        token_mint,
+        slot,
+        frozen,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(
+            instruction,
+            payer,
+            &[freeze_authority],
+        )
+        .await?;
+    Ok(())
+}
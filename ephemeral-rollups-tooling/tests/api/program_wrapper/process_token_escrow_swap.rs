@@ -0,0 +1,44 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_swap;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_token_escrow_swap(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    authority_a: &Keypair,
+    authority_b: &Keypair,
+    validator: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    authority_a_source_slot: u64,
+    authority_a_destination_slot: u64,
+    authority_b_source_slot: u64,
+    authority_b_destination_slot: u64,
+    amount_a: u64,
+    amount_b: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = token_escrow_swap::instruction(
+        &authority_a.pubkey(),
+        &authority_b.pubkey(),
+        validator,
+        token_mint_a,
+        token_mint_b,
+        authority_a_source_slot,
+        authority_a_destination_slot,
+        authority_b_source_slot,
+        authority_b_destination_slot,
+        amount_a,
+        amount_b,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(
+            instruction,
+            payer,
+            &[authority_a, authority_b],
+        )
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_swap_signed;
+use ephemeral_rollups_wrapper::instruction::token_escrow_transfer_signed;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_token_escrow_swap_signed(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    authority_a: &Keypair,
+    authority_b: &Keypair,
+    validator: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    authority_a_source_slot: u64,
+    authority_a_destination_slot: u64,
+    authority_b_source_slot: u64,
+    authority_b_destination_slot: u64,
+    amount_a: u64,
+    amount_b: u64,
+    nonce: u64,
+    expiry: i64,
+) -> Result<(), ToolboxEndpointError> {
+    // Authority A only signs the offer off-chain, authority B takes it
+    let message = token_escrow_swap_signed::message(
+        &authority_a.pubkey(),
+        &authority_b.pubkey(),
+        validator,
+        token_mint_a,
+        token_mint_b,
+        authority_a_source_slot,
+        authority_a_destination_slot,
+        amount_a,
+        amount_b,
+        nonce,
+        expiry,
+    );
+    let signature = authority_a.sign_message(&message);
+    let ed25519_instruction = token_escrow_transfer_signed::ed25519_instruction(
+        &authority_a.pubkey(),
+        &<[u8; 64]>::try_from(signature.as_ref()).unwrap(),
+        &message,
+    );
+    let instruction = token_escrow_swap_signed::instruction(
+        &authority_a.pubkey(),
+        &authority_b.pubkey(),
+        validator,
+        token_mint_a,
+        token_mint_b,
+        authority_a_source_slot,
+        authority_a_destination_slot,
+        authority_b_source_slot,
+        authority_b_destination_slot,
+        amount_a,
+        amount_b,
+        nonce,
+        expiry,
+    );
+    toolbox_endpoint
+        .process_instructions_with_signers(
+            &[ed25519_instruction, instruction],
+            payer,
+            &[authority_b],
+        )
+        .await?;
+    Ok(())
+}
@@ -5,6 +5,8 @@ use solana_sdk::signer::Signer;
 use solana_toolbox_endpoint::ToolboxEndpoint;
 use solana_toolbox_endpoint::ToolboxEndpointError;
 
+use crate::api::program_wrapper::get_token_fee_collector::get_token_fee_collector;
+
 pub async fn process_token_escrow_transfer(
     toolbox_endpoint: &mut ToolboxEndpoint,
     payer: &Keypair,
@@ -16,6 +18,9 @@ pub async fn process_token_escrow_transfer(
     destination_slot: u64,
     amount: u64,
 ) -> Result<(), ToolboxEndpointError> {
+    let (fee_collector_authority, fee_collector_slot) =
+        get_token_fee_collector(toolbox_endpoint, validator, token_mint)
+            .await?;
     let instruction = token_escrow_transfer::instruction(
         &source_authority.pubkey(),
         destination_authority,
@@ -24,6 +29,8 @@ pub async fn process_token_escrow_transfer(
         source_slot,
         destination_slot,
         amount,
+        &fee_collector_authority,
+        fee_collector_slot,
     );
     toolbox_endpoint
         .process_instruction_with_signers(
@@ -0,0 +1,39 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_transfer_batch;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::program_wrapper::get_token_fee_collector::get_token_fee_collector;
+
+pub async fn process_token_escrow_transfer_batch(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    source_authority: &Keypair,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    source_slot: u64,
+    transfers: &[(Pubkey, u64, u64)],
+) -> Result<(), ToolboxEndpointError> {
+    let (fee_collector_authority, fee_collector_slot) =
+        get_token_fee_collector(toolbox_endpoint, validator, token_mint)
+            .await?;
+    let instruction = token_escrow_transfer_batch::instruction(
+        &source_authority.pubkey(),
+        validator,
+        token_mint,
+        source_slot,
+        transfers,
+        &fee_collector_authority,
+        fee_collector_slot,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(
+            instruction,
+            payer,
+            &[source_authority],
+        )
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_transfer_create;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::program_wrapper::get_token_fee_collector::get_token_fee_collector;
+
+pub async fn process_token_escrow_transfer_create(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    source_authority: &Keypair,
+    destination_authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    source_slot: u64,
+    destination_slot: u64,
+    amount: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let (fee_collector_authority, fee_collector_slot) =
+        get_token_fee_collector(toolbox_endpoint, validator, token_mint)
+            .await?;
+    let instruction = token_escrow_transfer_create::instruction(
+        &payer.pubkey(),
+        &source_authority.pubkey(),
+        destination_authority,
+        validator,
+        token_mint,
+        source_slot,
+        destination_slot,
+        amount,
+        &fee_collector_authority,
+        fee_collector_slot,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(
+            instruction,
+            payer,
+            &[source_authority],
+        )
+        .await?;
+    Ok(())
+}
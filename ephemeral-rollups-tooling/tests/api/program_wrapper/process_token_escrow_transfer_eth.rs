@@ -0,0 +1,75 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_transfer_eth;
+use libsecp256k1::SecretKey;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::program_wrapper::get_eth_wallet::get_eth_address;
+use crate::api::program_wrapper::get_eth_wallet::get_eth_signed_instruction;
+use crate::api::program_wrapper::get_token_fee_collector::get_token_fee_collector;
+
+pub async fn process_token_escrow_transfer_eth(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    relayer: &Keypair,
+    source_eth_wallet: &SecretKey,
+    destination_authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    source_slot: u64,
+    destination_slot: u64,
+    amount: u64,
+    nonce: u64,
+    expiry: i64,
+    relayer_slot: u64,
+    relayer_fee: u64,
+    max_relayer_fee: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let (fee_collector_authority, fee_collector_slot) =
+        get_token_fee_collector(toolbox_endpoint, validator, token_mint)
+            .await?;
+    // The Ethereum wallet only signs the intent off-chain, the relayer pays
+    let source_eth_address = get_eth_address(source_eth_wallet);
+    let message = token_escrow_transfer_eth::message(
+        &source_eth_address,
+        destination_authority,
+        validator,
+        token_mint,
+        source_slot,
+        destination_slot,
+        amount,
+        nonce,
+        expiry,
+        &relayer.pubkey(),
+        relayer_slot,
+        max_relayer_fee,
+    );
+    let secp256k1_instruction =
+        get_eth_signed_instruction(source_eth_wallet, &message);
+    let instruction = token_escrow_transfer_eth::instruction(
+        &source_eth_address,
+        destination_authority,
+        validator,
+        token_mint,
+        source_slot,
+        destination_slot,
+        amount,
+        nonce,
+        expiry,
+        &fee_collector_authority,
+        fee_collector_slot,
+        &relayer.pubkey(),
+        relayer_slot,
+        relayer_fee,
+        max_relayer_fee,
+    );
+    toolbox_endpoint
+        .process_instructions_with_signers(
+            &[secp256k1_instruction, instruction],
+            relayer,
+            &[],
+        )
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use ephemeral_rollups_wrapper::instruction::multisig_sign;
+use ephemeral_rollups_wrapper::instruction::token_escrow_transfer;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::program_wrapper::get_token_fee_collector::get_token_fee_collector;
+
+pub async fn process_token_escrow_transfer_multisig(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    source_multisig: &Pubkey,
+    source_multisig_signers: &[&Keypair],
+    destination_authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    source_slot: u64,
+    destination_slot: u64,
+    amount: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let (fee_collector_authority, fee_collector_slot) =
+        get_token_fee_collector(toolbox_endpoint, validator, token_mint)
+            .await?;
+    let instruction = multisig_sign::instruction(
+        token_escrow_transfer::instruction(
+            source_multisig,
+            destination_authority,
+            validator,
+            token_mint,
+            source_slot,
+            destination_slot,
+            amount,
+            &fee_collector_authority,
+            fee_collector_slot,
+        ),
+        source_multisig,
+        &source_multisig_signers
+            .iter()
+            .map(|signer| signer.pubkey())
+            .collect::<Vec<_>>(),
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(
+            instruction,
+            payer,
+            source_multisig_signers,
+        )
+        .await?;
+    Ok(())
+}
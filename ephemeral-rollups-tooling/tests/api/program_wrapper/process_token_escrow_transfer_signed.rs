@@ -0,0 +1,75 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_transfer_signed;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::program_wrapper::get_token_fee_collector::get_token_fee_collector;
+
+pub async fn process_token_escrow_transfer_signed(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    relayer: &Keypair,
+    source_authority: &Keypair,
+    destination_authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    source_slot: u64,
+    destination_slot: u64,
+    amount: u64,
+    nonce: u64,
+    expiry: i64,
+    relayer_slot: u64,
+    relayer_fee: u64,
+    max_relayer_fee: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let (fee_collector_authority, fee_collector_slot) =
+        get_token_fee_collector(toolbox_endpoint, validator, token_mint)
+            .await?;
+    // The authority only signs the intent off-chain, the relayer pays
+    let message = token_escrow_transfer_signed::message(
+        &source_authority.pubkey(),
+        destination_authority,
+        validator,
+        token_mint,
+        source_slot,
+        destination_slot,
+        amount,
+        nonce,
+        expiry,
+        &relayer.pubkey(),
+        relayer_slot,
+        max_relayer_fee,
+    );
+    let signature = source_authority.sign_message(&message);
+    let ed25519_instruction = token_escrow_transfer_signed::ed25519_instruction(
+        &source_authority.pubkey(),
+        &<[u8; 64]>::try_from(signature.as_ref()).unwrap(),
+        &message,
+    );
+    let instruction = token_escrow_transfer_signed::instruction(
+        &source_authority.pubkey(),
+        destination_authority,
+        validator,
+        token_mint,
+        source_slot,
+        destination_slot,
+        amount,
+        nonce,
+        expiry,
+        &fee_collector_authority,
+        fee_collector_slot,
+        &relayer.pubkey(),
+        relayer_slot,
+        relayer_fee,
+        max_relayer_fee,
+    );
+    toolbox_endpoint
+        .process_instructions_with_signers(
+            &[ed25519_instruction, instruction],
+            relayer,
+            &[],
+        )
+        .await?;
+    Ok(())
+}
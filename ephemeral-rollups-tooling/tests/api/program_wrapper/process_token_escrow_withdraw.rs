@@ -15,11 +15,14 @@ pub async fn process_token_escrow_withdraw(
     slot: u64,
     amount: u64,
 ) -> Result<(), ToolboxEndpointError> {
+    let token_program_id =
+        toolbox_endpoint.get_account_owner(token_mint).await?;
     let instruction = token_escrow_withdraw::instruction(
         &authority.pubkey(),
         destination_token_account,
         validator,
         token_mint,
+        &token_program_id,
         slot,
         amount,
     );
@@ -0,0 +1,34 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_withdraw_create;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_token_escrow_withdraw_create(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    authority: &Keypair,
+    destination_wallet: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    slot: u64,
+    amount: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let token_program_id =
+        toolbox_endpoint.get_account_owner(token_mint).await?;
+    let instruction = token_escrow_withdraw_create::instruction(
+        &payer.pubkey(),
+        &authority.pubkey(),
+        destination_wallet,
+        validator,
+        token_mint,
+        &token_program_id,
+        slot,
+        amount,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[authority])
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_withdraw_eth;
+use libsecp256k1::SecretKey;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::program_wrapper::get_eth_wallet::get_eth_address;
+use crate::api::program_wrapper::get_eth_wallet::get_eth_signed_instruction;
+
+pub async fn process_token_escrow_withdraw_eth(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    relayer: &Keypair,
+    eth_wallet: &SecretKey,
+    destination_token_account: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    slot: u64,
+    amount: u64,
+    nonce: u64,
+    expiry: i64,
+) -> Result<(), ToolboxEndpointError> {
+    // The Ethereum wallet only signs the intent off-chain, the relayer pays
+    let eth_address = get_eth_address(eth_wallet);
+    let message = token_escrow_withdraw_eth::message(
+        &eth_address,
+        destination_token_account,
+        validator,
+        token_mint,
+        slot,
+        amount,
+        nonce,
+        expiry,
+    );
+    let secp256k1_instruction =
+        get_eth_signed_instruction(eth_wallet, &message);
+    let token_program_id =
+        toolbox_endpoint.get_account_owner(token_mint).await?;
+    let instruction = token_escrow_withdraw_eth::instruction(
+        &eth_address,
+        destination_token_account,
+        validator,
+        token_mint,
+        &token_program_id,
+        slot,
+        amount,
+        nonce,
+        expiry,
+    );
+    toolbox_endpoint
+        .process_instructions_with_signers(
+            &[secp256k1_instruction, instruction],
+            relayer,
+            &[],
+        )
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use ephemeral_rollups_wrapper::instruction::token_vault_audit;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_token_vault_audit(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = token_vault_audit::instruction(validator, token_mint);
+    toolbox_endpoint.process_instruction(instruction, payer).await?;
+    Ok(())
+}
@@ -11,8 +11,14 @@ pub async fn process_token_vault_init(
     validator: &Pubkey,
     token_mint: &Pubkey,
 ) -> Result<(), ToolboxEndpointError> {
-    let instruction =
-        token_vault_init::instruction(&payer.pubkey(), validator, token_mint);
+    let token_program_id =
+        toolbox_endpoint.get_account_owner(token_mint).await?;
+    let instruction = token_vault_init::instruction(
+        &payer.pubkey(),
+        validator,
+        token_mint,
+        &token_program_id,
+    );
     toolbox_endpoint.process_instruction(instruction, payer).await?;
     Ok(())
 }
@@ -0,0 +1,27 @@
+use ephemeral_rollups_wrapper::instruction::token_vault_state_init;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_token_vault_state_init(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    admin: &Keypair,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    outstanding_amount: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = token_vault_state_init::instruction(
+        &payer.pubkey(),
+        &admin.pubkey(),
+        validator,
+        token_mint,
+        outstanding_amount,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[admin])
+        .await?;
+    Ok(())
+}
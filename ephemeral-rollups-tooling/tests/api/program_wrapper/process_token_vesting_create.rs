@@ -0,0 +1,39 @@
+use ephemeral_rollups_wrapper::instruction::token_vesting_create;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_token_vesting_create(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    funder: &Keypair,
+    authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    funder_slot: u64,
+    slot: u64,
+    amount: u64,
+    start_unix_timestamp: i64,
+    cliff_unix_timestamp: i64,
+    end_unix_timestamp: i64,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = token_vesting_create::instruction(
+        &payer.pubkey(),
+        &funder.pubkey(),
+        authority,
+        validator,
+        token_mint,
+        funder_slot,
+        slot,
+        amount,
+        start_unix_timestamp,
+        cliff_unix_timestamp,
+        end_unix_timestamp,
+    );
+    toolbox_endpoint
+        .process_instruction_with_signers(instruction, payer, &[funder])
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use ephemeral_rollups_wrapper::instruction::token_vesting_release;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_toolbox_endpoint::ToolboxEndpoint;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+pub async fn process_token_vesting_release(
+    toolbox_endpoint: &mut ToolboxEndpoint,
+    payer: &Keypair,
+    authority: &Pubkey,
+    funder: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    slot: u64,
+) -> Result<(), ToolboxEndpointError> {
+    let instruction = token_vesting_release::instruction(
+        authority, funder, validator, token_mint, slot,
+    );
+    toolbox_endpoint.process_instruction(instruction, payer).await?;
+    Ok(())
+}
@@ -7,7 +7,6 @@ use mpl_bubblegum::types::MetadataArgs;
 use mpl_bubblegum::types::TokenProgramVersion;
 use mpl_bubblegum::types::TokenStandard;
 use mpl_bubblegum::utils::get_asset_id;
-use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_toolbox_endpoint::ToolboxEndpointError;
@@ -15,6 +14,7 @@ use spl_merkle_tree_reference::MerkleTree;
 use spl_merkle_tree_reference::Node;
 
 use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
 use crate::api::program_bubblegum::process_create_tree::process_create_tree;
 use crate::api::program_bubblegum::process_mint::process_mint;
 use crate::api::program_wrapper::process_bubblegum_escrow_delegate::process_bubblegum_escrow_delegate;
@@ -27,7 +27,7 @@ async fn localnet_bubblegum_escrow_deposit_transfer_delegate(
     let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
 
     // Important keys used in the test
-    let validator = Pubkey::new_unique();
+    let validator = localnet_validator();
 
     let payer = Keypair::new();
 
@@ -0,0 +1,204 @@
+use ephemeral_rollups_wrapper::state::config::ConfigPause;
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_config_admin;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_admin_set_config::process_admin_set_config;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_withdraw::process_token_escrow_withdraw;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_config_pause_deposit_withdraw(
+) -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+    let admin = localnet_config_admin();
+
+    let authority = Keypair::new();
+
+    let source = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Escrow account we will be using
+    let slot = 11;
+    let token_escrow_pda = TokenEscrow::generate_pda(
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create and fund the escrow
+    process_token_escrow_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+    )
+    .await?;
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        5_000_000,
+    )
+    .await?;
+
+    // Only the admin can change the config
+    let paused = ConfigPause::Deposit.flag();
+    assert!(process_admin_set_config(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &authority.pubkey(),
+        paused,
+        &[spl_token::ID],
+    )
+    .await
+    .is_err());
+
+    // Pause the deposits during an incident
+    process_admin_set_config(
+        &mut toolbox_endpoint,
+        &payer,
+        &admin,
+        &admin.pubkey(),
+        paused,
+        &[spl_token::ID],
+    )
+    .await?;
+
+    // Deposits must now fail
+    assert!(process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        5_000_000,
+    )
+    .await
+    .is_err());
+
+    // But users can still get their funds out
+    let destination_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &authority.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    process_token_escrow_withdraw(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &destination_token,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        2_000_000,
+    )
+    .await?;
+
+    // Resume the deposits after the incident
+    process_admin_set_config(
+        &mut toolbox_endpoint,
+        &payer,
+        &admin,
+        &admin.pubkey(),
+        0,
+        &[spl_token::ID],
+    )
+    .await?;
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        5_000_000,
+    )
+    .await?;
+
+    // Check the final balance
+    assert_eq!(
+        8_000_000,
+        toolbox_endpoint
+            .get_account_data_borsh_deserialized::<TokenEscrow>(
+                &token_escrow_pda
+            )
+            .await?
+            .unwrap()
+            .amount
+    );
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,323 @@
+use ephemeral_rollups_wrapper::state::lamport_escrow::LamportEscrow;
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_lamport_escrow_claim::process_lamport_escrow_claim;
+use crate::api::program_wrapper::process_lamport_escrow_create::process_lamport_escrow_create;
+use crate::api::program_wrapper::process_lamport_escrow_realloc::process_lamport_escrow_realloc;
+use crate::api::program_wrapper::process_lamport_escrow_set_spending_limit::process_lamport_escrow_set_spending_limit;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_realloc::process_token_escrow_realloc;
+use crate::api::program_wrapper::process_token_escrow_set_spending_limit::process_token_escrow_set_spending_limit;
+use crate::api::program_wrapper::process_token_escrow_transfer::process_token_escrow_transfer;
+use crate::api::program_wrapper::process_token_escrow_withdraw::process_token_escrow_withdraw;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+use crate::api::program_wrapper::process_token_vesting_create::process_token_vesting_create;
+
+#[tokio::test]
+async fn localnet_escrow_spending_limit() -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+
+    let authority = Keypair::new();
+    let receiver = Keypair::new();
+
+    let source = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create and fund the escrows
+    let slot = 3;
+    for authority in [&authority.pubkey(), &receiver.pubkey()] {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            authority,
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        10_000_000,
+    )
+    .await?;
+
+    // Escrows created with the current layout have nothing to migrate
+    assert!(process_token_escrow_realloc(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+    )
+    .await
+    .is_err());
+
+    // Only the authority can set the limits
+    assert!(process_token_escrow_set_spending_limit(
+        &mut toolbox_endpoint,
+        &payer,
+        &receiver,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        2_000_000,
+        3_000_000,
+        3600,
+    )
+    .await
+    .is_err());
+    process_token_escrow_set_spending_limit(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        2_000_000,
+        3_000_000,
+        3600,
+    )
+    .await?;
+
+    // A single transfer can't exceed the per-transaction limit
+    assert!(process_token_escrow_transfer(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        2_000_001,
+    )
+    .await
+    .is_err());
+
+    // Spending within the limits succeeds until the window is exhausted
+    process_token_escrow_transfer(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        2_000_000,
+    )
+    .await?;
+    assert!(process_token_escrow_withdraw(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &source_token,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        1_000_001,
+    )
+    .await
+    .is_err());
+    process_token_escrow_withdraw(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &source_token,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        1_000_000,
+    )
+    .await?;
+
+    // Locking funds into a vesting counts against the same window
+    assert!(process_token_vesting_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1,
+        0,
+        0,
+        1,
+    )
+    .await
+    .is_err());
+
+    // Check the spent amounts
+    let token_escrow = toolbox_endpoint
+        .get_account_data_borsh_deserialized::<TokenEscrow>(
+            &TokenEscrow::generate_pda(
+                &authority.pubkey(),
+                &validator,
+                &token_mint.pubkey(),
+                slot,
+                &ephemeral_rollups_wrapper::ID,
+            ),
+        )
+        .await?
+        .unwrap();
+    assert_eq!(7_000_000, token_escrow.amount);
+    assert_eq!(3_000_000, token_escrow.spending_limit.window_spent);
+
+    // Lifting the limits allows spending again
+    process_token_escrow_set_spending_limit(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        0,
+        0,
+        0,
+    )
+    .await?;
+    process_token_escrow_withdraw(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &source_token,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        7_000_000,
+    )
+    .await?;
+
+    // Lamport escrows are limited the same way
+    process_lamport_escrow_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority.pubkey(),
+        &validator,
+        slot,
+    )
+    .await?;
+    toolbox_endpoint
+        .process_system_transfer(
+            &payer,
+            &payer,
+            &LamportEscrow::generate_pda(
+                &authority.pubkey(),
+                &validator,
+                slot,
+                &ephemeral_rollups_wrapper::ID,
+            ),
+            10 * LAMPORTS_PER_SOL,
+        )
+        .await?;
+    assert!(process_lamport_escrow_realloc(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority.pubkey(),
+        &validator,
+        slot,
+    )
+    .await
+    .is_err());
+    process_lamport_escrow_set_spending_limit(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &validator,
+        slot,
+        0,
+        LAMPORTS_PER_SOL,
+        3600,
+    )
+    .await?;
+    process_lamport_escrow_claim(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &receiver.pubkey(),
+        &validator,
+        slot,
+        LAMPORTS_PER_SOL,
+    )
+    .await?;
+    assert!(process_lamport_escrow_claim(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &receiver.pubkey(),
+        &validator,
+        slot,
+        1,
+    )
+    .await
+    .is_err());
+
+    // Done
+    Ok(())
+}
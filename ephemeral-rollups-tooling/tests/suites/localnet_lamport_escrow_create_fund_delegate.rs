@@ -1,11 +1,11 @@
 use ephemeral_rollups_wrapper::state::lamport_escrow::LamportEscrow;
 use solana_sdk::native_token::LAMPORTS_PER_SOL;
-use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_toolbox_endpoint::ToolboxEndpointError;
 
 use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
 use crate::api::program_wrapper::process_lamport_escrow_create::process_lamport_escrow_create;
 use crate::api::program_wrapper::process_lamport_escrow_delegate::process_lamport_escrow_delegate;
 
@@ -15,7 +15,7 @@ async fn localnet_lamport_escrow_create_fund_delegate(
     let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
 
     // Important keys used in the test
-    let validator = Pubkey::new_unique();
+    let validator = localnet_validator();
 
     let payer = Keypair::new();
     let authority = Keypair::new();
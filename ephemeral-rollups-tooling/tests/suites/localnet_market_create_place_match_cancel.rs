@@ -0,0 +1,377 @@
+use ephemeral_rollups_wrapper::state::market::MarketOrderSide;
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_config_admin;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_market_create::process_market_create;
+use crate::api::program_wrapper::process_market_order_cancel::process_market_order_cancel;
+use crate::api::program_wrapper::process_market_order_match::process_market_order_match;
+use crate::api::program_wrapper::process_market_order_place::process_market_order_place;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_market_create_place_match_cancel(
+) -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let admin = localnet_config_admin();
+    let payer = Keypair::new();
+
+    let market_authority = Keypair::new();
+
+    let seller = Keypair::new();
+    let buyer = Keypair::new();
+
+    let source = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create token mints
+    let base_token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &base_token_mint,
+            &base_token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+    let quote_token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &quote_token_mint,
+            &quote_token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop both tokens to our source wallet
+    let source_base_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &base_token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &base_token_mint.pubkey(),
+            &base_token_mint,
+            &source_base_token,
+            10_000_000,
+        )
+        .await?;
+    let source_quote_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &quote_token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &quote_token_mint.pubkey(),
+            &quote_token_mint,
+            &source_quote_token,
+            1_000_000_000,
+        )
+        .await?;
+
+    // Escrow accounts we will be trading with
+    let seller_slot = 0;
+    let seller_base_token_escrow_pda = TokenEscrow::generate_pda(
+        &seller.pubkey(),
+        &validator,
+        &base_token_mint.pubkey(),
+        seller_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    let seller_quote_token_escrow_pda = TokenEscrow::generate_pda(
+        &seller.pubkey(),
+        &validator,
+        &quote_token_mint.pubkey(),
+        seller_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    // The buyer keeps its base and quote tokens in different slots
+    let buyer_base_slot = 3;
+    let buyer_quote_slot = 7;
+    let buyer_base_token_escrow_pda = TokenEscrow::generate_pda(
+        &buyer.pubkey(),
+        &validator,
+        &base_token_mint.pubkey(),
+        buyer_base_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    let buyer_quote_token_escrow_pda = TokenEscrow::generate_pda(
+        &buyer.pubkey(),
+        &validator,
+        &quote_token_mint.pubkey(),
+        buyer_quote_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+
+    // Prepare being able to escrow both tokens for this validator
+    for token_mint in [&base_token_mint, &quote_token_mint] {
+        process_token_vault_init(
+            &mut toolbox_endpoint,
+            &payer,
+            &validator,
+            &token_mint.pubkey(),
+        )
+        .await?;
+    }
+
+    // Create all escrows for both traders
+    for (authority, token_mint, slot) in [
+        (&seller, &base_token_mint, seller_slot),
+        (&seller, &quote_token_mint, seller_slot),
+        (&buyer, &base_token_mint, buyer_base_slot),
+        (&buyer, &quote_token_mint, buyer_quote_slot),
+    ] {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            &authority.pubkey(),
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+
+    // The seller has base tokens, the buyer has quote tokens
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_base_token,
+        &seller.pubkey(),
+        &validator,
+        &base_token_mint.pubkey(),
+        seller_slot,
+        10_000_000,
+    )
+    .await?;
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_quote_token,
+        &buyer.pubkey(),
+        &validator,
+        &quote_token_mint.pubkey(),
+        buyer_quote_slot,
+        1_000_000_000,
+    )
+    .await?;
+
+    // Only the config's admin can list a market for this pair
+    assert!(process_market_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &payer,
+        &market_authority,
+        &validator,
+        &base_token_mint.pubkey(),
+        &quote_token_mint.pubkey(),
+        1_000_000,
+        2,
+    )
+    .await
+    .is_err());
+
+    // Create the market, trading base tokens in lots of one unit, at least two
+    // lots per order
+    process_market_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &admin,
+        &market_authority,
+        &validator,
+        &base_token_mint.pubkey(),
+        &quote_token_mint.pubkey(),
+        1_000_000,
+        2,
+    )
+    .await?;
+
+    // Dust orders below the minimum size are rejected
+    assert!(process_market_order_place(
+        &mut toolbox_endpoint,
+        &payer,
+        &seller,
+        &validator,
+        &base_token_mint.pubkey(),
+        &quote_token_mint.pubkey(),
+        seller_slot,
+        seller_slot,
+        MarketOrderSide::Ask,
+        50_000_000,
+        1_000_000,
+    )
+    .await
+    .is_err());
+
+    // Seller asks 4 lots at 50 quote tokens per lot (order 0)
+    process_market_order_place(
+        &mut toolbox_endpoint,
+        &payer,
+        &seller,
+        &validator,
+        &base_token_mint.pubkey(),
+        &quote_token_mint.pubkey(),
+        seller_slot,
+        seller_slot,
+        MarketOrderSide::Ask,
+        50_000_000,
+        4_000_000,
+    )
+    .await?;
+
+    // Buyer bids for 3 lots at 60 quote tokens per lot (order 1)
+    process_market_order_place(
+        &mut toolbox_endpoint,
+        &payer,
+        &buyer,
+        &validator,
+        &base_token_mint.pubkey(),
+        &quote_token_mint.pubkey(),
+        buyer_base_slot,
+        buyer_quote_slot,
+        MarketOrderSide::Bid,
+        60_000_000,
+        3_000_000,
+    )
+    .await?;
+
+    // Placing the orders should have locked the funds
+    for (token_escrow_pda, amount) in [
+        (seller_base_token_escrow_pda, 6_000_000),
+        (buyer_quote_token_escrow_pda, 820_000_000),
+    ] {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &token_escrow_pda
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // Match the orders, filling at the ask's price since it was resting first
+    process_market_order_match(
+        &mut toolbox_endpoint,
+        &payer,
+        &buyer.pubkey(),
+        &seller.pubkey(),
+        &validator,
+        &base_token_mint.pubkey(),
+        &quote_token_mint.pubkey(),
+        buyer_base_slot,
+        buyer_quote_slot,
+        seller_slot,
+        1,
+        0,
+    )
+    .await?;
+
+    // The fill and the price improvement should be reflected in the balances
+    for (token_escrow_pda, amount) in [
+        (seller_base_token_escrow_pda, 6_000_000),
+        (seller_quote_token_escrow_pda, 150_000_000),
+        (buyer_base_token_escrow_pda, 3_000_000),
+        (buyer_quote_token_escrow_pda, 850_000_000),
+    ] {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &token_escrow_pda
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // The filled bid is gone from the book and cannot be matched again
+    assert!(process_market_order_match(
+        &mut toolbox_endpoint,
+        &payer,
+        &buyer.pubkey(),
+        &seller.pubkey(),
+        &validator,
+        &base_token_mint.pubkey(),
+        &quote_token_mint.pubkey(),
+        buyer_base_slot,
+        buyer_quote_slot,
+        seller_slot,
+        1,
+        0,
+    )
+    .await
+    .is_err());
+
+    // Only the seller can cancel the rest of the ask
+    assert!(process_market_order_cancel(
+        &mut toolbox_endpoint,
+        &payer,
+        &buyer,
+        &validator,
+        &base_token_mint.pubkey(),
+        &quote_token_mint.pubkey(),
+        buyer_base_slot,
+        MarketOrderSide::Ask,
+        0,
+    )
+    .await
+    .is_err());
+    process_market_order_cancel(
+        &mut toolbox_endpoint,
+        &payer,
+        &seller,
+        &validator,
+        &base_token_mint.pubkey(),
+        &quote_token_mint.pubkey(),
+        seller_slot,
+        MarketOrderSide::Ask,
+        0,
+    )
+    .await?;
+
+    // The remaining lot should be back in the seller's escrow
+    assert_eq!(
+        7_000_000,
+        toolbox_endpoint
+            .get_account_data_borsh_deserialized::<TokenEscrow>(
+                &seller_base_token_escrow_pda
+            )
+            .await?
+            .unwrap()
+            .amount
+    );
+
+    // Done
+    Ok(())
+}
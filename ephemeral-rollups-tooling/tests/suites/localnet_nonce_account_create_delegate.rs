@@ -0,0 +1,205 @@
+use ephemeral_rollups_wrapper::state::nonce_account::NonceAccount;
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_nonce_account_create::process_nonce_account_create;
+use crate::api::program_wrapper::process_nonce_account_delegate::process_nonce_account_delegate;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_transfer_signed::process_token_escrow_transfer_signed;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_nonce_account_create_delegate(
+) -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+    let authority = Keypair::new();
+
+    let receiver = Keypair::new();
+
+    let source = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Nonce account we will be using
+    let authority_nonce_account_pda = NonceAccount::generate_pda(
+        &authority.pubkey(),
+        &ephemeral_rollups_wrapper::ID,
+    );
+
+    // Create the nonce account
+    process_nonce_account_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority.pubkey(),
+    )
+    .await?;
+
+    // No nonce has been used yet
+    assert_eq!(
+        0,
+        toolbox_endpoint
+            .get_account_data_borsh_deserialized::<NonceAccount>(
+                &authority_nonce_account_pda
+            )
+            .await?
+            .unwrap()
+            .nonce
+    );
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create and fund the escrows
+    let slot = 0;
+    for escrow_authority in [&authority.pubkey(), &receiver.pubkey()] {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            escrow_authority,
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        10_000_000,
+    )
+    .await?;
+
+    // A signed intent consumes its nonce, the counter moves past it
+    process_token_escrow_transfer_signed(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1_000_000,
+        7,
+        i64::MAX,
+        0,
+        0,
+        0,
+    )
+    .await?;
+    assert_eq!(
+        8,
+        toolbox_endpoint
+            .get_account_data_borsh_deserialized::<NonceAccount>(
+                &authority_nonce_account_pda
+            )
+            .await?
+            .unwrap()
+            .nonce
+    );
+
+    // Reusing the same nonce for another intent is rejected
+    assert!(process_token_escrow_transfer_signed(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        2_000_000,
+        7,
+        i64::MAX,
+        0,
+        0,
+        0,
+    )
+    .await
+    .is_err());
+
+    // Only the first transfer went through
+    assert_eq!(
+        1_000_000,
+        toolbox_endpoint
+            .get_account_data_borsh_deserialized::<TokenEscrow>(
+                &TokenEscrow::generate_pda(
+                    &receiver.pubkey(),
+                    &validator,
+                    &token_mint.pubkey(),
+                    slot,
+                    &ephemeral_rollups_wrapper::ID,
+                )
+            )
+            .await?
+            .unwrap()
+            .amount
+    );
+
+    // Delegate it so that signed intents can be submitted in the ER
+    process_nonce_account_delegate(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &validator,
+    )
+    .await?;
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,327 @@
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_config_admin;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_pool_add_liquidity::process_pool_add_liquidity;
+use crate::api::program_wrapper::process_pool_create::process_pool_create;
+use crate::api::program_wrapper::process_pool_remove_liquidity::process_pool_remove_liquidity;
+use crate::api::program_wrapper::process_pool_swap::process_pool_swap;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_pool_create_add_swap_remove(
+) -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let admin = localnet_config_admin();
+    let payer = Keypair::new();
+
+    let pool_authority = Keypair::new();
+
+    let provider = Keypair::new();
+    let trader = Keypair::new();
+
+    let source = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create token mints, in the canonical order pools are keyed by
+    let mut token_mints = [Keypair::new(), Keypair::new()];
+    token_mints.sort_by_key(|token_mint| token_mint.pubkey());
+    let [token_mint_a, token_mint_b] = token_mints;
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint_a,
+            &token_mint_a.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint_b,
+            &token_mint_b.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop both tokens to our source wallet
+    let source_token_a = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint_a.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint_a.pubkey(),
+            &token_mint_a,
+            &source_token_a,
+            110_000_000,
+        )
+        .await?;
+    let source_token_b = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint_b.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint_b.pubkey(),
+            &token_mint_b,
+            &source_token_b,
+            400_000_000,
+        )
+        .await?;
+
+    // Escrow accounts we will be using
+    let provider_slot = 1;
+    let provider_token_escrow_a_pda = TokenEscrow::generate_pda(
+        &provider.pubkey(),
+        &validator,
+        &token_mint_a.pubkey(),
+        provider_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    let provider_token_escrow_b_pda = TokenEscrow::generate_pda(
+        &provider.pubkey(),
+        &validator,
+        &token_mint_b.pubkey(),
+        provider_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    let trader_slot = 2;
+    let trader_token_escrow_a_pda = TokenEscrow::generate_pda(
+        &trader.pubkey(),
+        &validator,
+        &token_mint_a.pubkey(),
+        trader_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    let trader_token_escrow_b_pda = TokenEscrow::generate_pda(
+        &trader.pubkey(),
+        &validator,
+        &token_mint_b.pubkey(),
+        trader_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+
+    // Prepare being able to escrow both tokens for this validator
+    for token_mint in [&token_mint_a, &token_mint_b] {
+        process_token_vault_init(
+            &mut toolbox_endpoint,
+            &payer,
+            &validator,
+            &token_mint.pubkey(),
+        )
+        .await?;
+    }
+
+    // Create all escrows
+    for (authority, token_mint, slot) in [
+        (&provider, &token_mint_a, provider_slot),
+        (&provider, &token_mint_b, provider_slot),
+        (&trader, &token_mint_a, trader_slot),
+        (&trader, &token_mint_b, trader_slot),
+    ] {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            &authority.pubkey(),
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+
+    // Fund the escrows
+    for (source_token, authority, token_mint, slot, amount) in [
+        (&source_token_a, &provider, &token_mint_a, provider_slot, 100_000_000),
+        (&source_token_b, &provider, &token_mint_b, provider_slot, 400_000_000),
+        (&source_token_a, &trader, &token_mint_a, trader_slot, 10_000_000),
+    ] {
+        process_token_escrow_deposit(
+            &mut toolbox_endpoint,
+            &payer,
+            &source,
+            source_token,
+            &authority.pubkey(),
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+            amount,
+        )
+        .await?;
+    }
+
+    // Only the config's admin can list a pool, for mints in canonical order
+    // and with a sensible fee
+    for (admin, token_mint_a, token_mint_b, fee_basis_points) in [
+        (&payer, &token_mint_a, &token_mint_b, 30),
+        (&admin, &token_mint_b, &token_mint_a, 30),
+        (&admin, &token_mint_a, &token_mint_b, 5_000),
+    ] {
+        assert!(process_pool_create(
+            &mut toolbox_endpoint,
+            &payer,
+            admin,
+            &pool_authority,
+            &validator,
+            &token_mint_a.pubkey(),
+            &token_mint_b.pubkey(),
+            fee_basis_points,
+            1_000_000,
+        )
+        .await
+        .is_err());
+    }
+
+    // Create the pool with a 0.3% fee
+    process_pool_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &admin,
+        &pool_authority,
+        &validator,
+        &token_mint_a.pubkey(),
+        &token_mint_b.pubkey(),
+        30,
+        1_000_000,
+    )
+    .await?;
+
+    // Provide all the liquidity
+    process_pool_add_liquidity(
+        &mut toolbox_endpoint,
+        &payer,
+        &provider,
+        &validator,
+        &token_mint_a.pubkey(),
+        &token_mint_b.pubkey(),
+        provider_slot,
+        100_000_000,
+        400_000_000,
+        200_000_000,
+    )
+    .await?;
+
+    // Swap A for B, asking for too much out should fail
+    assert!(process_pool_swap(
+        &mut toolbox_endpoint,
+        &payer,
+        &trader,
+        &validator,
+        &token_mint_a.pubkey(),
+        &token_mint_b.pubkey(),
+        trader_slot,
+        trader_slot,
+        true,
+        10_000_000,
+        40_000_000,
+    )
+    .await
+    .is_err());
+    process_pool_swap(
+        &mut toolbox_endpoint,
+        &payer,
+        &trader,
+        &validator,
+        &token_mint_a.pubkey(),
+        &token_mint_b.pubkey(),
+        trader_slot,
+        trader_slot,
+        true,
+        10_000_000,
+        36_000_000,
+    )
+    .await?;
+
+    // The trader should have received the curve's output
+    for (token_escrow_pda, amount) in [
+        (trader_token_escrow_a_pda, 0),
+        (trader_token_escrow_b_pda, 36_264_435),
+    ] {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &token_escrow_pda
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // A position can't be left as dust
+    assert!(process_pool_remove_liquidity(
+        &mut toolbox_endpoint,
+        &payer,
+        &provider,
+        &validator,
+        &token_mint_a.pubkey(),
+        &token_mint_b.pubkey(),
+        provider_slot,
+        199_999_999,
+        0,
+        0,
+    )
+    .await
+    .is_err());
+
+    // Remove all the liquidity
+    process_pool_remove_liquidity(
+        &mut toolbox_endpoint,
+        &payer,
+        &provider,
+        &validator,
+        &token_mint_a.pubkey(),
+        &token_mint_b.pubkey(),
+        provider_slot,
+        200_000_000,
+        0,
+        0,
+    )
+    .await?;
+
+    // The provider should have received the whole reserves, including fees
+    for (token_escrow_pda, amount) in [
+        (provider_token_escrow_a_pda, 110_000_000),
+        (provider_token_escrow_b_pda, 363_735_565),
+    ] {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &token_escrow_pda
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // Done
+    Ok(())
+}
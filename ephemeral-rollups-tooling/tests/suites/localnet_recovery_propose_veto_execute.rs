@@ -0,0 +1,296 @@
+use ephemeral_rollups_wrapper::state::recovery::Recovery;
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_recovery_close::process_recovery_close;
+use crate::api::program_wrapper::process_recovery_execute::process_recovery_execute;
+use crate::api::program_wrapper::process_recovery_propose::process_recovery_propose;
+use crate::api::program_wrapper::process_recovery_set::process_recovery_set;
+use crate::api::program_wrapper::process_recovery_veto::process_recovery_veto;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_recovery_propose_veto_execute(
+) -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+
+    let authority = Keypair::new();
+    let guardian1 = Keypair::new();
+    let guardian2 = Keypair::new();
+    let guardian3 = Keypair::new();
+    let recovered = Keypair::new();
+    let attacker = Keypair::new();
+
+    let source = Keypair::new();
+
+    // Fund payers
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+    toolbox_endpoint
+        .process_airdrop(&recovered.pubkey(), 1_000_000_000)
+        .await?;
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create escrows for both the original and the recovered keys
+    let slot = 11;
+    for authority in [&authority.pubkey(), &recovered.pubkey()] {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            authority,
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        10_000_000,
+    )
+    .await?;
+
+    // The guardian set must be usable and come with a real waiting period
+    let guardians =
+        [guardian1.pubkey(), guardian2.pubkey(), guardian3.pubkey()];
+    for (threshold, delay_seconds) in [(2, 0), (0, 86_400), (4, 86_400)] {
+        assert!(process_recovery_set(
+            &mut toolbox_endpoint,
+            &payer,
+            &authority,
+            threshold,
+            &guardians,
+            delay_seconds,
+        )
+        .await
+        .is_err());
+    }
+
+    // The authority picks 2 out of 3 guardians with a one day waiting period
+    process_recovery_set(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        2,
+        &guardians,
+        86_400,
+    )
+    .await?;
+
+    // Only enough of the guardians together can propose a new authority
+    assert!(process_recovery_propose(
+        &mut toolbox_endpoint,
+        &payer,
+        &[&attacker, &guardian1],
+        &authority.pubkey(),
+        &attacker.pubkey(),
+    )
+    .await
+    .is_err());
+    assert!(process_recovery_propose(
+        &mut toolbox_endpoint,
+        &payer,
+        &[&guardian1],
+        &authority.pubkey(),
+        &attacker.pubkey(),
+    )
+    .await
+    .is_err());
+    process_recovery_propose(
+        &mut toolbox_endpoint,
+        &payer,
+        &[&guardian1, &guardian2],
+        &authority.pubkey(),
+        &recovered.pubkey(),
+    )
+    .await?;
+
+    // The proposal can't be executed before the waiting period passed
+    let token_escrows = [(validator, token_mint.pubkey(), slot)];
+    assert!(process_recovery_execute(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority.pubkey(),
+        &recovered,
+        &token_escrows,
+    )
+    .await
+    .is_err());
+
+    // The original authority can veto it
+    process_recovery_veto(&mut toolbox_endpoint, &payer, &authority).await?;
+    assert!(
+        !toolbox_endpoint
+            .get_account_data_borsh_deserialized::<Recovery>(
+                &Recovery::generate_pda(
+                    &authority.pubkey(),
+                    &ephemeral_rollups_wrapper::ID,
+                )
+            )
+            .await?
+            .unwrap()
+            .pending
+    );
+
+    // Another pair of guardians proposes again and the waiting period passes
+    process_recovery_propose(
+        &mut toolbox_endpoint,
+        &payer,
+        &[&guardian2, &guardian3],
+        &authority.pubkey(),
+        &recovered.pubkey(),
+    )
+    .await?;
+    toolbox_endpoint.forward_clock_unix_timestamp(86_400).await?;
+
+    // Only the recovered authority can execute the proposal
+    assert!(process_recovery_execute(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority.pubkey(),
+        &attacker,
+        &token_escrows,
+    )
+    .await
+    .is_err());
+    process_recovery_execute(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority.pubkey(),
+        &recovered,
+        &token_escrows,
+    )
+    .await?;
+
+    // The recovery stays open until the recovered authority closes it
+    assert!(
+        toolbox_endpoint
+            .get_account_data_borsh_deserialized::<Recovery>(
+                &Recovery::generate_pda(
+                    &authority.pubkey(),
+                    &ephemeral_rollups_wrapper::ID,
+                )
+            )
+            .await?
+            .unwrap()
+            .pending
+    );
+    assert!(process_recovery_close(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority.pubkey(),
+        &attacker,
+    )
+    .await
+    .is_err());
+    process_recovery_close(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority.pubkey(),
+        &recovered,
+    )
+    .await?;
+
+    // Once closed, the proposal can't be executed a second time
+    assert!(
+        !toolbox_endpoint
+            .get_account_data_borsh_deserialized::<Recovery>(
+                &Recovery::generate_pda(
+                    &authority.pubkey(),
+                    &ephemeral_rollups_wrapper::ID,
+                )
+            )
+            .await?
+            .unwrap()
+            .pending
+    );
+    assert!(process_recovery_execute(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority.pubkey(),
+        &recovered,
+        &token_escrows,
+    )
+    .await
+    .is_err());
+
+    // The balance now belongs to the recovered key
+    for (authority, amount) in [(&authority, 0), (&recovered, 10_000_000)] {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &TokenEscrow::generate_pda(
+                        &authority.pubkey(),
+                        &validator,
+                        &token_mint.pubkey(),
+                        slot,
+                        &ephemeral_rollups_wrapper::ID,
+                    )
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,208 @@
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use ephemeral_rollups_wrapper::state::token_vault::token_vault_generate_pda;
+use ephemeral_rollups_wrapper::util::token::TOKEN_2022_PROGRAM_ID;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_config_admin;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_token_2022::process_account_init::process_account_init;
+use crate::api::program_token_2022::process_mint_init_with_permanent_delegate::process_mint_init_with_permanent_delegate;
+use crate::api::program_token_2022::process_mint_to::process_mint_to;
+use crate::api::program_wrapper::process_admin_set_config::process_admin_set_config;
+use crate::api::program_wrapper::process_token_escrow_clawback::process_token_escrow_clawback;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_withdraw::process_token_escrow_withdraw;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_escrow_clawback() -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+    let admin = localnet_config_admin();
+
+    let payer = Keypair::new();
+
+    let issuer = Keypair::new();
+    let permanent_delegate = Keypair::new();
+
+    let holder = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Allow vaults of Token-2022 mints
+    process_admin_set_config(
+        &mut toolbox_endpoint,
+        &payer,
+        &admin,
+        &admin.pubkey(),
+        0,
+        &[spl_token::ID, TOKEN_2022_PROGRAM_ID],
+    )
+    .await?;
+
+    // Create the regulated Token-2022 mint, with a permanent delegate
+    let token_mint = Keypair::new();
+    process_mint_init_with_permanent_delegate(
+        &mut toolbox_endpoint,
+        &payer,
+        &token_mint,
+        &issuer.pubkey(),
+        &permanent_delegate.pubkey(),
+        6,
+    )
+    .await?;
+
+    // Mint token to the holder's wallet and prepare the issuer's treasury
+    let holder_token = Keypair::new();
+    process_account_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &holder_token,
+        &token_mint.pubkey(),
+        &holder.pubkey(),
+    )
+    .await?;
+    process_mint_to(
+        &mut toolbox_endpoint,
+        &payer,
+        &token_mint.pubkey(),
+        &issuer,
+        &holder_token.pubkey(),
+        10_000_000,
+    )
+    .await?;
+    let treasury_token = Keypair::new();
+    process_account_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &treasury_token,
+        &token_mint.pubkey(),
+        &issuer.pubkey(),
+    )
+    .await?;
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+    assert_eq!(
+        TOKEN_2022_PROGRAM_ID,
+        toolbox_endpoint
+            .get_account_owner(&token_vault_generate_pda(
+                &validator,
+                &token_mint.pubkey(),
+                &ephemeral_rollups_wrapper::ID,
+            ))
+            .await?
+    );
+
+    // Create and fund the holder's escrow
+    let slot = 9;
+    process_token_escrow_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &holder.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+    )
+    .await?;
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &holder,
+        &holder_token.pubkey(),
+        &holder.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        10_000_000,
+    )
+    .await?;
+
+    // Only the permanent delegate of the mint can claw back
+    assert!(process_token_escrow_clawback(
+        &mut toolbox_endpoint,
+        &payer,
+        &issuer,
+        &holder.pubkey(),
+        &treasury_token.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        4_000_000,
+    )
+    .await
+    .is_err());
+    process_token_escrow_clawback(
+        &mut toolbox_endpoint,
+        &payer,
+        &permanent_delegate,
+        &holder.pubkey(),
+        &treasury_token.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        4_000_000,
+    )
+    .await?;
+
+    // The holder can still withdraw what was not clawed back
+    process_token_escrow_withdraw(
+        &mut toolbox_endpoint,
+        &payer,
+        &holder,
+        &holder_token.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        6_000_000,
+    )
+    .await?;
+
+    // Check the balances after the clawback and withdraw
+    assert_eq!(
+        0,
+        toolbox_endpoint
+            .get_account_data_borsh_deserialized::<TokenEscrow>(
+                &TokenEscrow::generate_pda(
+                    &holder.pubkey(),
+                    &validator,
+                    &token_mint.pubkey(),
+                    slot,
+                    &ephemeral_rollups_wrapper::ID,
+                )
+            )
+            .await?
+            .unwrap()
+            .amount
+    );
+    for (token_account, amount) in
+        [(&holder_token, 6_000_000), (&treasury_token, 4_000_000)]
+    {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_spl_token_account(&token_account.pubkey())
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // Done
+    Ok(())
+}
@@ -1,10 +1,10 @@
 use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
-use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_toolbox_endpoint::ToolboxEndpointError;
 
 use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
 use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
 use crate::api::program_wrapper::process_token_escrow_delegate::process_token_escrow_delegate;
 use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
@@ -16,7 +16,7 @@ async fn localnet_token_escrow_create_deposit_delegate(
     let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
 
     // Important keys used in the test
-    let validator = Pubkey::new_unique();
+    let validator = localnet_validator();
 
     let payer = Keypair::new();
     let source = Keypair::new();
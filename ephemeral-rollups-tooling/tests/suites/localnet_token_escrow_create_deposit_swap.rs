@@ -0,0 +1,329 @@
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_nonce_account_create::process_nonce_account_create;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_swap::process_token_escrow_swap;
+use crate::api::program_wrapper::process_token_escrow_swap_signed::process_token_escrow_swap_signed;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_escrow_create_deposit_swap(
+) -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+
+    let authority1 = Keypair::new();
+    let authority2 = Keypair::new();
+
+    let source1 = Keypair::new();
+    let source2 = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create token mints
+    let token_mint_a = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint_a,
+            &token_mint_a.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+    let token_mint_b = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint_b,
+            &token_mint_b.pubkey(),
+            None,
+            9,
+        )
+        .await?;
+
+    // Airdrop token A to the first source wallet
+    let source1_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source1.pubkey(),
+            &token_mint_a.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint_a.pubkey(),
+            &token_mint_a,
+            &source1_token,
+            100_000_000,
+        )
+        .await?;
+
+    // Airdrop token B to the second source wallet
+    let source2_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source2.pubkey(),
+            &token_mint_b.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint_b.pubkey(),
+            &token_mint_b,
+            &source2_token,
+            500_000_000,
+        )
+        .await?;
+
+    // Escrow accounts we will be creating
+    let authority1_token_escrow_slot = 11;
+    let authority1_token_escrow_a_pda = TokenEscrow::generate_pda(
+        &authority1.pubkey(),
+        &validator,
+        &token_mint_a.pubkey(),
+        authority1_token_escrow_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    let authority1_token_escrow_b_pda = TokenEscrow::generate_pda(
+        &authority1.pubkey(),
+        &validator,
+        &token_mint_b.pubkey(),
+        authority1_token_escrow_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    let authority2_token_escrow_slot = 22;
+    let authority2_token_escrow_a_pda = TokenEscrow::generate_pda(
+        &authority2.pubkey(),
+        &validator,
+        &token_mint_a.pubkey(),
+        authority2_token_escrow_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    let authority2_token_escrow_b_pda = TokenEscrow::generate_pda(
+        &authority2.pubkey(),
+        &validator,
+        &token_mint_b.pubkey(),
+        authority2_token_escrow_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+
+    // Prepare being able to escrow both tokens for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint_a.pubkey(),
+    )
+    .await?;
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint_b.pubkey(),
+    )
+    .await?;
+
+    // Create all 4 escrows
+    for (authority, token_mint, slot) in [
+        (&authority1, &token_mint_a, authority1_token_escrow_slot),
+        (&authority1, &token_mint_b, authority1_token_escrow_slot),
+        (&authority2, &token_mint_a, authority2_token_escrow_slot),
+        (&authority2, &token_mint_b, authority2_token_escrow_slot),
+    ] {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            &authority.pubkey(),
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+
+    // Fund the escrows each party will be selling from
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source1,
+        &source1_token,
+        &authority1.pubkey(),
+        &validator,
+        &token_mint_a.pubkey(),
+        authority1_token_escrow_slot,
+        100_000_000,
+    )
+    .await?;
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source2,
+        &source2_token,
+        &authority2.pubkey(),
+        &validator,
+        &token_mint_b.pubkey(),
+        authority2_token_escrow_slot,
+        500_000_000,
+    )
+    .await?;
+
+    // Swap some token A from 1 for some token B from 2
+    process_token_escrow_swap(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority1,
+        &authority2,
+        &validator,
+        &token_mint_a.pubkey(),
+        &token_mint_b.pubkey(),
+        authority1_token_escrow_slot,
+        authority1_token_escrow_slot,
+        authority2_token_escrow_slot,
+        authority2_token_escrow_slot,
+        40_000_000,
+        300_000_000,
+    )
+    .await?;
+
+    // Both legs of the swap should be reflected in the balances
+    for (token_escrow_pda, amount) in [
+        (authority1_token_escrow_a_pda, 60_000_000),
+        (authority1_token_escrow_b_pda, 300_000_000),
+        (authority2_token_escrow_a_pda, 40_000_000),
+        (authority2_token_escrow_b_pda, 200_000_000),
+    ] {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &token_escrow_pda
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // A swap asking for more than available should fail entirely
+    assert!(process_token_escrow_swap(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority1,
+        &authority2,
+        &validator,
+        &token_mint_a.pubkey(),
+        &token_mint_b.pubkey(),
+        authority1_token_escrow_slot,
+        authority1_token_escrow_slot,
+        authority2_token_escrow_slot,
+        authority2_token_escrow_slot,
+        1_000_000,
+        200_000_001,
+    )
+    .await
+    .is_err());
+
+    // Balances should be left untouched by the failed swap
+    for (token_escrow_pda, amount) in [
+        (authority1_token_escrow_a_pda, 60_000_000),
+        (authority1_token_escrow_b_pda, 300_000_000),
+        (authority2_token_escrow_a_pda, 40_000_000),
+        (authority2_token_escrow_b_pda, 200_000_000),
+    ] {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &token_escrow_pda
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // The first party can also pre-sign an offer for the second one to take
+    process_nonce_account_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority1.pubkey(),
+    )
+    .await?;
+    process_token_escrow_swap_signed(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority1,
+        &authority2,
+        &validator,
+        &token_mint_a.pubkey(),
+        &token_mint_b.pubkey(),
+        authority1_token_escrow_slot,
+        authority1_token_escrow_slot,
+        authority2_token_escrow_slot,
+        authority2_token_escrow_slot,
+        10_000_000,
+        50_000_000,
+        1,
+        i64::MAX,
+    )
+    .await?;
+
+    // The same offer can't be taken twice
+    assert!(process_token_escrow_swap_signed(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority1,
+        &authority2,
+        &validator,
+        &token_mint_a.pubkey(),
+        &token_mint_b.pubkey(),
+        authority1_token_escrow_slot,
+        authority1_token_escrow_slot,
+        authority2_token_escrow_slot,
+        authority2_token_escrow_slot,
+        10_000_000,
+        50_000_000,
+        1,
+        i64::MAX,
+    )
+    .await
+    .is_err());
+
+    // Only the taken offer should be reflected in the balances
+    for (token_escrow_pda, amount) in [
+        (authority1_token_escrow_a_pda, 50_000_000),
+        (authority1_token_escrow_b_pda, 350_000_000),
+        (authority2_token_escrow_a_pda, 50_000_000),
+        (authority2_token_escrow_b_pda, 150_000_000),
+    ] {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &token_escrow_pda
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // Done
+    Ok(())
+}
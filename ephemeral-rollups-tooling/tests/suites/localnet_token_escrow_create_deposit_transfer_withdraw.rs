@@ -1,15 +1,19 @@
 use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
-use solana_sdk::pubkey::Pubkey;
+use ephemeral_rollups_wrapper::state::token_vault_state::TokenVaultState;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_toolbox_endpoint::ToolboxEndpointError;
 
 use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_config_admin;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
 use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
 use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
 use crate::api::program_wrapper::process_token_escrow_transfer::process_token_escrow_transfer;
 use crate::api::program_wrapper::process_token_escrow_withdraw::process_token_escrow_withdraw;
+use crate::api::program_wrapper::process_token_vault_audit::process_token_vault_audit;
 use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+use crate::api::program_wrapper::process_token_vault_state_init::process_token_vault_state_init;
 
 #[tokio::test]
 async fn localnet_token_escrow_create_deposit_transfer_withdraw(
@@ -17,8 +21,9 @@ async fn localnet_token_escrow_create_deposit_transfer_withdraw(
     let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
 
     // Important keys used in the test
-    let validator = Pubkey::new_unique();
+    let validator = localnet_validator();
 
+    let admin = localnet_config_admin();
     let payer = Keypair::new();
 
     let authority1 = Keypair::new();
@@ -89,6 +94,18 @@ async fn localnet_token_escrow_create_deposit_transfer_withdraw(
     )
     .await?;
 
+    // The vault state was created along with the vault, it can't be seeded again
+    assert!(process_token_vault_state_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &admin,
+        &validator,
+        &token_mint.pubkey(),
+        0,
+    )
+    .await
+    .is_err());
+
     // Create an escrow
     process_token_escrow_create(
         &mut toolbox_endpoint,
@@ -295,6 +312,15 @@ async fn localnet_token_escrow_create_deposit_transfer_withdraw(
             .amount
     );
 
+    // The vault should be backing everything that was escrowed
+    process_token_vault_audit(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
     // Withdraw everything after that
     let destination_token = toolbox_endpoint
         .process_spl_associated_token_account_get_or_init(
@@ -340,6 +366,30 @@ async fn localnet_token_escrow_create_deposit_transfer_withdraw(
             .amount
     );
 
+    // The vault accounting should reflect all deposits and withdrawals
+    let token_vault_state = toolbox_endpoint
+        .get_account_data_borsh_deserialized::<TokenVaultState>(
+            &TokenVaultState::generate_pda(
+                &validator,
+                &token_mint.pubkey(),
+                &ephemeral_rollups_wrapper::ID,
+            ),
+        )
+        .await?
+        .unwrap();
+    assert_eq!(100_000_000, token_vault_state.total_deposited);
+    assert_eq!(100_000_000, token_vault_state.total_withdrawn);
+    assert_eq!(0, token_vault_state.outstanding_amount);
+
+    // The emptied vault should still pass the audit
+    process_token_vault_audit(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
     // Done
     Ok(())
 }
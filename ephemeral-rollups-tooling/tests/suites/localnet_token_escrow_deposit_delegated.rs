@@ -0,0 +1,183 @@
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit_delegated::process_token_escrow_deposit_approve;
+use crate::api::program_wrapper::process_token_escrow_deposit_delegated::process_token_escrow_deposit_delegated;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_escrow_deposit_delegated(
+) -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+    let crank = Keypair::new();
+
+    let user = Keypair::new();
+
+    // Fund payer and crank
+    for wallet in [&payer, &crank] {
+        toolbox_endpoint
+            .process_airdrop(&wallet.pubkey(), 1_000_000_000_000)
+            .await?;
+    }
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our user wallet
+    let user_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &user.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &user_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create the escrows of the user and of the crank
+    let slot = 3;
+    for authority in [&user.pubkey(), &crank.pubkey()] {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            authority,
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+
+    // Nothing can be pulled before the user approves the vault
+    assert!(process_token_escrow_deposit_delegated(
+        &mut toolbox_endpoint,
+        &crank,
+        &user_token,
+        &user.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        1_000_000,
+    )
+    .await
+    .is_err());
+
+    // The user approves recurring top-ups once
+    process_token_escrow_deposit_approve(
+        &mut toolbox_endpoint,
+        &payer,
+        &user,
+        &user_token,
+        &validator,
+        &token_mint.pubkey(),
+        5_000_000,
+    )
+    .await?;
+
+    // The crank can't credit the approved funds to anyone else's escrow
+    assert!(process_token_escrow_deposit_delegated(
+        &mut toolbox_endpoint,
+        &crank,
+        &user_token,
+        &crank.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        1_000_000,
+    )
+    .await
+    .is_err());
+
+    // The crank tops up the user's escrow in multiple steps
+    for amount in [2_000_000, 3_000_000] {
+        process_token_escrow_deposit_delegated(
+            &mut toolbox_endpoint,
+            &crank,
+            &user_token,
+            &user.pubkey(),
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+            amount,
+        )
+        .await?;
+    }
+
+    // But never more than the approved amount
+    assert!(process_token_escrow_deposit_delegated(
+        &mut toolbox_endpoint,
+        &crank,
+        &user_token,
+        &user.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        500_000,
+    )
+    .await
+    .is_err());
+
+    // Check the balances after the top-ups
+    assert_eq!(
+        5_000_000,
+        toolbox_endpoint
+            .get_account_data_borsh_deserialized::<TokenEscrow>(
+                &TokenEscrow::generate_pda(
+                    &user.pubkey(),
+                    &validator,
+                    &token_mint.pubkey(),
+                    slot,
+                    &ephemeral_rollups_wrapper::ID,
+                )
+            )
+            .await?
+            .unwrap()
+            .amount
+    );
+    assert_eq!(
+        5_000_000,
+        toolbox_endpoint
+            .get_spl_token_account(&user_token)
+            .await?
+            .unwrap()
+            .amount
+    );
+
+    // Done
+    Ok(())
+}
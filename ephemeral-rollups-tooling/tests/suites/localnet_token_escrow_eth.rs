@@ -0,0 +1,233 @@
+use ephemeral_rollups_wrapper::state::eth_authority::eth_authority_generate_pda;
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use libsecp256k1::SecretKey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::get_eth_wallet::get_eth_address;
+use crate::api::program_wrapper::process_nonce_account_create::process_nonce_account_create;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_transfer_eth::process_token_escrow_transfer_eth;
+use crate::api::program_wrapper::process_token_escrow_withdraw_eth::process_token_escrow_withdraw_eth;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_escrow_eth() -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+    let relayer1 = Keypair::new();
+    let relayer2 = Keypair::new();
+
+    let receiver = Keypair::new();
+
+    let source = Keypair::new();
+    let destination = Keypair::new();
+
+    // Ethereum wallets, which never hold any Solana key
+    let eth_wallet = SecretKey::parse(&[42; 32]).unwrap();
+    let eth_wallet_other = SecretKey::parse(&[77; 32]).unwrap();
+
+    // The authority owning the escrows of the Ethereum wallet
+    let eth_authority = eth_authority_generate_pda(
+        &get_eth_address(&eth_wallet),
+        &ephemeral_rollups_wrapper::ID,
+    );
+
+    // Fund payer and relayers
+    for wallet in [&payer, &relayer1, &relayer2] {
+        toolbox_endpoint
+            .process_airdrop(&wallet.pubkey(), 1_000_000_000_000)
+            .await?;
+    }
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create the escrows, one of them owned by the Ethereum wallet
+    let slot = 7;
+    for authority in [&eth_authority, &receiver.pubkey()] {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            authority,
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+    process_nonce_account_create(&mut toolbox_endpoint, &payer, &eth_authority)
+        .await?;
+
+    // Anyone can fund the escrow of the Ethereum wallet
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &eth_authority,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        10_000_000,
+    )
+    .await?;
+
+    // A relayer can submit the transfer signed by the Ethereum wallet
+    process_token_escrow_transfer_eth(
+        &mut toolbox_endpoint,
+        &relayer1,
+        &eth_wallet,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        3_000_000,
+        1,
+        i64::MAX,
+        0,
+        0,
+        0,
+    )
+    .await?;
+
+    // The same intent can't be replayed by another relayer
+    assert!(process_token_escrow_transfer_eth(
+        &mut toolbox_endpoint,
+        &relayer2,
+        &eth_wallet,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        3_000_000,
+        1,
+        i64::MAX,
+        0,
+        0,
+        0,
+    )
+    .await
+    .is_err());
+
+    // Another Ethereum wallet can't move funds it doesn't own
+    assert!(process_token_escrow_transfer_eth(
+        &mut toolbox_endpoint,
+        &relayer1,
+        &eth_wallet_other,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1_000_000,
+        2,
+        i64::MAX,
+        0,
+        0,
+        0,
+    )
+    .await
+    .is_err());
+
+    // The Ethereum wallet can also withdraw to any token account
+    let destination_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &destination.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    process_token_escrow_withdraw_eth(
+        &mut toolbox_endpoint,
+        &relayer2,
+        &eth_wallet,
+        &destination_token,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        2_000_000,
+        2,
+        i64::MAX,
+    )
+    .await?;
+
+    // Check the balances after the transfer and withdrawal
+    for (authority, amount) in
+        [(eth_authority, 5_000_000), (receiver.pubkey(), 3_000_000)]
+    {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &TokenEscrow::generate_pda(
+                        &authority,
+                        &validator,
+                        &token_mint.pubkey(),
+                        slot,
+                        &ephemeral_rollups_wrapper::ID,
+                    )
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+    assert_eq!(
+        2_000_000,
+        toolbox_endpoint
+            .get_spl_token_account(&destination_token)
+            .await?
+            .unwrap()
+            .amount
+    );
+
+    // Done
+    Ok(())
+}
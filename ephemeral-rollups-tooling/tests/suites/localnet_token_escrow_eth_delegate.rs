@@ -0,0 +1,175 @@
+use ephemeral_rollups_wrapper::state::eth_authority::eth_authority_generate_pda;
+use libsecp256k1::SecretKey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::get_eth_wallet::get_eth_address;
+use crate::api::program_wrapper::process_nonce_account_create::process_nonce_account_create;
+use crate::api::program_wrapper::process_nonce_account_delegate_eth::process_nonce_account_delegate_eth;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_delegate_eth::process_token_escrow_delegate_eth;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_escrow_eth_delegate() -> Result<(), ToolboxEndpointError>
+{
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+    let relayer1 = Keypair::new();
+    let relayer2 = Keypair::new();
+
+    // Ethereum wallets, which never hold any Solana key
+    let eth_wallet = SecretKey::parse(&[42; 32]).unwrap();
+    let eth_wallet_other = SecretKey::parse(&[77; 32]).unwrap();
+
+    // The authority owning the escrows of the Ethereum wallet
+    let eth_authority = eth_authority_generate_pda(
+        &get_eth_address(&eth_wallet),
+        &ephemeral_rollups_wrapper::ID,
+    );
+
+    // Fund payer and relayers
+    for wallet in [&payer, &relayer1, &relayer2] {
+        toolbox_endpoint
+            .process_airdrop(&wallet.pubkey(), 1_000_000_000_000)
+            .await?;
+    }
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create the escrows and the nonce account of the Ethereum wallet
+    let slots = [3, 7];
+    for slot in slots {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            &eth_authority,
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+    process_nonce_account_create(&mut toolbox_endpoint, &payer, &eth_authority)
+        .await?;
+
+    // Another Ethereum wallet can't delegate escrows it doesn't own
+    assert!(process_token_escrow_delegate_eth(
+        &mut toolbox_endpoint,
+        &relayer1,
+        &eth_wallet_other,
+        &validator,
+        &token_mint.pubkey(),
+        slots[0],
+        1,
+        i64::MAX,
+    )
+    .await
+    .is_err());
+
+    // A relayer can submit the delegation signed by the Ethereum wallet
+    process_token_escrow_delegate_eth(
+        &mut toolbox_endpoint,
+        &relayer1,
+        &eth_wallet,
+        &validator,
+        &token_mint.pubkey(),
+        slots[0],
+        1,
+        i64::MAX,
+    )
+    .await?;
+
+    // The same intent can't be replayed on the other escrow
+    assert!(process_token_escrow_delegate_eth(
+        &mut toolbox_endpoint,
+        &relayer2,
+        &eth_wallet,
+        &validator,
+        &token_mint.pubkey(),
+        slots[1],
+        1,
+        i64::MAX,
+    )
+    .await
+    .is_err());
+
+    // An expired intent is rejected
+    assert!(process_token_escrow_delegate_eth(
+        &mut toolbox_endpoint,
+        &relayer2,
+        &eth_wallet,
+        &validator,
+        &token_mint.pubkey(),
+        slots[1],
+        2,
+        0,
+    )
+    .await
+    .is_err());
+
+    // Delegate the other escrow with a fresh nonce
+    process_token_escrow_delegate_eth(
+        &mut toolbox_endpoint,
+        &relayer2,
+        &eth_wallet,
+        &validator,
+        &token_mint.pubkey(),
+        slots[1],
+        2,
+        i64::MAX,
+    )
+    .await?;
+
+    // The nonce account goes last, as it's no longer writable on chain after
+    process_nonce_account_delegate_eth(
+        &mut toolbox_endpoint,
+        &relayer1,
+        &eth_wallet,
+        &validator,
+        3,
+        i64::MAX,
+    )
+    .await?;
+
+    // Nothing else can be delegated on chain once the nonce account is gone
+    assert!(process_nonce_account_delegate_eth(
+        &mut toolbox_endpoint,
+        &relayer1,
+        &eth_wallet,
+        &validator,
+        4,
+        i64::MAX,
+    )
+    .await
+    .is_err());
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,230 @@
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_token_escrow_clawback::process_token_escrow_clawback;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_delegate::process_token_escrow_delegate;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_set_frozen::process_token_escrow_set_frozen;
+use crate::api::program_wrapper::process_token_escrow_transfer::process_token_escrow_transfer;
+use crate::api::program_wrapper::process_token_escrow_withdraw::process_token_escrow_withdraw;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_escrow_freeze() -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+
+    let issuer = Keypair::new();
+    let freezer = Keypair::new();
+
+    let holder = Keypair::new();
+    let receiver = Keypair::new();
+
+    let source = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create the regulated token mint, with a freeze authority
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &issuer.pubkey(),
+            Some(&freezer.pubkey()),
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &issuer,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create and fund the escrows
+    let slot = 5;
+    for authority in [&holder.pubkey(), &receiver.pubkey()] {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            authority,
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &holder.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        10_000_000,
+    )
+    .await?;
+
+    // Only the freeze authority of the mint can freeze an escrow
+    assert!(process_token_escrow_set_frozen(
+        &mut toolbox_endpoint,
+        &payer,
+        &issuer,
+        &holder.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        true,
+    )
+    .await
+    .is_err());
+    process_token_escrow_set_frozen(
+        &mut toolbox_endpoint,
+        &payer,
+        &freezer,
+        &holder.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        true,
+    )
+    .await?;
+
+    // A frozen escrow can neither transfer nor withdraw
+    assert!(process_token_escrow_transfer(
+        &mut toolbox_endpoint,
+        &payer,
+        &holder,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1_000_000,
+    )
+    .await
+    .is_err());
+    assert!(process_token_escrow_withdraw(
+        &mut toolbox_endpoint,
+        &payer,
+        &holder,
+        &source_token,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        1_000_000,
+    )
+    .await
+    .is_err());
+
+    // A frozen escrow can't be moved out of reach into the ER either
+    assert!(process_token_escrow_delegate(
+        &mut toolbox_endpoint,
+        &payer,
+        &holder,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+    )
+    .await
+    .is_err());
+
+    // Clawbacks require a Token-2022 mint with a permanent delegate
+    assert!(process_token_escrow_clawback(
+        &mut toolbox_endpoint,
+        &payer,
+        &issuer,
+        &holder.pubkey(),
+        &source_token,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        1_000_000,
+    )
+    .await
+    .is_err());
+
+    // Once thawed, the escrow can be used again
+    process_token_escrow_set_frozen(
+        &mut toolbox_endpoint,
+        &payer,
+        &freezer,
+        &holder.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        false,
+    )
+    .await?;
+    process_token_escrow_transfer(
+        &mut toolbox_endpoint,
+        &payer,
+        &holder,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1_000_000,
+    )
+    .await?;
+
+    // Check the balances after the transfer
+    for (authority, amount) in [(&holder, 9_000_000), (&receiver, 1_000_000)] {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &TokenEscrow::generate_pda(
+                        &authority.pubkey(),
+                        &validator,
+                        &token_mint.pubkey(),
+                        slot,
+                        &ephemeral_rollups_wrapper::ID,
+                    )
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // Done
+    Ok(())
+}
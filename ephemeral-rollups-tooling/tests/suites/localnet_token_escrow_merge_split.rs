@@ -0,0 +1,189 @@
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_merge::process_token_escrow_merge;
+use crate::api::program_wrapper::process_token_escrow_split::process_token_escrow_split;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_escrow_merge_split() -> Result<(), ToolboxEndpointError>
+{
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+
+    let authority = Keypair::new();
+    let spill = Keypair::new();
+
+    let source = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Fund a first escrow
+    let slot1 = 1;
+    let slot2 = 2;
+    process_token_escrow_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot1,
+    )
+    .await?;
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot1,
+        10_000_000,
+    )
+    .await?;
+
+    // Split part of it into a new escrow slot
+    process_token_escrow_split(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &validator,
+        &token_mint.pubkey(),
+        slot1,
+        slot2,
+        4_000_000,
+    )
+    .await?;
+    let token_escrow_pda1 = TokenEscrow::generate_pda(
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot1,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    let token_escrow_pda2 = TokenEscrow::generate_pda(
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot2,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    for (token_escrow_pda, amount) in
+        [(&token_escrow_pda1, 6_000_000), (&token_escrow_pda2, 4_000_000)]
+    {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    token_escrow_pda
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // Can't split more than the available amount
+    assert!(process_token_escrow_split(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &validator,
+        &token_mint.pubkey(),
+        slot1,
+        slot2,
+        6_000_001,
+    )
+    .await
+    .is_err());
+
+    // Merge the second escrow back into the first one and close it
+    let token_escrow_rent =
+        toolbox_endpoint.get_account_lamports(&token_escrow_pda2).await?;
+    process_token_escrow_merge(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &validator,
+        &token_mint.pubkey(),
+        slot2,
+        slot1,
+        true,
+        &spill.pubkey(),
+    )
+    .await?;
+    assert_eq!(
+        10_000_000,
+        toolbox_endpoint
+            .get_account_data_borsh_deserialized::<TokenEscrow>(
+                &token_escrow_pda1
+            )
+            .await?
+            .unwrap()
+            .amount
+    );
+    assert_eq!(
+        0,
+        toolbox_endpoint.get_account_lamports(&token_escrow_pda2).await?
+    );
+    assert_eq!(
+        token_escrow_rent,
+        toolbox_endpoint.get_account_lamports(&spill.pubkey()).await?
+    );
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,203 @@
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use ephemeral_rollups_wrapper::state::token_vault_state::TokenVaultState;
+use ephemeral_rollups_wrapper::state::validator_registry::ValidatorStatus;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_config_admin;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_admin_set_validator::process_admin_set_validator;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_migrate_validator::process_token_escrow_migrate_validator;
+use crate::api::program_wrapper::process_token_vault_audit::process_token_vault_audit;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_escrow_migrate_validator(
+) -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator_a = localnet_validator();
+    let validator_b = Pubkey::new_unique();
+
+    let payer = Keypair::new();
+    let admin = localnet_config_admin();
+
+    let authority = Keypair::new();
+
+    let source = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Approve the second validator
+    process_admin_set_validator(
+        &mut toolbox_endpoint,
+        &payer,
+        &admin,
+        &validator_b,
+        "validator-b",
+        ValidatorStatus::Active,
+    )
+    .await?;
+
+    // Prepare being able to escrow token for both validators
+    let slot = 8;
+    for validator in [&validator_a, &validator_b] {
+        process_token_vault_init(
+            &mut toolbox_endpoint,
+            &payer,
+            validator,
+            &token_mint.pubkey(),
+        )
+        .await?;
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            &authority.pubkey(),
+            validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+
+    // Fund the escrow under the first validator
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &authority.pubkey(),
+        &validator_a,
+        &token_mint.pubkey(),
+        slot,
+        10_000_000,
+    )
+    .await?;
+
+    // Only the authority can migrate its escrow
+    assert!(process_token_escrow_migrate_validator(
+        &mut toolbox_endpoint,
+        &payer,
+        &payer,
+        &validator_a,
+        &validator_b,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        4_000_000,
+    )
+    .await
+    .is_err());
+    process_token_escrow_migrate_validator(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &validator_a,
+        &validator_b,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        4_000_000,
+    )
+    .await?;
+
+    // The escrows and the vaults should reflect the migration
+    for (validator, amount) in
+        [(&validator_a, 6_000_000), (&validator_b, 4_000_000)]
+    {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &TokenEscrow::generate_pda(
+                        &authority.pubkey(),
+                        validator,
+                        &token_mint.pubkey(),
+                        slot,
+                        &ephemeral_rollups_wrapper::ID,
+                    )
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenVaultState>(
+                    &TokenVaultState::generate_pda(
+                        validator,
+                        &token_mint.pubkey(),
+                        &ephemeral_rollups_wrapper::ID,
+                    )
+                )
+                .await?
+                .unwrap()
+                .outstanding_amount
+        );
+        process_token_vault_audit(
+            &mut toolbox_endpoint,
+            &payer,
+            validator,
+            &token_mint.pubkey(),
+        )
+        .await?;
+    }
+
+    // Can't migrate more than what's left
+    assert!(process_token_escrow_migrate_validator(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &validator_a,
+        &validator_b,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        6_000_001,
+    )
+    .await
+    .is_err());
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_token_escrow_burn::process_token_escrow_burn;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_mint_to::process_token_escrow_mint_to;
+use crate::api::program_wrapper::process_token_vault_audit::process_token_vault_audit;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_escrow_mint_to_burn() -> Result<(), ToolboxEndpointError>
+{
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+
+    let issuer = Keypair::new();
+    let impostor = Keypair::new();
+
+    let player = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create the game currency, controlled by the issuer
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &issuer.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create the player's escrow
+    let slot = 2;
+    process_token_escrow_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &player.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+    )
+    .await?;
+
+    // Only the mint authority can mint into escrows
+    assert!(process_token_escrow_mint_to(
+        &mut toolbox_endpoint,
+        &payer,
+        &impostor,
+        &player.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        1_000_000,
+    )
+    .await
+    .is_err());
+
+    // The issuer credits the player directly
+    process_token_escrow_mint_to(
+        &mut toolbox_endpoint,
+        &payer,
+        &issuer,
+        &player.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        6_000_000,
+    )
+    .await?;
+
+    // The player burns part of its balance
+    process_token_escrow_burn(
+        &mut toolbox_endpoint,
+        &payer,
+        &player,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        2_000_000,
+    )
+    .await?;
+
+    // The player can't burn more than it has
+    assert!(process_token_escrow_burn(
+        &mut toolbox_endpoint,
+        &payer,
+        &player,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        5_000_000,
+    )
+    .await
+    .is_err());
+
+    // Check the escrow balance after minting and burning
+    assert_eq!(
+        4_000_000,
+        toolbox_endpoint
+            .get_account_data_borsh_deserialized::<TokenEscrow>(
+                &TokenEscrow::generate_pda(
+                    &player.pubkey(),
+                    &validator,
+                    &token_mint.pubkey(),
+                    slot,
+                    &ephemeral_rollups_wrapper::ID,
+                )
+            )
+            .await?
+            .unwrap()
+            .amount
+    );
+
+    // The vault still holds exactly what is escrowed
+    process_token_vault_audit(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use ephemeral_rollups_wrapper::state::multisig::Multisig;
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_multisig_create::process_multisig_create;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_transfer_multisig::process_token_escrow_transfer_multisig;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_escrow_multisig_transfer(
+) -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+
+    let signer1 = Keypair::new();
+    let signer2 = Keypair::new();
+    let signer3 = Keypair::new();
+    let outsider = Keypair::new();
+
+    let destination = Pubkey::new_unique();
+
+    let source = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // A threshold higher than the number of signers should fail
+    assert!(process_multisig_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &payer,
+        0,
+        3,
+        &[signer1.pubkey(), signer2.pubkey()],
+    )
+    .await
+    .is_err());
+
+    // Create a 2-of-3 multisig that will act as the treasury authority
+    let multisig_slot = 0;
+    let multisig_pda = Multisig::generate_pda(
+        &payer.pubkey(),
+        multisig_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    process_multisig_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &payer,
+        multisig_slot,
+        2,
+        &[signer1.pubkey(), signer2.pubkey(), signer3.pubkey()],
+    )
+    .await?;
+
+    // Escrow accounts we will be using
+    let treasury_slot = 3;
+    let treasury_token_escrow_pda = TokenEscrow::generate_pda(
+        &multisig_pda,
+        &validator,
+        &token_mint.pubkey(),
+        treasury_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    let destination_slot = 4;
+    let destination_token_escrow_pda = TokenEscrow::generate_pda(
+        &destination,
+        &validator,
+        &token_mint.pubkey(),
+        destination_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create both escrows
+    for (authority, slot) in
+        [(&multisig_pda, treasury_slot), (&destination, destination_slot)]
+    {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            authority,
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+
+    // Fund the treasury
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &multisig_pda,
+        &validator,
+        &token_mint.pubkey(),
+        treasury_slot,
+        10_000_000,
+    )
+    .await?;
+
+    // Not enough (valid) signatures should fail
+    for signers in [vec![&signer1], vec![&signer1, &outsider]] {
+        assert!(process_token_escrow_transfer_multisig(
+            &mut toolbox_endpoint,
+            &payer,
+            &multisig_pda,
+            &signers,
+            &destination,
+            &validator,
+            &token_mint.pubkey(),
+            treasury_slot,
+            destination_slot,
+            1_000_000,
+        )
+        .await
+        .is_err());
+    }
+
+    // Two of the three signers are enough
+    process_token_escrow_transfer_multisig(
+        &mut toolbox_endpoint,
+        &payer,
+        &multisig_pda,
+        &[&signer1, &signer3],
+        &destination,
+        &validator,
+        &token_mint.pubkey(),
+        treasury_slot,
+        destination_slot,
+        1_000_000,
+    )
+    .await?;
+
+    // Check the balances
+    for (token_escrow_pda, amount) in [
+        (treasury_token_escrow_pda, 9_000_000),
+        (destination_token_escrow_pda, 1_000_000),
+    ] {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &token_escrow_pda
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // Done
+    Ok(())
+}
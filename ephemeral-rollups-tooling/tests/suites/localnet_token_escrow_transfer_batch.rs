@@ -0,0 +1,160 @@
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_transfer_batch::process_token_escrow_transfer_batch;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_escrow_transfer_batch(
+) -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+
+    let sender = Keypair::new();
+    let players = [Keypair::new(), Keypair::new(), Keypair::new()];
+
+    let source = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create the escrows of the sender and of all the players
+    let slot = 5;
+    for authority in [&sender, &players[0], &players[1], &players[2]] {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            &authority.pubkey(),
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+
+    // Fund the sender's escrow
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &sender.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        10_000_000,
+    )
+    .await?;
+
+    // A payout round exceeding the balance fails without paying anyone
+    assert!(process_token_escrow_transfer_batch(
+        &mut toolbox_endpoint,
+        &payer,
+        &sender,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        &[
+            (players[0].pubkey(), slot, 4_000_000),
+            (players[1].pubkey(), slot, 4_000_000),
+            (players[2].pubkey(), slot, 4_000_000),
+        ],
+    )
+    .await
+    .is_err());
+
+    // Pay out all the players at once
+    process_token_escrow_transfer_batch(
+        &mut toolbox_endpoint,
+        &payer,
+        &sender,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        &[
+            (players[0].pubkey(), slot, 1_000_000),
+            (players[1].pubkey(), slot, 2_000_000),
+            (players[2].pubkey(), slot, 3_000_000),
+        ],
+    )
+    .await?;
+
+    // Check the balances after the payout
+    for (authority, amount) in [
+        (&sender, 4_000_000),
+        (&players[0], 1_000_000),
+        (&players[1], 2_000_000),
+        (&players[2], 3_000_000),
+    ] {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &TokenEscrow::generate_pda(
+                        &authority.pubkey(),
+                        &validator,
+                        &token_mint.pubkey(),
+                        slot,
+                        &ephemeral_rollups_wrapper::ID,
+                    )
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_transfer::process_token_escrow_transfer;
+use crate::api::program_wrapper::process_token_escrow_transfer_create::process_token_escrow_transfer_create;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_escrow_transfer_create(
+) -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+
+    let sender = Keypair::new();
+    let receiver = Keypair::new();
+
+    let source = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Only the sender creates an escrow
+    let slot = 5;
+    process_token_escrow_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &sender.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+    )
+    .await?;
+
+    // Fund the sender's escrow
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &sender.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        10_000_000,
+    )
+    .await?;
+
+    // A regular transfer requires the receiver's escrow to exist
+    assert!(process_token_escrow_transfer(
+        &mut toolbox_endpoint,
+        &payer,
+        &sender,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1_000_000,
+    )
+    .await
+    .is_err());
+
+    // The payer can create the receiver's escrow as part of the transfer
+    process_token_escrow_transfer_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &sender,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1_000_000,
+    )
+    .await?;
+
+    // Once created, the escrow is reused by later transfers
+    process_token_escrow_transfer_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &sender,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        2_000_000,
+    )
+    .await?;
+
+    // Check the balances after the transfers
+    for (authority, amount) in [(&sender, 7_000_000), (&receiver, 3_000_000)] {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &TokenEscrow::generate_pda(
+                        &authority.pubkey(),
+                        &validator,
+                        &token_mint.pubkey(),
+                        slot,
+                        &ephemeral_rollups_wrapper::ID,
+                    )
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // Done
+    Ok(())
+}
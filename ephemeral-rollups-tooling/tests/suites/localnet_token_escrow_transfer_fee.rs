@@ -0,0 +1,231 @@
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_config_admin;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_admin_set_token_fee::process_admin_set_token_fee;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_transfer::process_token_escrow_transfer;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_escrow_transfer_fee() -> Result<(), ToolboxEndpointError>
+{
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+    let admin = localnet_config_admin();
+
+    let sender = Keypair::new();
+    let receiver = Keypair::new();
+    let collector = Keypair::new();
+
+    let source = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Escrow accounts we will be using
+    let slot = 3;
+    let sender_token_escrow_pda = TokenEscrow::generate_pda(
+        &sender.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    let receiver_token_escrow_pda = TokenEscrow::generate_pda(
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    let collector_token_escrow_pda = TokenEscrow::generate_pda(
+        &collector.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create all escrows, including the one collecting the fees
+    for authority in [&sender, &receiver, &collector] {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            &authority.pubkey(),
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+
+    // Fund the sender's escrow
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &sender.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        10_000_000,
+    )
+    .await?;
+
+    // Without a fee schedule, transfers are free
+    process_token_escrow_transfer(
+        &mut toolbox_endpoint,
+        &payer,
+        &sender,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1_000_000,
+    )
+    .await?;
+
+    // Only the admin can set a fee schedule
+    assert!(process_admin_set_token_fee(
+        &mut toolbox_endpoint,
+        &payer,
+        &sender,
+        &validator,
+        &token_mint.pubkey(),
+        100,
+        50_000,
+        &sender.pubkey(),
+        slot,
+    )
+    .await
+    .is_err());
+    process_admin_set_token_fee(
+        &mut toolbox_endpoint,
+        &payer,
+        &admin,
+        &validator,
+        &token_mint.pubkey(),
+        100,
+        50_000,
+        &collector.pubkey(),
+        slot,
+    )
+    .await?;
+
+    // Small transfers pay the minimum fee
+    process_token_escrow_transfer(
+        &mut toolbox_endpoint,
+        &payer,
+        &sender,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1_000_000,
+    )
+    .await?;
+
+    // Larger transfers pay the proportional fee
+    process_token_escrow_transfer(
+        &mut toolbox_endpoint,
+        &payer,
+        &sender,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        8_000_000,
+    )
+    .await?;
+
+    // Transfers smaller than the minimum fee are refused
+    assert!(process_token_escrow_transfer(
+        &mut toolbox_endpoint,
+        &payer,
+        &receiver,
+        &sender.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        10_000,
+    )
+    .await
+    .is_err());
+
+    // The fees should have been split into the collector's escrow
+    for (token_escrow_pda, amount) in [
+        (sender_token_escrow_pda, 0),
+        (receiver_token_escrow_pda, 9_870_000),
+        (collector_token_escrow_pda, 130_000),
+    ] {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &token_escrow_pda
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // Done
+    Ok(())
+}
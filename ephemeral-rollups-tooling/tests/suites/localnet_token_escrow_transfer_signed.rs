@@ -0,0 +1,368 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_transfer_signed;
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::get_token_fee_collector::get_token_fee_collector;
+use crate::api::program_wrapper::process_nonce_account_create::process_nonce_account_create;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_transfer_signed::process_token_escrow_transfer_signed;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_escrow_transfer_signed(
+) -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+    let relayer1 = Keypair::new();
+    let relayer2 = Keypair::new();
+
+    let sender = Keypair::new();
+    let receiver = Keypair::new();
+
+    let source = Keypair::new();
+
+    // Fund payer and relayers (the sender never pays anything)
+    for wallet in [&payer, &relayer1, &relayer2] {
+        toolbox_endpoint
+            .process_airdrop(&wallet.pubkey(), 1_000_000_000_000)
+            .await?;
+    }
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create and fund the escrows
+    let slot = 4;
+    for authority in [&sender.pubkey(), &receiver.pubkey()] {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            authority,
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &sender.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        10_000_000,
+    )
+    .await?;
+
+    // Signed intents can't be used until the sender has a nonce account
+    assert!(process_token_escrow_transfer_signed(
+        &mut toolbox_endpoint,
+        &relayer1,
+        &sender,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1_000_000,
+        0,
+        i64::MAX,
+        0,
+        0,
+        0,
+    )
+    .await
+    .is_err());
+    process_nonce_account_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &sender.pubkey(),
+    )
+    .await?;
+
+    // A relayer can submit the sender's signed intent
+    process_token_escrow_transfer_signed(
+        &mut toolbox_endpoint,
+        &relayer1,
+        &sender,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1_000_000,
+        1,
+        i64::MAX,
+        0,
+        0,
+        0,
+    )
+    .await?;
+
+    // The same intent can't be replayed by another relayer
+    assert!(process_token_escrow_transfer_signed(
+        &mut toolbox_endpoint,
+        &relayer2,
+        &sender,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1_000_000,
+        1,
+        i64::MAX,
+        0,
+        0,
+        0,
+    )
+    .await
+    .is_err());
+
+    // Expired intents are rejected
+    assert!(process_token_escrow_transfer_signed(
+        &mut toolbox_endpoint,
+        &relayer2,
+        &sender,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1_000_000,
+        2,
+        0,
+        0,
+        0,
+        0,
+    )
+    .await
+    .is_err());
+
+    // A fresh nonce goes through
+    process_token_escrow_transfer_signed(
+        &mut toolbox_endpoint,
+        &relayer2,
+        &sender,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        2_000_000,
+        2,
+        i64::MAX,
+        0,
+        0,
+        0,
+    )
+    .await?;
+
+    // Nonces below the last one used are stale, even for a new intent
+    assert!(process_token_escrow_transfer_signed(
+        &mut toolbox_endpoint,
+        &relayer1,
+        &sender,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        500_000,
+        1,
+        i64::MAX,
+        0,
+        0,
+        0,
+    )
+    .await
+    .is_err());
+
+    // The relayer can be compensated from the sender's balance
+    process_token_escrow_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &relayer1.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+    )
+    .await?;
+
+    // But never more than the maximum signed by the sender
+    assert!(process_token_escrow_transfer_signed(
+        &mut toolbox_endpoint,
+        &relayer1,
+        &sender,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1_000_000,
+        3,
+        i64::MAX,
+        slot,
+        300_000,
+        200_000,
+    )
+    .await
+    .is_err());
+
+    // Another relayer can't redirect the fee signed for the first relayer
+    process_token_escrow_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &relayer2.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+    )
+    .await?;
+    let message = token_escrow_transfer_signed::message(
+        &sender.pubkey(),
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1_000_000,
+        3,
+        i64::MAX,
+        &relayer1.pubkey(),
+        slot,
+        200_000,
+    );
+    let signature = sender.sign_message(&message);
+    let ed25519_instruction = token_escrow_transfer_signed::ed25519_instruction(
+        &sender.pubkey(),
+        &<[u8; 64]>::try_from(signature.as_ref()).unwrap(),
+        &message,
+    );
+    let (fee_collector_authority, fee_collector_slot) =
+        get_token_fee_collector(
+            &mut toolbox_endpoint,
+            &validator,
+            &token_mint.pubkey(),
+        )
+        .await?;
+    let instruction = token_escrow_transfer_signed::instruction(
+        &sender.pubkey(),
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1_000_000,
+        3,
+        i64::MAX,
+        &fee_collector_authority,
+        fee_collector_slot,
+        &relayer2.pubkey(),
+        slot,
+        100_000,
+        200_000,
+    );
+    assert!(toolbox_endpoint
+        .process_instructions_with_signers(
+            &[ed25519_instruction, instruction],
+            &relayer2,
+            &[],
+        )
+        .await
+        .is_err());
+
+    process_token_escrow_transfer_signed(
+        &mut toolbox_endpoint,
+        &relayer1,
+        &sender,
+        &receiver.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        slot,
+        1_000_000,
+        3,
+        i64::MAX,
+        slot,
+        100_000,
+        200_000,
+    )
+    .await?;
+
+    // Check the balances after the transfers
+    for (authority, amount) in [
+        (&sender, 5_900_000),
+        (&receiver, 4_000_000),
+        (&relayer1, 100_000),
+        (&relayer2, 0),
+    ] {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &TokenEscrow::generate_pda(
+                        &authority.pubkey(),
+                        &validator,
+                        &token_mint.pubkey(),
+                        slot,
+                        &ephemeral_rollups_wrapper::ID,
+                    )
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // Done
+    Ok(())
+}
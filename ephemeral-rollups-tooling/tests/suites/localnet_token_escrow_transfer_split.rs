@@ -0,0 +1,200 @@
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_split_config_create::process_split_config_create;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_transfer_split::process_token_escrow_transfer_split;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_escrow_transfer_split(
+) -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+
+    let sender = Keypair::new();
+    let creator = Keypair::new();
+    let platform = Keypair::new();
+    let referrer = Keypair::new();
+
+    let source = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create the escrows of the sender and of all the recipients
+    let slot = 5;
+    for authority in [&sender, &creator, &platform, &referrer] {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            &authority.pubkey(),
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+
+    // Fund the sender's escrow
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &sender.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        10_000_000,
+    )
+    .await?;
+
+    // Revenue is shared between the creator, the platform and the referrer
+    let split_slot = 1;
+    let split_destinations = [
+        (creator.pubkey(), slot),
+        (platform.pubkey(), slot),
+        (referrer.pubkey(), slot),
+    ];
+
+    // The shares must add up to exactly 100%
+    assert!(process_split_config_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &sender,
+        split_slot,
+        &[
+            (creator.pubkey(), slot, 5_000),
+            (platform.pubkey(), slot, 3_000),
+            (referrer.pubkey(), slot, 1_000),
+        ],
+    )
+    .await
+    .is_err());
+    process_split_config_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &sender,
+        split_slot,
+        &[
+            (creator.pubkey(), slot, 5_000),
+            (platform.pubkey(), slot, 3_000),
+            (referrer.pubkey(), slot, 2_000),
+        ],
+    )
+    .await?;
+
+    // Destination escrows must match the split config
+    assert!(process_token_escrow_transfer_split(
+        &mut toolbox_endpoint,
+        &payer,
+        &sender,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        &sender.pubkey(),
+        split_slot,
+        &[
+            (creator.pubkey(), slot),
+            (creator.pubkey(), slot),
+            (referrer.pubkey(), slot),
+        ],
+        1_000_001,
+    )
+    .await
+    .is_err());
+
+    // Split a payment, the rounding remainder goes to the first recipient
+    process_token_escrow_transfer_split(
+        &mut toolbox_endpoint,
+        &payer,
+        &sender,
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        &sender.pubkey(),
+        split_slot,
+        &split_destinations,
+        1_000_001,
+    )
+    .await?;
+
+    // Check the balances after the split
+    for (authority, amount) in [
+        (&sender, 8_999_999),
+        (&creator, 500_001),
+        (&platform, 300_000),
+        (&referrer, 200_000),
+    ] {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &TokenEscrow::generate_pda(
+                        &authority.pubkey(),
+                        &validator,
+                        &token_mint.pubkey(),
+                        slot,
+                        &ephemeral_rollups_wrapper::ID,
+                    )
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+
+    // Done
+    Ok(())
+}
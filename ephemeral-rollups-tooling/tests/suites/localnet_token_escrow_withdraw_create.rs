@@ -0,0 +1,224 @@
+use ephemeral_rollups_wrapper::instruction::token_escrow_withdraw_create;
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_withdraw_create::process_token_escrow_withdraw_create;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_escrow_withdraw_create(
+) -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+
+    let authority = Keypair::new();
+
+    let source = Keypair::new();
+    let destination = Keypair::new();
+    let existing_destination = Keypair::new();
+    let attacker = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create and fund the escrow
+    let slot = 9;
+    process_token_escrow_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+    )
+    .await?;
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        10_000_000,
+    )
+    .await?;
+
+    // The destination must be the wallet's associated token account
+    let token_program_id =
+        toolbox_endpoint.get_account_owner(&token_mint.pubkey()).await?;
+    let mut instruction = token_escrow_withdraw_create::instruction(
+        &payer.pubkey(),
+        &authority.pubkey(),
+        &destination.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        &token_program_id,
+        slot,
+        1_000_000,
+    );
+    instruction.accounts[3].pubkey = source_token;
+    assert!(toolbox_endpoint
+        .process_instruction_with_signers(instruction, &payer, &[&authority])
+        .await
+        .is_err());
+
+    // The associated token account must belong to the destination wallet
+    let mut instruction = token_escrow_withdraw_create::instruction(
+        &payer.pubkey(),
+        &authority.pubkey(),
+        &destination.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        &token_program_id,
+        slot,
+        1_000_000,
+    );
+    instruction.accounts[2].pubkey = attacker.pubkey();
+    assert!(toolbox_endpoint
+        .process_instruction_with_signers(instruction, &payer, &[&authority])
+        .await
+        .is_err());
+
+    // Withdraw to a wallet that never had a token account for this mint
+    process_token_escrow_withdraw_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &destination.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        4_000_000,
+    )
+    .await?;
+
+    // Withdrawing again works now that the token account already exists
+    process_token_escrow_withdraw_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &destination.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        1_000_000,
+    )
+    .await?;
+
+    // Withdraw to a wallet whose token account was created beforehand
+    let existing_destination_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &existing_destination.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    process_token_escrow_withdraw_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &existing_destination.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        2_000_000,
+    )
+    .await?;
+
+    // Check the balances after the withdrawals
+    let destination_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &destination.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    assert_eq!(
+        5_000_000,
+        toolbox_endpoint
+            .get_spl_token_account(&destination_token)
+            .await?
+            .unwrap()
+            .amount
+    );
+    assert_eq!(
+        2_000_000,
+        toolbox_endpoint
+            .get_spl_token_account(&existing_destination_token)
+            .await?
+            .unwrap()
+            .amount
+    );
+    assert_eq!(
+        3_000_000,
+        toolbox_endpoint
+            .get_account_data_borsh_deserialized::<TokenEscrow>(
+                &TokenEscrow::generate_pda(
+                    &authority.pubkey(),
+                    &validator,
+                    &token_mint.pubkey(),
+                    slot,
+                    &ephemeral_rollups_wrapper::ID,
+                )
+            )
+            .await?
+            .unwrap()
+            .amount
+    );
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use ephemeral_rollups_wrapper::audit::token_vault_reconcile::token_vault_reconcile;
+use ephemeral_rollups_wrapper::audit::token_vault_reconcile::AccountDump;
+use ephemeral_rollups_wrapper::audit::token_vault_reconcile::TokenEscrowSeeds;
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use ephemeral_rollups_wrapper::state::token_vault::token_vault_generate_pda;
+use ephemeral_rollups_wrapper::state::token_vault_state::TokenVaultState;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_transfer::process_token_escrow_transfer;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_token_vault_reconcile() -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+
+    let authority1 = Keypair::new();
+    let authority2 = Keypair::new();
+    let authority3 = Keypair::new();
+
+    let source = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Escrow accounts we will be using
+    let slot = 0;
+    let token_escrows_seeds =
+        [&authority1, &authority2, &authority3].map(|authority| {
+            TokenEscrowSeeds {
+                authority: authority.pubkey(),
+                funder: None,
+                validator,
+                token_mint: token_mint.pubkey(),
+                slot,
+            }
+        });
+    let token_escrows_pdas = token_escrows_seeds.map(|seeds| {
+        TokenEscrow::generate_pda(
+            &seeds.authority,
+            &seeds.validator,
+            &seeds.token_mint,
+            seeds.slot,
+            &ephemeral_rollups_wrapper::ID,
+        )
+    });
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create all escrows
+    for seeds in &token_escrows_seeds {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            &seeds.authority,
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+
+    // Fund and move some balances around
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &authority1.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        slot,
+        10_000_000,
+    )
+    .await?;
+    for (authority, amount) in
+        [(&authority2, 3_000_000), (&authority3, 2_000_000)]
+    {
+        process_token_escrow_transfer(
+            &mut toolbox_endpoint,
+            &payer,
+            &authority1,
+            &authority.pubkey(),
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+            slot,
+            amount,
+        )
+        .await?;
+    }
+
+    // Take a snapshot of all the relevant accounts
+    let mut addresses = token_escrows_pdas.to_vec();
+    addresses.push(token_vault_generate_pda(
+        &validator,
+        &token_mint.pubkey(),
+        &ephemeral_rollups_wrapper::ID,
+    ));
+    addresses.push(TokenVaultState::generate_pda(
+        &validator,
+        &token_mint.pubkey(),
+        &ephemeral_rollups_wrapper::ID,
+    ));
+    let mut accounts = vec![];
+    for address in addresses {
+        let account = toolbox_endpoint.get_account(&address).await?.unwrap();
+        accounts.push(AccountDump {
+            address,
+            owner: account.owner,
+            data: account.data,
+        });
+    }
+
+    // With all the seeds known, every balance should be accounted for
+    let report = token_vault_reconcile(
+        &ephemeral_rollups_wrapper::ID,
+        &[validator],
+        &HashMap::from_iter(
+            token_escrows_pdas.into_iter().zip(token_escrows_seeds),
+        ),
+        &accounts,
+    );
+    assert!(report.is_clean());
+    assert_eq!(1, report.token_vaults.len());
+    assert_eq!(3, report.token_vaults[0].token_escrows_count);
+    assert_eq!(10_000_000, report.token_vaults[0].token_escrows_amount);
+    assert_eq!(10_000_000, report.token_vaults[0].token_vault_amount);
+    assert_eq!(
+        Some(10_000_000),
+        report.token_vaults[0].token_vault_state_outstanding_amount
+    );
+
+    // Unknown and mismatched seeds should be flagged
+    let report = token_vault_reconcile(
+        &ephemeral_rollups_wrapper::ID,
+        &[validator],
+        &HashMap::from([
+            (token_escrows_pdas[0], token_escrows_seeds[0]),
+            (token_escrows_pdas[1], token_escrows_seeds[2]),
+        ]),
+        &accounts,
+    );
+    assert!(!report.is_clean());
+    assert_eq!(vec![token_escrows_pdas[2]], report.orphan_accounts);
+    assert_eq!(vec![token_escrows_pdas[1]], report.misderived_accounts);
+    assert_eq!(5_000_000, report.token_vaults[0].token_escrows_amount);
+    assert!(report.token_vaults[0].is_solvent());
+    assert!(!report.token_vaults[0].is_consistent());
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,265 @@
+use ephemeral_rollups_wrapper::state::token_escrow::TokenEscrow;
+use ephemeral_rollups_wrapper::state::token_vesting::TokenVesting;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_validator;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_deposit::process_token_escrow_deposit;
+use crate::api::program_wrapper::process_token_escrow_transfer::process_token_escrow_transfer;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+use crate::api::program_wrapper::process_token_vesting_create::process_token_vesting_create;
+use crate::api::program_wrapper::process_token_vesting_release::process_token_vesting_release;
+
+#[tokio::test]
+async fn localnet_token_vesting_create_release(
+) -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = localnet_validator();
+
+    let payer = Keypair::new();
+
+    let funder = Keypair::new();
+    let beneficiary = Keypair::new();
+
+    let source = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Airdrop token to our source wallet
+    let source_token = toolbox_endpoint
+        .process_spl_associated_token_account_get_or_init(
+            &payer,
+            &source.pubkey(),
+            &token_mint.pubkey(),
+        )
+        .await?;
+    toolbox_endpoint
+        .process_spl_token_mint_to(
+            &payer,
+            &token_mint.pubkey(),
+            &token_mint,
+            &source_token,
+            10_000_000,
+        )
+        .await?;
+
+    // Escrow and vesting accounts we will be using
+    let funder_slot = 0;
+    let funder_token_escrow_pda = TokenEscrow::generate_pda(
+        &funder.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        funder_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    let vested_slot = 1;
+    let vested_token_escrow_pda = TokenEscrow::generate_pda(
+        &beneficiary.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        vested_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    let vested_token_vesting_pda = TokenVesting::generate_pda(
+        &beneficiary.pubkey(),
+        &funder.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        vested_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+    let locked_slot = 2;
+    let locked_token_escrow_pda = TokenEscrow::generate_pda(
+        &beneficiary.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        locked_slot,
+        &ephemeral_rollups_wrapper::ID,
+    );
+
+    // Prepare being able to escrow token for this validator
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+
+    // Create all escrows
+    for (authority, slot) in [
+        (&funder, funder_slot),
+        (&beneficiary, vested_slot),
+        (&beneficiary, locked_slot),
+    ] {
+        process_token_escrow_create(
+            &mut toolbox_endpoint,
+            &payer,
+            &authority.pubkey(),
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+
+    // Fund the funder's escrow
+    process_token_escrow_deposit(
+        &mut toolbox_endpoint,
+        &payer,
+        &source,
+        &source_token,
+        &funder.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        funder_slot,
+        10_000_000,
+    )
+    .await?;
+
+    // A schedule that ends before it starts should fail
+    assert!(process_token_vesting_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &funder,
+        &beneficiary.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        funder_slot,
+        vested_slot,
+        1_000_000,
+        100,
+        100,
+        50,
+    )
+    .await
+    .is_err());
+
+    // A schedule that has already ended (fully vested)
+    process_token_vesting_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &funder,
+        &beneficiary.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        funder_slot,
+        vested_slot,
+        4_000_000,
+        0,
+        0,
+        1,
+    )
+    .await?;
+
+    // A schedule whose cliff will not be reached (fully locked)
+    process_token_vesting_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &funder,
+        &beneficiary.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        funder_slot,
+        locked_slot,
+        5_000_000,
+        0,
+        i64::MAX - 1,
+        i64::MAX,
+    )
+    .await?;
+
+    // The locked amounts must not be spendable yet
+    assert!(process_token_escrow_transfer(
+        &mut toolbox_endpoint,
+        &payer,
+        &beneficiary,
+        &funder.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        vested_slot,
+        funder_slot,
+        1,
+    )
+    .await
+    .is_err());
+
+    // Anyone can release the unlocked amounts
+    for slot in [vested_slot, locked_slot] {
+        process_token_vesting_release(
+            &mut toolbox_endpoint,
+            &payer,
+            &beneficiary.pubkey(),
+            &funder.pubkey(),
+            &validator,
+            &token_mint.pubkey(),
+            slot,
+        )
+        .await?;
+    }
+
+    // Check the balances, only the fully vested amount should be released
+    for (token_escrow_pda, amount) in [
+        (funder_token_escrow_pda, 1_000_000),
+        (vested_token_escrow_pda, 4_000_000),
+        (locked_token_escrow_pda, 0),
+    ] {
+        assert_eq!(
+            amount,
+            toolbox_endpoint
+                .get_account_data_borsh_deserialized::<TokenEscrow>(
+                    &token_escrow_pda
+                )
+                .await?
+                .unwrap()
+                .amount
+        );
+    }
+    assert_eq!(
+        4_000_000,
+        toolbox_endpoint
+            .get_account_data_borsh_deserialized::<TokenVesting>(
+                &vested_token_vesting_pda
+            )
+            .await?
+            .unwrap()
+            .released_amount
+    );
+
+    // The released amount is now spendable
+    process_token_escrow_transfer(
+        &mut toolbox_endpoint,
+        &payer,
+        &beneficiary,
+        &funder.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        vested_slot,
+        funder_slot,
+        4_000_000,
+    )
+    .await?;
+
+    // Done
+    Ok(())
+}
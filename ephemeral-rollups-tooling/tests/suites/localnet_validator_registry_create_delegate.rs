@@ -0,0 +1,138 @@
+use ephemeral_rollups_wrapper::state::validator_registry::ValidatorStatus;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_toolbox_endpoint::ToolboxEndpointError;
+
+use crate::api::create_localnet_toolbox_endpoint::create_localnet_toolbox_endpoint;
+use crate::api::create_localnet_toolbox_endpoint::localnet_config_admin;
+use crate::api::program_wrapper::process_admin_set_validator::process_admin_set_validator;
+use crate::api::program_wrapper::process_token_escrow_create::process_token_escrow_create;
+use crate::api::program_wrapper::process_token_escrow_delegate::process_token_escrow_delegate;
+use crate::api::program_wrapper::process_token_vault_init::process_token_vault_init;
+
+#[tokio::test]
+async fn localnet_validator_registry_create_delegate(
+) -> Result<(), ToolboxEndpointError> {
+    let mut toolbox_endpoint = create_localnet_toolbox_endpoint().await;
+
+    // Important keys used in the test
+    let validator = Pubkey::new_unique();
+
+    let payer = Keypair::new();
+    let admin = localnet_config_admin();
+
+    let authority = Keypair::new();
+
+    // Fund payer
+    toolbox_endpoint
+        .process_airdrop(&payer.pubkey(), 1_000_000_000_000)
+        .await?;
+
+    // Create token mint
+    let token_mint = Keypair::new();
+    toolbox_endpoint
+        .process_spl_token_mint_init(
+            &payer,
+            &token_mint,
+            &token_mint.pubkey(),
+            None,
+            6,
+        )
+        .await?;
+
+    // Nothing can be created for a validator that was never approved
+    assert!(process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await
+    .is_err());
+    assert!(process_token_escrow_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        0,
+    )
+    .await
+    .is_err());
+
+    // Only the admin can approve a validator
+    assert!(process_admin_set_validator(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &validator,
+        "rogue",
+        ValidatorStatus::Active,
+    )
+    .await
+    .is_err());
+    process_admin_set_validator(
+        &mut toolbox_endpoint,
+        &payer,
+        &admin,
+        &validator,
+        "eu-west",
+        ValidatorStatus::Active,
+    )
+    .await?;
+
+    // Once approved, the validator can be used
+    process_token_vault_init(
+        &mut toolbox_endpoint,
+        &payer,
+        &validator,
+        &token_mint.pubkey(),
+    )
+    .await?;
+    process_token_escrow_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        0,
+    )
+    .await?;
+
+    // Retire the validator
+    process_admin_set_validator(
+        &mut toolbox_endpoint,
+        &payer,
+        &admin,
+        &validator,
+        "eu-west",
+        ValidatorStatus::Retired,
+    )
+    .await?;
+
+    // A retired validator can't receive new escrows nor delegations
+    assert!(process_token_escrow_create(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority.pubkey(),
+        &validator,
+        &token_mint.pubkey(),
+        1,
+    )
+    .await
+    .is_err());
+    assert!(process_token_escrow_delegate(
+        &mut toolbox_endpoint,
+        &payer,
+        &authority,
+        &validator,
+        &token_mint.pubkey(),
+        0,
+    )
+    .await
+    .is_err());
+
+    // Done
+    Ok(())
+}
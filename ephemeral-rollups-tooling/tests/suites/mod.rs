@@ -4,7 +4,32 @@ pub mod devnet_lamport_payer_delegate_on_curve_transfer;
 pub mod devnet_token_escrow_create_deposit_delegate_undelegate;
 pub mod localnet_bubblegum_escrow_deposit_transfer_delegate;
 pub mod localnet_bubblegum_escrow_deposit_transfer_withdraw;
+pub mod localnet_config_pause_deposit_withdraw;
+pub mod localnet_escrow_spending_limit;
 pub mod localnet_lamport_escrow_create_fund_claim;
 pub mod localnet_lamport_escrow_create_fund_delegate;
+pub mod localnet_market_create_place_match_cancel;
+pub mod localnet_nonce_account_create_delegate;
+pub mod localnet_pool_create_add_swap_remove;
+pub mod localnet_recovery_propose_veto_execute;
+pub mod localnet_token_escrow_clawback;
 pub mod localnet_token_escrow_create_deposit_delegate;
+pub mod localnet_token_escrow_create_deposit_swap;
 pub mod localnet_token_escrow_create_deposit_transfer_withdraw;
+pub mod localnet_token_escrow_deposit_delegated;
+pub mod localnet_token_escrow_eth;
+pub mod localnet_token_escrow_eth_delegate;
+pub mod localnet_token_escrow_freeze;
+pub mod localnet_token_escrow_merge_split;
+pub mod localnet_token_escrow_migrate_validator;
+pub mod localnet_token_escrow_mint_to_burn;
+pub mod localnet_token_escrow_multisig_transfer;
+pub mod localnet_token_escrow_transfer_batch;
+pub mod localnet_token_escrow_transfer_create;
+pub mod localnet_token_escrow_transfer_fee;
+pub mod localnet_token_escrow_transfer_signed;
+pub mod localnet_token_escrow_transfer_split;
+pub mod localnet_token_escrow_withdraw_create;
+pub mod localnet_token_vault_reconcile;
+pub mod localnet_token_vesting_create_release;
+pub mod localnet_validator_registry_create_delegate;
@@ -0,0 +1 @@
+pub mod token_vault_reconcile;
@@ -0,0 +1,549 @@
+use std::collections::HashMap;
+
+use borsh::BorshDeserialize;
+use ephemeral_rollups_sdk::consts::DELEGATION_PROGRAM_ID;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::market::Market;
+use crate::state::pool::Pool;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_vault::token_vault_generate_pda;
+use crate::state::token_vault_state::TokenVaultState;
+use crate::state::token_vesting::TokenVesting;
+use crate::util::token::is_token_program;
+use crate::util::token::unpack_token_account;
+
+#[derive(Debug, Clone)]
+pub struct AccountDump {
+    pub address: Pubkey,
+    pub owner: Pubkey,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TokenEscrowSeeds {
+    pub authority: Pubkey,
+    pub funder: Option<Pubkey>,
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub slot: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct TokenVaultReconciliation {
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_vault: Pubkey,
+    pub token_vault_amount: u64,
+    pub token_vault_state_outstanding_amount: Option<u64>,
+    pub token_escrows_count: usize,
+    pub token_escrows_amount: u64,
+    pub locked_amount: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TokenVaultReconciliationReport {
+    pub token_vaults: Vec<TokenVaultReconciliation>,
+    pub unknown_token_vaults: Vec<Pubkey>,
+    pub orphan_accounts: Vec<Pubkey>,
+    pub misderived_accounts: Vec<Pubkey>,
+    pub outdated_accounts: Vec<Pubkey>,
+}
+
+impl TokenVaultReconciliation {
+    pub fn escrowed_amount(&self) -> u64 {
+        self.token_escrows_amount.saturating_add(self.locked_amount)
+    }
+
+    pub fn is_solvent(&self) -> bool {
+        self.token_vault_amount >= self.escrowed_amount()
+    }
+
+    pub fn is_consistent(&self) -> bool {
+        let escrowed_amount = self.escrowed_amount();
+        self.token_vault_state_outstanding_amount.unwrap_or(escrowed_amount)
+            == escrowed_amount
+    }
+}
+
+impl TokenVaultReconciliationReport {
+    pub fn is_clean(&self) -> bool {
+        self.unknown_token_vaults.is_empty()
+            && self.orphan_accounts.is_empty()
+            && self.misderived_accounts.is_empty()
+            && self.token_vaults.iter().all(|token_vault| {
+                token_vault.is_solvent() && token_vault.is_consistent()
+            })
+    }
+}
+
+// Reconcile a snapshot of accounts: every decoded balance is grouped under the
+// (validator, mint) vault it is backed by. Escrow PDAs cannot be reversed, so
+// their seeds must be provided (e.g. indexed from the creation transactions),
+// including the funder for vestings.
+pub fn token_vault_reconcile(
+    program_id: &Pubkey,
+    validators: &[Pubkey],
+    token_escrows_seeds: &HashMap<Pubkey, TokenEscrowSeeds>,
+    accounts: &[AccountDump],
+) -> TokenVaultReconciliationReport {
+    let mut report = TokenVaultReconciliationReport::default();
+
+    // Find every vault in the snapshot, keyed by its (validator, mint)
+    let mut token_vaults_indexes = HashMap::new();
+    for account in accounts {
+        if !is_token_program(&account.owner) {
+            continue;
+        }
+        let Ok(token_account) = unpack_token_account(&account.data)
+        else {
+            continue;
+        };
+        if token_account.owner != account.address {
+            continue;
+        }
+        // A self-owned token account that doesn't match any listed validator
+        // is likely the vault of a validator missing from the list, its
+        // balance can't be reconciled
+        let Some(validator) = validators.iter().find(|validator| {
+            token_vault_generate_pda(validator, &token_account.mint, program_id)
+                == account.address
+        })
+        else {
+            report.unknown_token_vaults.push(account.address);
+            continue;
+        };
+        token_vaults_indexes.insert(
+            (*validator, token_account.mint),
+            report.token_vaults.len(),
+        );
+        report.token_vaults.push(TokenVaultReconciliation {
+            validator: *validator,
+            token_mint: token_account.mint,
+            token_vault: account.address,
+            token_vault_amount: token_account.amount,
+            token_vault_state_outstanding_amount: None,
+            token_escrows_count: 0,
+            token_escrows_amount: 0,
+            locked_amount: 0,
+        });
+    }
+
+    // Every program account that may hold a balance must match a known vault
+    let token_vaults_keys =
+        token_vaults_indexes.keys().copied().collect::<Vec<_>>();
+    for account in accounts {
+        // Delegated accounts still expose their last committed state
+        if account.owner != *program_id
+            && account.owner != DELEGATION_PROGRAM_ID
+        {
+            continue;
+        }
+        if account.data.len() < 8 {
+            continue;
+        }
+        let discriminant =
+            u64::from_le_bytes(account.data[..8].try_into().unwrap());
+        if discriminant == TokenEscrow::discriminant()
+            || discriminant == TokenVesting::discriminant()
+        {
+            let Some(seeds) = token_escrows_seeds.get(&account.address)
+            else {
+                report.orphan_accounts.push(account.address);
+                continue;
+            };
+            let (expected_address, amount, is_outdated) =
+                if discriminant == TokenEscrow::discriminant() {
+                    let Some((amount, is_outdated)) =
+                        unpack_token_escrow_amount(&account.data)
+                    else {
+                        report.misderived_accounts.push(account.address);
+                        continue;
+                    };
+                    (
+                        TokenEscrow::generate_pda(
+                            &seeds.authority,
+                            &seeds.validator,
+                            &seeds.token_mint,
+                            seeds.slot,
+                            program_id,
+                        ),
+                        amount,
+                        is_outdated,
+                    )
+                }
+                else {
+                    let Ok(token_vesting) =
+                        TokenVesting::try_from_slice(&account.data)
+                    else {
+                        report.misderived_accounts.push(account.address);
+                        continue;
+                    };
+                    // Vestings are also keyed by the funder that locked them
+                    let Some(funder) = seeds.funder
+                    else {
+                        report.misderived_accounts.push(account.address);
+                        continue;
+                    };
+                    (
+                        TokenVesting::generate_pda(
+                            &seeds.authority,
+                            &funder,
+                            &seeds.validator,
+                            &seeds.token_mint,
+                            seeds.slot,
+                            program_id,
+                        ),
+                        token_vesting
+                            .total_amount
+                            .saturating_sub(token_vesting.released_amount),
+                        false,
+                    )
+                };
+            if expected_address != account.address {
+                report.misderived_accounts.push(account.address);
+                continue;
+            }
+            if is_outdated {
+                report.outdated_accounts.push(account.address);
+            }
+            let Some(index) =
+                token_vaults_indexes.get(&(seeds.validator, seeds.token_mint))
+            else {
+                report.orphan_accounts.push(account.address);
+                continue;
+            };
+            let token_vault = &mut report.token_vaults[*index];
+            if discriminant == TokenEscrow::discriminant() {
+                token_vault.token_escrows_count += 1;
+                token_vault.token_escrows_amount =
+                    token_vault.token_escrows_amount.saturating_add(amount);
+            }
+            else {
+                token_vault.locked_amount =
+                    token_vault.locked_amount.saturating_add(amount);
+            }
+        }
+        else if discriminant == TokenVaultState::discriminant() {
+            let Ok(token_vault_state) =
+                TokenVaultState::try_from_slice(&account.data)
+            else {
+                report.misderived_accounts.push(account.address);
+                continue;
+            };
+            let Some((validator, token_mint)) =
+                token_vaults_keys.iter().find(|(validator, token_mint)| {
+                    TokenVaultState::generate_pda(
+                        validator, token_mint, program_id,
+                    ) == account.address
+                })
+            else {
+                report.orphan_accounts.push(account.address);
+                continue;
+            };
+            let index = token_vaults_indexes[&(*validator, *token_mint)];
+            report.token_vaults[index].token_vault_state_outstanding_amount =
+                Some(token_vault_state.outstanding_amount);
+        }
+        else if discriminant == Market::discriminant() {
+            let Ok(market) = Market::deserialize(&mut &account.data[..])
+            else {
+                report.misderived_accounts.push(account.address);
+                continue;
+            };
+            let Some((base_index, quote_index)) = find_token_vaults_pair(
+                &token_vaults_indexes,
+                &token_vaults_keys,
+                |validator, base_token_mint, quote_token_mint| {
+                    Market::generate_pda(
+                        validator,
+                        base_token_mint,
+                        quote_token_mint,
+                        program_id,
+                    ) == account.address
+                },
+            )
+            else {
+                report.orphan_accounts.push(account.address);
+                continue;
+            };
+            let base_token_vault = &mut report.token_vaults[base_index];
+            base_token_vault.locked_amount = base_token_vault
+                .locked_amount
+                .saturating_add(market.base_locked);
+            let quote_token_vault = &mut report.token_vaults[quote_index];
+            quote_token_vault.locked_amount = quote_token_vault
+                .locked_amount
+                .saturating_add(market.quote_locked);
+        }
+        else if discriminant == Pool::discriminant() {
+            let Ok(pool) = Pool::deserialize(&mut &account.data[..])
+            else {
+                report.misderived_accounts.push(account.address);
+                continue;
+            };
+            let Some((a_index, b_index)) = find_token_vaults_pair(
+                &token_vaults_indexes,
+                &token_vaults_keys,
+                |validator, token_mint_a, token_mint_b| {
+                    Pool::generate_pda(
+                        validator,
+                        token_mint_a,
+                        token_mint_b,
+                        program_id,
+                    ) == account.address
+                },
+            )
+            else {
+                report.orphan_accounts.push(account.address);
+                continue;
+            };
+            let a_token_vault = &mut report.token_vaults[a_index];
+            a_token_vault.locked_amount =
+                a_token_vault.locked_amount.saturating_add(pool.reserve_a);
+            let b_token_vault = &mut report.token_vaults[b_index];
+            b_token_vault.locked_amount =
+                b_token_vault.locked_amount.saturating_add(pool.reserve_b);
+        }
+    }
+
+    report
+}
+
+// Every TokenEscrow layout starts with the discriminant and the amount, escrows
+// created before the spending limits and the frozen flag existed keep their
+// shorter layout until migrated with token_escrow_realloc
+fn unpack_token_escrow_amount(data: &[u8]) -> Option<(u64, bool)> {
+    if data.len() < TokenEscrow::space() {
+        let amount = data.get(8..16)?;
+        return Some((u64::from_le_bytes(amount.try_into().ok()?), true));
+    }
+    let token_escrow = TokenEscrow::try_from_slice(data).ok()?;
+    Some((token_escrow.amount, false))
+}
+
+fn find_token_vaults_pair(
+    token_vaults_indexes: &HashMap<(Pubkey, Pubkey), usize>,
+    token_vaults_keys: &[(Pubkey, Pubkey)],
+    is_derived_from: impl Fn(&Pubkey, &Pubkey, &Pubkey) -> bool,
+) -> Option<(usize, usize)> {
+    for (validator, token_mint_a) in token_vaults_keys {
+        for (other_validator, token_mint_b) in token_vaults_keys {
+            if validator != other_validator || token_mint_a == token_mint_b {
+                continue;
+            }
+            if is_derived_from(validator, token_mint_a, token_mint_b) {
+                return Some((
+                    token_vaults_indexes[&(*validator, *token_mint_a)],
+                    token_vaults_indexes[&(*validator, *token_mint_b)],
+                ));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::program_option::COption;
+    use solana_program::program_pack::Pack;
+    use spl_token::state::Account;
+    use spl_token::state::AccountState;
+
+    use super::*;
+    use crate::state::spending_limit::SpendingLimit;
+
+    fn token_vault_dump(
+        validator: &Pubkey,
+        token_mint: &Pubkey,
+        amount: u64,
+    ) -> AccountDump {
+        let address =
+            token_vault_generate_pda(validator, token_mint, &crate::ID);
+        let mut data = vec![0; Account::LEN];
+        Account::pack(
+            Account {
+                mint: *token_mint,
+                owner: address,
+                amount,
+                delegate: COption::None,
+                state: AccountState::Initialized,
+                is_native: COption::None,
+                delegated_amount: 0,
+                close_authority: COption::None,
+            },
+            &mut data,
+        )
+        .unwrap();
+        AccountDump { address, owner: spl_token::ID, data }
+    }
+
+    fn token_vault_state_dump(
+        validator: &Pubkey,
+        token_mint: &Pubkey,
+        outstanding_amount: u64,
+    ) -> AccountDump {
+        let token_vault_state = TokenVaultState {
+            discriminant: TokenVaultState::discriminant(),
+            total_deposited: outstanding_amount,
+            total_withdrawn: 0,
+            outstanding_amount,
+        };
+        AccountDump {
+            address: TokenVaultState::generate_pda(
+                validator,
+                token_mint,
+                &crate::ID,
+            ),
+            owner: crate::ID,
+            data: borsh::to_vec(&token_vault_state).unwrap(),
+        }
+    }
+
+    fn token_escrow_dump(
+        validator: &Pubkey,
+        token_mint: &Pubkey,
+        amount: u64,
+    ) -> (TokenEscrowSeeds, AccountDump) {
+        let seeds = TokenEscrowSeeds {
+            authority: Pubkey::new_unique(),
+            funder: None,
+            validator: *validator,
+            token_mint: *token_mint,
+            slot: 0,
+        };
+        let token_escrow = TokenEscrow {
+            discriminant: TokenEscrow::discriminant(),
+            amount,
+            spending_limit: SpendingLimit::unlimited(),
+            frozen: false,
+        };
+        let address = TokenEscrow::generate_pda(
+            &seeds.authority,
+            &seeds.validator,
+            &seeds.token_mint,
+            seeds.slot,
+            &crate::ID,
+        );
+        let data = borsh::to_vec(&token_escrow).unwrap();
+        (seeds, AccountDump { address, owner: crate::ID, data })
+    }
+
+    #[test]
+    fn token_vault_reconcile_reports_unknown_validators() {
+        let validator = Pubkey::new_unique();
+        let unknown_validator = Pubkey::new_unique();
+        let token_mint = Pubkey::new_unique();
+
+        let (seeds, token_escrow) =
+            token_escrow_dump(&validator, &token_mint, 42);
+        let known_token_vault = token_vault_dump(&validator, &token_mint, 42);
+        let unknown_token_vault =
+            token_vault_dump(&unknown_validator, &token_mint, 7);
+
+        let report = token_vault_reconcile(
+            &crate::ID,
+            &[validator],
+            &HashMap::from([(token_escrow.address, seeds)]),
+            &[
+                known_token_vault.clone(),
+                unknown_token_vault.clone(),
+                token_escrow,
+            ],
+        );
+
+        assert_eq!(1, report.token_vaults.len());
+        assert_eq!(
+            known_token_vault.address,
+            report.token_vaults[0].token_vault
+        );
+        assert_eq!(42, report.token_vaults[0].token_escrows_amount);
+        assert!(report.token_vaults[0].is_solvent());
+        assert_eq!(
+            vec![unknown_token_vault.address],
+            report.unknown_token_vaults
+        );
+        assert!(!report.is_clean());
+    }
+
+    #[test]
+    fn token_vault_reconcile_counts_outdated_token_escrows() {
+        let validator = Pubkey::new_unique();
+        let token_mint = Pubkey::new_unique();
+
+        // An escrow created before the spending limits existed
+        let (seeds, mut token_escrow) =
+            token_escrow_dump(&validator, &token_mint, 42);
+        token_escrow.data.truncate(16);
+
+        let report = token_vault_reconcile(
+            &crate::ID,
+            &[validator],
+            &HashMap::from([(token_escrow.address, seeds)]),
+            &[
+                token_vault_dump(&validator, &token_mint, 42),
+                token_vault_state_dump(&validator, &token_mint, 42),
+                token_escrow.clone(),
+            ],
+        );
+
+        assert_eq!(1, report.token_vaults[0].token_escrows_count);
+        assert_eq!(42, report.token_vaults[0].token_escrows_amount);
+        assert!(report.misderived_accounts.is_empty());
+        assert_eq!(vec![token_escrow.address], report.outdated_accounts);
+        assert!(report.is_clean());
+    }
+
+    #[test]
+    fn token_vault_reconcile_accepts_surplus() {
+        let validator = Pubkey::new_unique();
+        let token_mint = Pubkey::new_unique();
+
+        // Tokens sent directly to the vault are not owed to anyone
+        let (seeds, token_escrow) =
+            token_escrow_dump(&validator, &token_mint, 42);
+
+        let report = token_vault_reconcile(
+            &crate::ID,
+            &[validator],
+            &HashMap::from([(token_escrow.address, seeds)]),
+            &[
+                token_vault_dump(&validator, &token_mint, 100),
+                token_vault_state_dump(&validator, &token_mint, 42),
+                token_escrow,
+            ],
+        );
+
+        assert_eq!(100, report.token_vaults[0].token_vault_amount);
+        assert_eq!(42, report.token_vaults[0].escrowed_amount());
+        assert!(report.token_vaults[0].is_solvent());
+        assert!(report.token_vaults[0].is_consistent());
+        assert!(report.is_clean());
+    }
+
+    #[test]
+    fn token_vault_reconcile_reports_deficit() {
+        let validator = Pubkey::new_unique();
+        let token_mint = Pubkey::new_unique();
+
+        let (seeds, token_escrow) =
+            token_escrow_dump(&validator, &token_mint, 42);
+
+        let report = token_vault_reconcile(
+            &crate::ID,
+            &[validator],
+            &HashMap::from([(token_escrow.address, seeds)]),
+            &[
+                token_vault_dump(&validator, &token_mint, 10),
+                token_vault_state_dump(&validator, &token_mint, 42),
+                token_escrow,
+            ],
+        );
+
+        assert_eq!(10, report.token_vaults[0].token_vault_amount);
+        assert_eq!(42, report.token_vaults[0].escrowed_amount());
+        assert!(!report.token_vaults[0].is_solvent());
+        assert!(report.token_vaults[0].is_consistent());
+        assert!(!report.is_clean());
+    }
+}
@@ -4,23 +4,82 @@ use solana_program::entrypoint::ProgramResult;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
+use crate::processor::admin_set_config;
+use crate::processor::admin_set_token_fee;
+use crate::processor::admin_set_validator;
 use crate::processor::apply_undelegation;
 // use crate::processor::bubblegum_escrow_delegate;
 // use crate::processor::bubblegum_escrow_deposit;
 // use crate::processor::bubblegum_escrow_transfer;
 // use crate::processor::bubblegum_escrow_undelegate;
 // use crate::processor::bubblegum_escrow_withdraw;
+use crate::processor::config_init;
 use crate::processor::lamport_escrow_claim;
 use crate::processor::lamport_escrow_create;
 use crate::processor::lamport_escrow_delegate;
+use crate::processor::lamport_escrow_realloc;
+use crate::processor::lamport_escrow_set_spending_limit;
 use crate::processor::lamport_escrow_undelegate;
+use crate::processor::market_create;
+use crate::processor::market_delegate;
+use crate::processor::market_order_cancel;
+use crate::processor::market_order_match;
+use crate::processor::market_order_place;
+use crate::processor::market_undelegate;
+use crate::processor::multisig_create;
+use crate::processor::nonce_account_create;
+use crate::processor::nonce_account_delegate;
+use crate::processor::nonce_account_delegate_eth;
+use crate::processor::nonce_account_undelegate;
+use crate::processor::nonce_account_undelegate_eth;
+use crate::processor::pool_add_liquidity;
+use crate::processor::pool_create;
+use crate::processor::pool_delegate;
+use crate::processor::pool_remove_liquidity;
+use crate::processor::pool_swap;
+use crate::processor::pool_undelegate;
+use crate::processor::recovery_close;
+use crate::processor::recovery_execute;
+use crate::processor::recovery_propose;
+use crate::processor::recovery_set;
+use crate::processor::recovery_veto;
+use crate::processor::split_config_create;
+use crate::processor::token_escrow_burn;
+use crate::processor::token_escrow_clawback;
 use crate::processor::token_escrow_create;
 use crate::processor::token_escrow_delegate;
+use crate::processor::token_escrow_delegate_eth;
 use crate::processor::token_escrow_deposit;
+use crate::processor::token_escrow_deposit_delegated;
+use crate::processor::token_escrow_freeze_undelegate;
+use crate::processor::token_escrow_merge;
+use crate::processor::token_escrow_migrate_validator;
+use crate::processor::token_escrow_mint_to;
+use crate::processor::token_escrow_realloc;
+use crate::processor::token_escrow_set_frozen;
+use crate::processor::token_escrow_set_spending_limit;
+use crate::processor::token_escrow_split;
+use crate::processor::token_escrow_swap;
+use crate::processor::token_escrow_swap_signed;
 use crate::processor::token_escrow_transfer;
+use crate::processor::token_escrow_transfer_batch;
+use crate::processor::token_escrow_transfer_create;
+use crate::processor::token_escrow_transfer_eth;
+use crate::processor::token_escrow_transfer_signed;
+use crate::processor::token_escrow_transfer_split;
 use crate::processor::token_escrow_undelegate;
+use crate::processor::token_escrow_undelegate_eth;
 use crate::processor::token_escrow_withdraw;
+use crate::processor::token_escrow_withdraw_create;
+use crate::processor::token_escrow_withdraw_eth;
+use crate::processor::token_vault_audit;
 use crate::processor::token_vault_init;
+use crate::processor::token_vault_state_init;
+use crate::processor::token_vesting_create;
+use crate::processor::token_vesting_delegate;
+use crate::processor::token_vesting_release;
+use crate::processor::token_vesting_undelegate;
+use crate::processor::validator_registry_init;
 
 fn process_instruction(
     program_id: &Pubkey,
@@ -40,6 +99,15 @@ fn process_instruction(
         tag.try_into().map_err(|_| ProgramError::InvalidInstructionData)?;
 
     match tag_array {
+        admin_set_config::DISCRIMINANT => {
+            admin_set_config::process(program_id, accounts, data)
+        },
+        admin_set_token_fee::DISCRIMINANT => {
+            admin_set_token_fee::process(program_id, accounts, data)
+        },
+        admin_set_validator::DISCRIMINANT => {
+            admin_set_validator::process(program_id, accounts, data)
+        },
         // bubblegum_escrow_deposit::DISCRIMINANT => {
         //     bubblegum_escrow_deposit::process(program_id, accounts, data)
         // },
@@ -55,39 +123,207 @@ fn process_instruction(
         // bubblegum_escrow_withdraw::DISCRIMINANT => {
         //     bubblegum_escrow_withdraw::process(program_id, accounts, data)
         // },
+        config_init::DISCRIMINANT => {
+            config_init::process(program_id, accounts, data)
+        },
         lamport_escrow_create::DISCRIMINANT => {
             lamport_escrow_create::process(program_id, accounts, data)
         },
         lamport_escrow_delegate::DISCRIMINANT => {
             lamport_escrow_delegate::process(program_id, accounts, data)
         },
+        lamport_escrow_realloc::DISCRIMINANT => {
+            lamport_escrow_realloc::process(program_id, accounts, data)
+        },
         lamport_escrow_claim::DISCRIMINANT => {
             lamport_escrow_claim::process(program_id, accounts, data)
         },
+        lamport_escrow_set_spending_limit::DISCRIMINANT => {
+            lamport_escrow_set_spending_limit::process(program_id, accounts, data)
+        },
         lamport_escrow_undelegate::DISCRIMINANT => {
             lamport_escrow_undelegate::process(program_id, accounts, data)
         },
+        market_create::DISCRIMINANT => {
+            market_create::process(program_id, accounts, data)
+        },
+        market_delegate::DISCRIMINANT => {
+            market_delegate::process(program_id, accounts, data)
+        },
+        market_order_cancel::DISCRIMINANT => {
+            market_order_cancel::process(program_id, accounts, data)
+        },
+        market_order_match::DISCRIMINANT => {
+            market_order_match::process(program_id, accounts, data)
+        },
+        market_order_place::DISCRIMINANT => {
+            market_order_place::process(program_id, accounts, data)
+        },
+        market_undelegate::DISCRIMINANT => {
+            market_undelegate::process(program_id, accounts, data)
+        },
+        multisig_create::DISCRIMINANT => {
+            multisig_create::process(program_id, accounts, data)
+        },
+        nonce_account_create::DISCRIMINANT => {
+            nonce_account_create::process(program_id, accounts, data)
+        },
+        nonce_account_delegate::DISCRIMINANT => {
+            nonce_account_delegate::process(program_id, accounts, data)
+        },
+        nonce_account_delegate_eth::DISCRIMINANT => {
+            nonce_account_delegate_eth::process(program_id, accounts, data)
+        },
+        nonce_account_undelegate::DISCRIMINANT => {
+            nonce_account_undelegate::process(program_id, accounts, data)
+        },
+        nonce_account_undelegate_eth::DISCRIMINANT => {
+            nonce_account_undelegate_eth::process(program_id, accounts, data)
+        },
+        pool_add_liquidity::DISCRIMINANT => {
+            pool_add_liquidity::process(program_id, accounts, data)
+        },
+        pool_create::DISCRIMINANT => {
+            pool_create::process(program_id, accounts, data)
+        },
+        pool_delegate::DISCRIMINANT => {
+            pool_delegate::process(program_id, accounts, data)
+        },
+        pool_remove_liquidity::DISCRIMINANT => {
+            pool_remove_liquidity::process(program_id, accounts, data)
+        },
+        pool_swap::DISCRIMINANT => {
+            pool_swap::process(program_id, accounts, data)
+        },
+        pool_undelegate::DISCRIMINANT => {
+            pool_undelegate::process(program_id, accounts, data)
+        },
+        recovery_close::DISCRIMINANT => {
+            recovery_close::process(program_id, accounts, data)
+        },
+        recovery_execute::DISCRIMINANT => {
+            recovery_execute::process(program_id, accounts, data)
+        },
+        recovery_propose::DISCRIMINANT => {
+            recovery_propose::process(program_id, accounts, data)
+        },
+        recovery_set::DISCRIMINANT => {
+            recovery_set::process(program_id, accounts, data)
+        },
+        recovery_veto::DISCRIMINANT => {
+            recovery_veto::process(program_id, accounts, data)
+        },
+        split_config_create::DISCRIMINANT => {
+            split_config_create::process(program_id, accounts, data)
+        },
+        token_escrow_burn::DISCRIMINANT => {
+            token_escrow_burn::process(program_id, accounts, data)
+        },
+        token_escrow_clawback::DISCRIMINANT => {
+            token_escrow_clawback::process(program_id, accounts, data)
+        },
         token_escrow_create::DISCRIMINANT => {
             token_escrow_create::process(program_id, accounts, data)
         },
         token_escrow_delegate::DISCRIMINANT => {
             token_escrow_delegate::process(program_id, accounts, data)
         },
+        token_escrow_delegate_eth::DISCRIMINANT => {
+            token_escrow_delegate_eth::process(program_id, accounts, data)
+        },
         token_escrow_deposit::DISCRIMINANT => {
             token_escrow_deposit::process(program_id, accounts, data)
         },
+        token_escrow_deposit_delegated::DISCRIMINANT => {
+            token_escrow_deposit_delegated::process(program_id, accounts, data)
+        },
+        token_escrow_freeze_undelegate::DISCRIMINANT => {
+            token_escrow_freeze_undelegate::process(program_id, accounts, data)
+        },
+        token_escrow_merge::DISCRIMINANT => {
+            token_escrow_merge::process(program_id, accounts, data)
+        },
+        token_escrow_migrate_validator::DISCRIMINANT => {
+            token_escrow_migrate_validator::process(program_id, accounts, data)
+        },
+        token_escrow_mint_to::DISCRIMINANT => {
+            token_escrow_mint_to::process(program_id, accounts, data)
+        },
+        token_escrow_realloc::DISCRIMINANT => {
+            token_escrow_realloc::process(program_id, accounts, data)
+        },
+        token_escrow_set_frozen::DISCRIMINANT => {
+            token_escrow_set_frozen::process(program_id, accounts, data)
+        },
+        token_escrow_set_spending_limit::DISCRIMINANT => {
+            token_escrow_set_spending_limit::process(program_id, accounts, data)
+        },
+        token_escrow_split::DISCRIMINANT => {
+            token_escrow_split::process(program_id, accounts, data)
+        },
+        token_escrow_swap::DISCRIMINANT => {
+            token_escrow_swap::process(program_id, accounts, data)
+        },
+        token_escrow_swap_signed::DISCRIMINANT => {
+            token_escrow_swap_signed::process(program_id, accounts, data)
+        },
         token_escrow_transfer::DISCRIMINANT => {
             token_escrow_transfer::process(program_id, accounts, data)
         },
+        token_escrow_transfer_batch::DISCRIMINANT => {
+            token_escrow_transfer_batch::process(program_id, accounts, data)
+        },
+        token_escrow_transfer_create::DISCRIMINANT => {
+            token_escrow_transfer_create::process(program_id, accounts, data)
+        },
+        token_escrow_transfer_eth::DISCRIMINANT => {
+            token_escrow_transfer_eth::process(program_id, accounts, data)
+        },
+        token_escrow_transfer_signed::DISCRIMINANT => {
+            token_escrow_transfer_signed::process(program_id, accounts, data)
+        },
+        token_escrow_transfer_split::DISCRIMINANT => {
+            token_escrow_transfer_split::process(program_id, accounts, data)
+        },
         token_escrow_undelegate::DISCRIMINANT => {
             token_escrow_undelegate::process(program_id, accounts, data)
         },
+        token_escrow_undelegate_eth::DISCRIMINANT => {
+            token_escrow_undelegate_eth::process(program_id, accounts, data)
+        },
         token_escrow_withdraw::DISCRIMINANT => {
             token_escrow_withdraw::process(program_id, accounts, data)
         },
+        token_escrow_withdraw_create::DISCRIMINANT => {
+            token_escrow_withdraw_create::process(program_id, accounts, data)
+        },
+        token_escrow_withdraw_eth::DISCRIMINANT => {
+            token_escrow_withdraw_eth::process(program_id, accounts, data)
+        },
+        token_vault_audit::DISCRIMINANT => {
+            token_vault_audit::process(program_id, accounts, data)
+        },
         token_vault_init::DISCRIMINANT => {
             token_vault_init::process(program_id, accounts, data)
         },
+        token_vault_state_init::DISCRIMINANT => {
+            token_vault_state_init::process(program_id, accounts, data)
+        },
+        token_vesting_create::DISCRIMINANT => {
+            token_vesting_create::process(program_id, accounts, data)
+        },
+        token_vesting_delegate::DISCRIMINANT => {
+            token_vesting_delegate::process(program_id, accounts, data)
+        },
+        token_vesting_release::DISCRIMINANT => {
+            token_vesting_release::process(program_id, accounts, data)
+        },
+        token_vesting_undelegate::DISCRIMINANT => {
+            token_vesting_undelegate::process(program_id, accounts, data)
+        },
+        validator_registry_init::DISCRIMINANT => {
+            validator_registry_init::process(program_id, accounts, data)
+        },
         EXTERNAL_UNDELEGATE_DISCRIMINATOR => {
             apply_undelegation::process(program_id, accounts, data)
         },
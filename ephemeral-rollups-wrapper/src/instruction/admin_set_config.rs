@@ -0,0 +1,34 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::admin_set_config;
+use crate::state::config::Config;
+
+pub fn instruction(
+    admin: &Pubkey,
+    new_admin: &Pubkey,
+    paused: u64,
+    allowed_token_programs: &[Pubkey],
+) -> Instruction {
+    let program_id = crate::ID;
+    let config_pda = Config::generate_pda(&program_id);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(config_pda, false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&admin_set_config::DISCRIMINANT);
+    admin_set_config::Args {
+        admin: *new_admin,
+        paused,
+        allowed_token_programs: allowed_token_programs.to_vec(),
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,48 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::processor::admin_set_token_fee;
+use crate::state::config::Config;
+use crate::state::token_fee::TokenFee;
+
+pub fn instruction(
+    payer: &Pubkey,
+    admin: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    fee_basis_points: u16,
+    fee_minimum: u64,
+    collector_authority: &Pubkey,
+    collector_slot: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+    let config_pda = Config::generate_pda(&program_id);
+    let token_fee_pda =
+        TokenFee::generate_pda(validator, token_mint, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(config_pda, false),
+        AccountMeta::new(token_fee_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&admin_set_token_fee::DISCRIMINANT);
+    admin_set_token_fee::Args {
+        validator: *validator,
+        token_mint: *token_mint,
+        fee_basis_points,
+        fee_minimum,
+        collector_authority: *collector_authority,
+        collector_slot,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
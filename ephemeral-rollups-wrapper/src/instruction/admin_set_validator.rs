@@ -0,0 +1,42 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::admin_set_validator;
+use crate::state::config::Config;
+use crate::state::validator_registry::ValidatorRegistry;
+use crate::state::validator_registry::ValidatorStatus;
+
+pub fn instruction(
+    admin: &Pubkey,
+    validator: &Pubkey,
+    label: &str,
+    status: ValidatorStatus,
+) -> Instruction {
+    let program_id = crate::ID;
+    let config_pda = Config::generate_pda(&program_id);
+    let validator_registry_pda = ValidatorRegistry::generate_pda(&program_id);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(config_pda, false),
+        AccountMeta::new(validator_registry_pda, false),
+    ];
+
+    let mut label_bytes = [0u8; 32];
+    let label_len = label.len().min(label_bytes.len());
+    label_bytes[..label_len].copy_from_slice(&label.as_bytes()[..label_len]);
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&admin_set_validator::DISCRIMINANT);
+    admin_set_validator::Args {
+        validator: *validator,
+        label: label_bytes,
+        status,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
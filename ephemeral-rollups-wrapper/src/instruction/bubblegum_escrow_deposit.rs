@@ -8,6 +8,8 @@ use solana_program::system_program;
 
 use crate::processor::bubblegum_escrow_deposit;
 use crate::state::bubblegum_escrow::BubblegumEscrow;
+use crate::state::config::Config;
+use crate::state::validator_registry::ValidatorRegistry;
 
 pub fn instruction(
     payer: &Pubkey,
@@ -41,6 +43,11 @@ pub fn instruction(
         AccountMeta::new_readonly(spl_account_compression::ID, false),
         AccountMeta::new_readonly(spl_noop::ID, false),
         AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+        AccountMeta::new_readonly(
+            ValidatorRegistry::generate_pda(&program_id),
+            false,
+        ),
     ];
 
     let mut data = Vec::new();
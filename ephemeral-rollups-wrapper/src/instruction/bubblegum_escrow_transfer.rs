@@ -6,6 +6,7 @@ use solana_program::pubkey::Pubkey;
 
 use crate::processor::bubblegum_escrow_transfer;
 use crate::state::bubblegum_escrow::BubblegumEscrow;
+use crate::state::config::Config;
 
 pub fn instruction(
     source_authority: &Pubkey,
@@ -23,6 +24,7 @@ pub fn instruction(
     let accounts = vec![
         AccountMeta::new_readonly(*source_authority, true),
         AccountMeta::new(bubblegum_escrow_pda, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
     ];
 
     let mut data = Vec::new();
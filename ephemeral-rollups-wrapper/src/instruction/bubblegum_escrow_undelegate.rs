@@ -8,6 +8,7 @@ use solana_program::pubkey::Pubkey;
 
 use crate::processor::bubblegum_escrow_undelegate;
 use crate::state::bubblegum_escrow::BubblegumEscrow;
+use crate::state::config::Config;
 
 pub fn instruction(
     payer: &Pubkey,
@@ -28,6 +29,7 @@ pub fn instruction(
         AccountMeta::new(bubblegum_escrow_pda, false),
         AccountMeta::new(MAGIC_CONTEXT_ID, false),
         AccountMeta::new_readonly(MAGIC_PROGRAM_ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
     ];
 
     let mut data = Vec::new();
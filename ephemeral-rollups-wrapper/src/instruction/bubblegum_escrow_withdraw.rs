@@ -8,6 +8,7 @@ use solana_program::system_program;
 
 use crate::processor::bubblegum_escrow_withdraw;
 use crate::state::bubblegum_escrow::BubblegumEscrow;
+use crate::state::config::Config;
 
 pub fn instruction(
     authority: &Pubkey,
@@ -40,6 +41,7 @@ pub fn instruction(
         AccountMeta::new_readonly(spl_account_compression::ID, false),
         AccountMeta::new_readonly(spl_noop::ID, false),
         AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
     ];
 
     let mut data = Vec::new();
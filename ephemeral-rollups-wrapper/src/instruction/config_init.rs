@@ -0,0 +1,34 @@
+use solana_program::bpf_loader_upgradeable;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::processor::config_init;
+use crate::state::config::Config;
+
+pub fn instruction(
+    payer: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let program_id = crate::ID;
+    let config_pda = Config::generate_pda(&program_id);
+    let program_data = Pubkey::find_program_address(
+        &[program_id.as_ref()],
+        &bpf_loader_upgradeable::ID,
+    )
+    .0;
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new_readonly(program_data, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&config_init::DISCRIMINANT);
+
+    Instruction { program_id, accounts, data }
+}
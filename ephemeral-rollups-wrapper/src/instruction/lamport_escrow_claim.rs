@@ -4,6 +4,7 @@ use solana_program::instruction::Instruction;
 use solana_program::pubkey::Pubkey;
 
 use crate::processor::lamport_escrow_claim;
+use crate::state::config::Config;
 use crate::state::lamport_escrow::LamportEscrow;
 
 pub fn instruction(
@@ -21,6 +22,7 @@ pub fn instruction(
         AccountMeta::new_readonly(*authority, true),
         AccountMeta::new(*destination, false),
         AccountMeta::new(lamport_escrow_pda, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
     ];
 
     let mut data = Vec::new();
@@ -5,7 +5,9 @@ use solana_program::pubkey::Pubkey;
 use solana_program::system_program;
 
 use crate::processor::lamport_escrow_create;
+use crate::state::config::Config;
 use crate::state::lamport_escrow::LamportEscrow;
+use crate::state::validator_registry::ValidatorRegistry;
 
 pub fn instruction(
     payer: &Pubkey,
@@ -21,6 +23,11 @@ pub fn instruction(
         AccountMeta::new(*payer, true),
         AccountMeta::new(lamport_escrow_pda, false),
         AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+        AccountMeta::new_readonly(
+            ValidatorRegistry::generate_pda(&program_id),
+            false,
+        ),
     ];
 
     let mut data = Vec::new();
@@ -9,7 +9,9 @@ use solana_program::pubkey::Pubkey;
 use solana_program::system_program;
 
 use crate::processor::lamport_escrow_delegate;
+use crate::state::config::Config;
 use crate::state::lamport_escrow::LamportEscrow;
+use crate::state::validator_registry::ValidatorRegistry;
 
 pub fn instruction(
     payer: &Pubkey,
@@ -43,6 +45,11 @@ pub fn instruction(
         AccountMeta::new_readonly(delegation_program_id, false),
         AccountMeta::new_readonly(program_id, false),
         AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+        AccountMeta::new_readonly(
+            ValidatorRegistry::generate_pda(&program_id),
+            false,
+        ),
     ];
 
     let mut data = Vec::new();
@@ -0,0 +1,44 @@
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::pda::delegation_record_pda_from_delegated_account;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::lamport_escrow_set_spending_limit;
+use crate::state::lamport_escrow::LamportEscrow;
+
+pub fn instruction(
+    authority: &Pubkey,
+    validator: &Pubkey,
+    slot: u64,
+    max_per_transaction: u64,
+    max_per_window: u64,
+    window_seconds: i64,
+) -> Instruction {
+    let program_id = crate::ID;
+    let lamport_escrow_pda =
+        LamportEscrow::generate_pda(authority, validator, slot, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(lamport_escrow_pda, false),
+        AccountMeta::new_readonly(
+            delegation_record_pda_from_delegated_account(&lamport_escrow_pda),
+            false,
+        ),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&lamport_escrow_set_spending_limit::DISCRIMINANT);
+    lamport_escrow_set_spending_limit::Args {
+        validator: *validator,
+        slot,
+        max_per_transaction,
+        max_per_window,
+        window_seconds,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
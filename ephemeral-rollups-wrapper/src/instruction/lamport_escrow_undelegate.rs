@@ -6,6 +6,7 @@ use solana_program::instruction::Instruction;
 use solana_program::pubkey::Pubkey;
 
 use crate::processor::lamport_escrow_undelegate;
+use crate::state::config::Config;
 use crate::state::lamport_escrow::LamportEscrow;
 
 pub fn instruction(
@@ -24,6 +25,7 @@ pub fn instruction(
         AccountMeta::new(lamport_escrow_pda, false),
         AccountMeta::new(MAGIC_CONTEXT_ID, false),
         AccountMeta::new_readonly(MAGIC_PROGRAM_ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
     ];
 
     let mut data = Vec::new();
@@ -0,0 +1,56 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::processor::market_create;
+use crate::state::config::Config;
+use crate::state::market::Market;
+use crate::state::validator_registry::ValidatorRegistry;
+
+pub fn instruction(
+    payer: &Pubkey,
+    admin: &Pubkey,
+    authority: &Pubkey,
+    validator: &Pubkey,
+    base_token_mint: &Pubkey,
+    quote_token_mint: &Pubkey,
+    base_lot_size: u64,
+    min_order_lots: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+    let market_pda = Market::generate_pda(
+        validator,
+        base_token_mint,
+        quote_token_mint,
+        &program_id,
+    );
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(market_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+        AccountMeta::new_readonly(
+            ValidatorRegistry::generate_pda(&program_id),
+            false,
+        ),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&market_create::DISCRIMINANT);
+    market_create::Args {
+        validator: *validator,
+        base_token_mint: *base_token_mint,
+        quote_token_mint: *quote_token_mint,
+        base_lot_size,
+        min_order_lots,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,74 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::market_order_match;
+use crate::state::config::Config;
+use crate::state::market::Market;
+use crate::state::token_escrow::TokenEscrow;
+
+pub fn instruction(
+    bid_maker: &Pubkey,
+    ask_maker: &Pubkey,
+    validator: &Pubkey,
+    base_token_mint: &Pubkey,
+    quote_token_mint: &Pubkey,
+    bid_maker_base_slot: u64,
+    bid_maker_quote_slot: u64,
+    ask_maker_quote_slot: u64,
+    bid_order_id: u64,
+    ask_order_id: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let bid_maker_base_token_escrow_pda = TokenEscrow::generate_pda(
+        bid_maker,
+        validator,
+        base_token_mint,
+        bid_maker_base_slot,
+        &program_id,
+    );
+    let bid_maker_quote_token_escrow_pda = TokenEscrow::generate_pda(
+        bid_maker,
+        validator,
+        quote_token_mint,
+        bid_maker_quote_slot,
+        &program_id,
+    );
+    let ask_maker_quote_token_escrow_pda = TokenEscrow::generate_pda(
+        ask_maker,
+        validator,
+        quote_token_mint,
+        ask_maker_quote_slot,
+        &program_id,
+    );
+    let market_pda = Market::generate_pda(
+        validator,
+        base_token_mint,
+        quote_token_mint,
+        &program_id,
+    );
+
+    let accounts = vec![
+        AccountMeta::new(bid_maker_base_token_escrow_pda, false),
+        AccountMeta::new(bid_maker_quote_token_escrow_pda, false),
+        AccountMeta::new(ask_maker_quote_token_escrow_pda, false),
+        AccountMeta::new(market_pda, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&market_order_match::DISCRIMINANT);
+    market_order_match::Args {
+        validator: *validator,
+        base_token_mint: *base_token_mint,
+        quote_token_mint: *quote_token_mint,
+        bid_order_id,
+        ask_order_id,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
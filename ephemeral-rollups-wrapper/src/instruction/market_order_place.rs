@@ -0,0 +1,66 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::market_order_place;
+use crate::state::config::Config;
+use crate::state::market::Market;
+use crate::state::market::MarketOrderSide;
+use crate::state::token_escrow::TokenEscrow;
+
+pub fn instruction(
+    maker: &Pubkey,
+    validator: &Pubkey,
+    base_token_mint: &Pubkey,
+    quote_token_mint: &Pubkey,
+    maker_base_slot: u64,
+    maker_quote_slot: u64,
+    side: MarketOrderSide,
+    price: u64,
+    quantity: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let (locked_token_mint, locked_slot) = match side {
+        MarketOrderSide::Bid => (quote_token_mint, maker_quote_slot),
+        MarketOrderSide::Ask => (base_token_mint, maker_base_slot),
+    };
+    let maker_token_escrow_pda = TokenEscrow::generate_pda(
+        maker,
+        validator,
+        locked_token_mint,
+        locked_slot,
+        &program_id,
+    );
+    let market_pda = Market::generate_pda(
+        validator,
+        base_token_mint,
+        quote_token_mint,
+        &program_id,
+    );
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*maker, true),
+        AccountMeta::new(maker_token_escrow_pda, false),
+        AccountMeta::new(market_pda, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&market_order_place::DISCRIMINANT);
+    market_order_place::Args {
+        validator: *validator,
+        base_token_mint: *base_token_mint,
+        quote_token_mint: *quote_token_mint,
+        maker_base_slot,
+        maker_quote_slot,
+        side,
+        price,
+        quantity,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,47 @@
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::consts::MAGIC_CONTEXT_ID;
+use ephemeral_rollups_sdk::consts::MAGIC_PROGRAM_ID;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::market_undelegate;
+use crate::state::config::Config;
+use crate::state::market::Market;
+
+pub fn instruction(
+    payer: &Pubkey,
+    authority: &Pubkey,
+    validator: &Pubkey,
+    base_token_mint: &Pubkey,
+    quote_token_mint: &Pubkey,
+) -> Instruction {
+    let program_id = crate::ID;
+    let market_pda = Market::generate_pda(
+        validator,
+        base_token_mint,
+        quote_token_mint,
+        &program_id,
+    );
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(market_pda, false),
+        AccountMeta::new(MAGIC_CONTEXT_ID, false),
+        AccountMeta::new_readonly(MAGIC_PROGRAM_ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&market_undelegate::DISCRIMINANT);
+    market_undelegate::Args {
+        validator: *validator,
+        base_token_mint: *base_token_mint,
+        quote_token_mint: *quote_token_mint,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -1,16 +1,80 @@
+// Instruction builders take one parameter per account and argument of their
+// IX, in the order the processor reads them, rather than grouping them
+#![allow(clippy::too_many_arguments)]
+
+pub mod admin_set_config;
+pub mod admin_set_token_fee;
+pub mod admin_set_validator;
 // pub mod bubblegum_escrow_delegate;
 // pub mod bubblegum_escrow_deposit;
 // pub mod bubblegum_escrow_transfer;
 // pub mod bubblegum_escrow_undelegate;
 // pub mod bubblegum_escrow_withdraw;
+pub mod config_init;
 pub mod lamport_escrow_claim;
 pub mod lamport_escrow_create;
 pub mod lamport_escrow_delegate;
+pub mod lamport_escrow_realloc;
+pub mod lamport_escrow_set_spending_limit;
 pub mod lamport_escrow_undelegate;
+pub mod market_create;
+pub mod market_delegate;
+pub mod market_order_cancel;
+pub mod market_order_match;
+pub mod market_order_place;
+pub mod market_undelegate;
+pub mod multisig_create;
+pub mod multisig_sign;
+pub mod nonce_account_create;
+pub mod nonce_account_delegate;
+pub mod nonce_account_delegate_eth;
+pub mod nonce_account_undelegate;
+pub mod nonce_account_undelegate_eth;
+pub mod pool_add_liquidity;
+pub mod pool_create;
+pub mod pool_delegate;
+pub mod pool_remove_liquidity;
+pub mod pool_swap;
+pub mod pool_undelegate;
+pub mod recovery_close;
+pub mod recovery_execute;
+pub mod recovery_propose;
+pub mod recovery_set;
+pub mod recovery_veto;
+pub mod split_config_create;
+pub mod token_escrow_burn;
+pub mod token_escrow_clawback;
 pub mod token_escrow_create;
 pub mod token_escrow_delegate;
+pub mod token_escrow_delegate_eth;
 pub mod token_escrow_deposit;
+pub mod token_escrow_deposit_delegated;
+pub mod token_escrow_freeze_undelegate;
+pub mod token_escrow_merge;
+pub mod token_escrow_migrate_validator;
+pub mod token_escrow_mint_to;
+pub mod token_escrow_realloc;
+pub mod token_escrow_set_frozen;
+pub mod token_escrow_set_spending_limit;
+pub mod token_escrow_split;
+pub mod token_escrow_swap;
+pub mod token_escrow_swap_signed;
 pub mod token_escrow_transfer;
+pub mod token_escrow_transfer_batch;
+pub mod token_escrow_transfer_create;
+pub mod token_escrow_transfer_eth;
+pub mod token_escrow_transfer_signed;
+pub mod token_escrow_transfer_split;
 pub mod token_escrow_undelegate;
+pub mod token_escrow_undelegate_eth;
 pub mod token_escrow_withdraw;
+pub mod token_escrow_withdraw_create;
+pub mod token_escrow_withdraw_eth;
+pub mod token_vault_audit;
 pub mod token_vault_init;
+pub mod token_vault_state_init;
+pub mod token_vesting_create;
+pub mod token_vesting_delegate;
+pub mod token_vesting_release;
+pub mod token_vesting_undelegate;
+pub mod validator_registry_init;
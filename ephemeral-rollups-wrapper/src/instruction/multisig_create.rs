@@ -0,0 +1,36 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::processor::multisig_create;
+use crate::state::config::Config;
+use crate::state::multisig::Multisig;
+
+pub fn instruction(
+    payer: &Pubkey,
+    creator: &Pubkey,
+    slot: u64,
+    threshold: u8,
+    signers: &[Pubkey],
+) -> Instruction {
+    let program_id = crate::ID;
+    let multisig_pda = Multisig::generate_pda(creator, slot, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*creator, true),
+        AccountMeta::new(multisig_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&multisig_create::DISCRIMINANT);
+    multisig_create::Args { slot, threshold, signers: signers.to_vec() }
+        .serialize(&mut data)
+        .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,20 @@
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+pub fn instruction(
+    mut instruction: Instruction,
+    multisig: &Pubkey,
+    signers: &[Pubkey],
+) -> Instruction {
+    // The multisig itself cannot sign, its signers are appended instead
+    for account in instruction.accounts.iter_mut() {
+        if account.pubkey == *multisig {
+            account.is_signer = false;
+        }
+    }
+    for signer in signers {
+        instruction.accounts.push(AccountMeta::new_readonly(*signer, true));
+    }
+    instruction
+}
@@ -0,0 +1,32 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::processor::nonce_account_create;
+use crate::state::config::Config;
+use crate::state::nonce_account::NonceAccount;
+
+pub fn instruction(
+    payer: &Pubkey,
+    authority: &Pubkey,
+) -> Instruction {
+    let program_id = crate::ID;
+    let nonce_account_pda = NonceAccount::generate_pda(authority, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new(nonce_account_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&nonce_account_create::DISCRIMINANT);
+    nonce_account_create::Args { authority: *authority }
+        .serialize(&mut data)
+        .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,31 @@
+use ephemeral_rollups_sdk::consts::MAGIC_CONTEXT_ID;
+use ephemeral_rollups_sdk::consts::MAGIC_PROGRAM_ID;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::nonce_account_undelegate;
+use crate::state::config::Config;
+use crate::state::nonce_account::NonceAccount;
+
+pub fn instruction(
+    payer: &Pubkey,
+    authority: &Pubkey,
+) -> Instruction {
+    let program_id = crate::ID;
+    let nonce_account_pda = NonceAccount::generate_pda(authority, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(nonce_account_pda, false),
+        AccountMeta::new(MAGIC_CONTEXT_ID, false),
+        AccountMeta::new_readonly(MAGIC_PROGRAM_ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&nonce_account_undelegate::DISCRIMINANT);
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,63 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::pool_add_liquidity;
+use crate::state::config::Config;
+use crate::state::pool::Pool;
+use crate::state::token_escrow::TokenEscrow;
+
+pub fn instruction(
+    provider: &Pubkey,
+    validator: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    provider_slot: u64,
+    amount_a: u64,
+    amount_b: u64,
+    minimum_shares: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let provider_token_escrow_a_pda = TokenEscrow::generate_pda(
+        provider,
+        validator,
+        token_mint_a,
+        provider_slot,
+        &program_id,
+    );
+    let provider_token_escrow_b_pda = TokenEscrow::generate_pda(
+        provider,
+        validator,
+        token_mint_b,
+        provider_slot,
+        &program_id,
+    );
+    let pool_pda =
+        Pool::generate_pda(validator, token_mint_a, token_mint_b, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*provider, true),
+        AccountMeta::new(provider_token_escrow_a_pda, false),
+        AccountMeta::new(provider_token_escrow_b_pda, false),
+        AccountMeta::new(pool_pda, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&pool_add_liquidity::DISCRIMINANT);
+    pool_add_liquidity::Args {
+        validator: *validator,
+        token_mint_a: *token_mint_a,
+        token_mint_b: *token_mint_b,
+        provider_slot,
+        amount_a,
+        amount_b,
+        minimum_shares,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
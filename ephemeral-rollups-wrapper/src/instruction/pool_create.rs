@@ -0,0 +1,52 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::processor::pool_create;
+use crate::state::config::Config;
+use crate::state::pool::Pool;
+use crate::state::validator_registry::ValidatorRegistry;
+
+pub fn instruction(
+    payer: &Pubkey,
+    admin: &Pubkey,
+    authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    fee_basis_points: u16,
+    min_position_shares: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+    let pool_pda =
+        Pool::generate_pda(validator, token_mint_a, token_mint_b, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(pool_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+        AccountMeta::new_readonly(
+            ValidatorRegistry::generate_pda(&program_id),
+            false,
+        ),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&pool_create::DISCRIMINANT);
+    pool_create::Args {
+        validator: *validator,
+        token_mint_a: *token_mint_a,
+        token_mint_b: *token_mint_b,
+        fee_basis_points,
+        min_position_shares,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,67 @@
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::consts::DELEGATION_PROGRAM_ID;
+use ephemeral_rollups_sdk::pda::delegation_metadata_pda_from_delegated_account;
+use ephemeral_rollups_sdk::pda::delegation_record_pda_from_delegated_account;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::processor::pool_delegate;
+use crate::state::config::Config;
+use crate::state::pool::Pool;
+use crate::state::validator_registry::ValidatorRegistry;
+use crate::DELEGATION_BUFFER_SEED;
+
+pub fn instruction(
+    payer: &Pubkey,
+    authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+) -> Instruction {
+    let program_id = crate::ID;
+    let pool_pda =
+        Pool::generate_pda(validator, token_mint_a, token_mint_b, &program_id);
+
+    let delegation_buffer_pda = Pubkey::find_program_address(
+        &[DELEGATION_BUFFER_SEED, &pool_pda.to_bytes()],
+        &program_id,
+    )
+    .0;
+
+    let delegation_record_pda =
+        delegation_record_pda_from_delegated_account(&pool_pda);
+    let delegation_metadata_pda =
+        delegation_metadata_pda_from_delegated_account(&pool_pda);
+    let delegation_program_id = DELEGATION_PROGRAM_ID;
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(pool_pda, false),
+        AccountMeta::new(delegation_buffer_pda, false),
+        AccountMeta::new(delegation_record_pda, false),
+        AccountMeta::new(delegation_metadata_pda, false),
+        AccountMeta::new_readonly(delegation_program_id, false),
+        AccountMeta::new_readonly(program_id, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+        AccountMeta::new_readonly(
+            ValidatorRegistry::generate_pda(&program_id),
+            false,
+        ),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&pool_delegate::DISCRIMINANT);
+    pool_delegate::Args {
+        validator: *validator,
+        token_mint_a: *token_mint_a,
+        token_mint_b: *token_mint_b,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,71 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::pool_swap;
+use crate::state::config::Config;
+use crate::state::pool::Pool;
+use crate::state::token_escrow::TokenEscrow;
+
+pub fn instruction(
+    trader: &Pubkey,
+    validator: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    source_slot: u64,
+    destination_slot: u64,
+    a_to_b: bool,
+    amount_in: u64,
+    minimum_amount_out: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let (source_token_mint, destination_token_mint) = if a_to_b {
+        (token_mint_a, token_mint_b)
+    }
+    else {
+        (token_mint_b, token_mint_a)
+    };
+    let source_token_escrow_pda = TokenEscrow::generate_pda(
+        trader,
+        validator,
+        source_token_mint,
+        source_slot,
+        &program_id,
+    );
+    let destination_token_escrow_pda = TokenEscrow::generate_pda(
+        trader,
+        validator,
+        destination_token_mint,
+        destination_slot,
+        &program_id,
+    );
+    let pool_pda =
+        Pool::generate_pda(validator, token_mint_a, token_mint_b, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*trader, true),
+        AccountMeta::new(source_token_escrow_pda, false),
+        AccountMeta::new(destination_token_escrow_pda, false),
+        AccountMeta::new(pool_pda, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&pool_swap::DISCRIMINANT);
+    pool_swap::Args {
+        validator: *validator,
+        token_mint_a: *token_mint_a,
+        token_mint_b: *token_mint_b,
+        source_slot,
+        destination_slot,
+        a_to_b,
+        amount_in,
+        minimum_amount_out,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,28 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::recovery_close;
+use crate::state::recovery::Recovery;
+
+pub fn instruction(
+    authority: &Pubkey,
+    proposed_authority: &Pubkey,
+) -> Instruction {
+    let program_id = crate::ID;
+    let recovery_pda = Recovery::generate_pda(authority, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*proposed_authority, true),
+        AccountMeta::new(recovery_pda, false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&recovery_close::DISCRIMINANT);
+    recovery_close::Args { authority: *authority }
+        .serialize(&mut data)
+        .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,58 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::recovery_execute;
+use crate::state::config::Config;
+use crate::state::recovery::Recovery;
+use crate::state::token_escrow::TokenEscrow;
+
+pub fn instruction(
+    authority: &Pubkey,
+    proposed_authority: &Pubkey,
+    token_escrows: &[(Pubkey, Pubkey, u64)],
+) -> Instruction {
+    let program_id = crate::ID;
+    let recovery_pda = Recovery::generate_pda(authority, &program_id);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*proposed_authority, true),
+        AccountMeta::new(recovery_pda, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+    for (validator, token_mint, slot) in token_escrows {
+        for escrow_authority in [authority, proposed_authority] {
+            accounts.push(AccountMeta::new(
+                TokenEscrow::generate_pda(
+                    escrow_authority,
+                    validator,
+                    token_mint,
+                    *slot,
+                    &program_id,
+                ),
+                false,
+            ));
+        }
+    }
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&recovery_execute::DISCRIMINANT);
+    recovery_execute::Args {
+        authority: *authority,
+        token_escrows: token_escrows
+            .iter()
+            .map(|(validator, token_mint, slot)| {
+                recovery_execute::ArgsTokenEscrow {
+                    validator: *validator,
+                    token_mint: *token_mint,
+                    slot: *slot,
+                }
+            })
+            .collect(),
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
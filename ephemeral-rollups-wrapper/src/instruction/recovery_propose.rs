@@ -0,0 +1,36 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::recovery_propose;
+use crate::state::config::Config;
+use crate::state::recovery::Recovery;
+
+pub fn instruction(
+    guardians: &[Pubkey],
+    authority: &Pubkey,
+    proposed_authority: &Pubkey,
+) -> Instruction {
+    let program_id = crate::ID;
+    let recovery_pda = Recovery::generate_pda(authority, &program_id);
+
+    let mut accounts = vec![
+        AccountMeta::new(recovery_pda, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+    for guardian in guardians {
+        accounts.push(AccountMeta::new_readonly(*guardian, true));
+    }
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&recovery_propose::DISCRIMINANT);
+    recovery_propose::Args {
+        authority: *authority,
+        proposed_authority: *proposed_authority,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,40 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::processor::recovery_set;
+use crate::state::config::Config;
+use crate::state::recovery::Recovery;
+
+pub fn instruction(
+    payer: &Pubkey,
+    authority: &Pubkey,
+    threshold: u8,
+    guardians: &[Pubkey],
+    delay_seconds: i64,
+) -> Instruction {
+    let program_id = crate::ID;
+    let recovery_pda = Recovery::generate_pda(authority, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(recovery_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&recovery_set::DISCRIMINANT);
+    recovery_set::Args {
+        threshold,
+        guardians: guardians.to_vec(),
+        delay_seconds,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
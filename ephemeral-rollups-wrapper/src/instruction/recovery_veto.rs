@@ -0,0 +1,21 @@
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::recovery_veto;
+use crate::state::recovery::Recovery;
+
+pub fn instruction(authority: &Pubkey) -> Instruction {
+    let program_id = crate::ID;
+    let recovery_pda = Recovery::generate_pda(authority, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(recovery_pda, false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&recovery_veto::DISCRIMINANT);
+
+    Instruction { program_id, accounts, data }
+}
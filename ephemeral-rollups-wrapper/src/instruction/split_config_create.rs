@@ -0,0 +1,55 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::processor::split_config_create;
+use crate::state::config::Config;
+use crate::state::split_config::SplitConfig;
+use crate::state::split_config::SplitConfigRecipient;
+
+pub fn instruction(
+    payer: &Pubkey,
+    creator: &Pubkey,
+    slot: u64,
+    recipients: &[(Pubkey, u64, u16)],
+) -> Instruction {
+    let program_id = crate::ID;
+    let split_config_pda =
+        SplitConfig::generate_pda(creator, slot, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*creator, true),
+        AccountMeta::new(split_config_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&split_config_create::DISCRIMINANT);
+    split_config_create::Args {
+        slot,
+        recipients: recipients
+            .iter()
+            .map(
+                |(
+                    destination_authority,
+                    destination_slot,
+                    share_basis_points,
+                )| {
+                    SplitConfigRecipient {
+                        destination_authority: *destination_authority,
+                        destination_slot: *destination_slot,
+                        share_basis_points: *share_basis_points,
+                    }
+                },
+            )
+            .collect(),
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,56 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::token_escrow_burn;
+use crate::state::config::Config;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_vault::token_vault_generate_pda;
+use crate::state::token_vault_state::TokenVaultState;
+
+pub fn instruction(
+    authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    token_program_id: &Pubkey,
+    slot: u64,
+    amount: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let token_escrow_pda = TokenEscrow::generate_pda(
+        authority,
+        validator,
+        token_mint,
+        slot,
+        &program_id,
+    );
+    let token_vault_pda =
+        token_vault_generate_pda(validator, token_mint, &program_id);
+    let token_vault_state_pda =
+        TokenVaultState::generate_pda(validator, token_mint, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(*token_mint, false),
+        AccountMeta::new(token_escrow_pda, false),
+        AccountMeta::new(token_vault_pda, false),
+        AccountMeta::new(token_vault_state_pda, false),
+        AccountMeta::new_readonly(*token_program_id, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_escrow_burn::DISCRIMINANT);
+    token_escrow_burn::Args {
+        validator: *validator,
+        token_mint: *token_mint,
+        slot,
+        amount,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -4,8 +4,10 @@ use solana_program::instruction::Instruction;
 use solana_program::pubkey::Pubkey;
 
 use crate::processor::token_escrow_deposit;
+use crate::state::config::Config;
 use crate::state::token_escrow::TokenEscrow;
 use crate::state::token_vault::token_vault_generate_pda;
+use crate::state::token_vault_state::TokenVaultState;
 
 pub fn instruction(
     source_authority: &Pubkey,
@@ -13,6 +15,7 @@ pub fn instruction(
     authority: &Pubkey,
     validator: &Pubkey,
     token_mint: &Pubkey,
+    token_program_id: &Pubkey,
     slot: u64,
     amount: u64,
 ) -> Instruction {
@@ -26,13 +29,17 @@ pub fn instruction(
     );
     let token_vault_pda =
         token_vault_generate_pda(validator, token_mint, &program_id);
+    let token_vault_state_pda =
+        TokenVaultState::generate_pda(validator, token_mint, &program_id);
 
     let accounts = vec![
         AccountMeta::new_readonly(*source_authority, true),
         AccountMeta::new(*source_token_account, false),
         AccountMeta::new(token_escrow_pda, false),
         AccountMeta::new(token_vault_pda, false),
-        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new(token_vault_state_pda, false),
+        AccountMeta::new_readonly(*token_program_id, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
     ];
 
     let mut data = Vec::new();
@@ -0,0 +1,64 @@
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::pda::delegation_record_pda_from_delegated_account;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::token_escrow_merge;
+use crate::state::config::Config;
+use crate::state::token_escrow::TokenEscrow;
+
+pub fn instruction(
+    authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    source_slot: u64,
+    destination_slot: u64,
+    close_source: bool,
+    spill: &Pubkey,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let source_token_escrow_pda = TokenEscrow::generate_pda(
+        authority,
+        validator,
+        token_mint,
+        source_slot,
+        &program_id,
+    );
+    let destination_token_escrow_pda = TokenEscrow::generate_pda(
+        authority,
+        validator,
+        token_mint,
+        destination_slot,
+        &program_id,
+    );
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(source_token_escrow_pda, false),
+        AccountMeta::new(destination_token_escrow_pda, false),
+        AccountMeta::new(*spill, false),
+        AccountMeta::new_readonly(
+            delegation_record_pda_from_delegated_account(
+                &source_token_escrow_pda,
+            ),
+            false,
+        ),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_escrow_merge::DISCRIMINANT);
+    token_escrow_merge::Args {
+        validator: *validator,
+        token_mint: *token_mint,
+        source_slot,
+        destination_slot,
+        close_source,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,87 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::token_escrow_migrate_validator;
+use crate::state::config::Config;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_vault::token_vault_generate_pda;
+use crate::state::token_vault_state::TokenVaultState;
+use crate::state::validator_registry::ValidatorRegistry;
+
+pub fn instruction(
+    authority: &Pubkey,
+    source_validator: &Pubkey,
+    destination_validator: &Pubkey,
+    token_mint: &Pubkey,
+    token_program_id: &Pubkey,
+    source_slot: u64,
+    destination_slot: u64,
+    amount: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let source_token_escrow_pda = TokenEscrow::generate_pda(
+        authority,
+        source_validator,
+        token_mint,
+        source_slot,
+        &program_id,
+    );
+    let source_token_vault_pda =
+        token_vault_generate_pda(source_validator, token_mint, &program_id);
+    let source_token_vault_state_pda = TokenVaultState::generate_pda(
+        source_validator,
+        token_mint,
+        &program_id,
+    );
+    let destination_token_escrow_pda = TokenEscrow::generate_pda(
+        authority,
+        destination_validator,
+        token_mint,
+        destination_slot,
+        &program_id,
+    );
+    let destination_token_vault_pda = token_vault_generate_pda(
+        destination_validator,
+        token_mint,
+        &program_id,
+    );
+    let destination_token_vault_state_pda = TokenVaultState::generate_pda(
+        destination_validator,
+        token_mint,
+        &program_id,
+    );
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(source_token_escrow_pda, false),
+        AccountMeta::new(source_token_vault_pda, false),
+        AccountMeta::new(source_token_vault_state_pda, false),
+        AccountMeta::new(destination_token_escrow_pda, false),
+        AccountMeta::new(destination_token_vault_pda, false),
+        AccountMeta::new(destination_token_vault_state_pda, false),
+        AccountMeta::new_readonly(*token_program_id, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+        AccountMeta::new_readonly(
+            ValidatorRegistry::generate_pda(&program_id),
+            false,
+        ),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_escrow_migrate_validator::DISCRIMINANT);
+    token_escrow_migrate_validator::Args {
+        source_validator: *source_validator,
+        destination_validator: *destination_validator,
+        token_mint: *token_mint,
+        source_slot,
+        destination_slot,
+        amount,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,46 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::token_escrow_set_frozen;
+use crate::state::token_escrow::TokenEscrow;
+
+pub fn instruction(
+    freeze_authority: &Pubkey,
+    authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    slot: u64,
+    frozen: bool,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let token_escrow_pda = TokenEscrow::generate_pda(
+        authority,
+        validator,
+        token_mint,
+        slot,
+        &program_id,
+    );
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*freeze_authority, true),
+        AccountMeta::new_readonly(*token_mint, false),
+        AccountMeta::new(token_escrow_pda, false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_escrow_set_frozen::DISCRIMINANT);
+    token_escrow_set_frozen::Args {
+        authority: *authority,
+        validator: *validator,
+        token_mint: *token_mint,
+        slot,
+        frozen,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
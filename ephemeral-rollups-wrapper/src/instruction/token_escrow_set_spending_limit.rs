@@ -0,0 +1,51 @@
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::pda::delegation_record_pda_from_delegated_account;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::token_escrow_set_spending_limit;
+use crate::state::token_escrow::TokenEscrow;
+
+pub fn instruction(
+    authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    slot: u64,
+    max_per_transaction: u64,
+    max_per_window: u64,
+    window_seconds: i64,
+) -> Instruction {
+    let program_id = crate::ID;
+    let token_escrow_pda = TokenEscrow::generate_pda(
+        authority,
+        validator,
+        token_mint,
+        slot,
+        &program_id,
+    );
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(token_escrow_pda, false),
+        AccountMeta::new_readonly(
+            delegation_record_pda_from_delegated_account(&token_escrow_pda),
+            false,
+        ),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_escrow_set_spending_limit::DISCRIMINANT);
+    token_escrow_set_spending_limit::Args {
+        validator: *validator,
+        token_mint: *token_mint,
+        slot,
+        max_per_transaction,
+        max_per_window,
+        window_seconds,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
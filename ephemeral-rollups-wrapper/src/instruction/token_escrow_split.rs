@@ -0,0 +1,64 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::processor::token_escrow_split;
+use crate::state::config::Config;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::validator_registry::ValidatorRegistry;
+
+pub fn instruction(
+    payer: &Pubkey,
+    authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    source_slot: u64,
+    destination_slot: u64,
+    amount: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let source_token_escrow_pda = TokenEscrow::generate_pda(
+        authority,
+        validator,
+        token_mint,
+        source_slot,
+        &program_id,
+    );
+    let destination_token_escrow_pda = TokenEscrow::generate_pda(
+        authority,
+        validator,
+        token_mint,
+        destination_slot,
+        &program_id,
+    );
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(source_token_escrow_pda, false),
+        AccountMeta::new(destination_token_escrow_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+        AccountMeta::new_readonly(
+            ValidatorRegistry::generate_pda(&program_id),
+            false,
+        ),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_escrow_split::DISCRIMINANT);
+    token_escrow_split::Args {
+        validator: *validator,
+        token_mint: *token_mint,
+        source_slot,
+        destination_slot,
+        amount,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
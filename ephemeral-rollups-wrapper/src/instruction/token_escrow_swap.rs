@@ -0,0 +1,81 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::token_escrow_swap;
+use crate::state::config::Config;
+use crate::state::token_escrow::TokenEscrow;
+
+pub fn instruction(
+    authority_a: &Pubkey,
+    authority_b: &Pubkey,
+    validator: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    authority_a_source_slot: u64,
+    authority_a_destination_slot: u64,
+    authority_b_source_slot: u64,
+    authority_b_destination_slot: u64,
+    amount_a: u64,
+    amount_b: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let authority_a_source_token_escrow_pda = TokenEscrow::generate_pda(
+        authority_a,
+        validator,
+        token_mint_a,
+        authority_a_source_slot,
+        &program_id,
+    );
+    let authority_b_destination_token_escrow_pda = TokenEscrow::generate_pda(
+        authority_b,
+        validator,
+        token_mint_a,
+        authority_b_destination_slot,
+        &program_id,
+    );
+    let authority_b_source_token_escrow_pda = TokenEscrow::generate_pda(
+        authority_b,
+        validator,
+        token_mint_b,
+        authority_b_source_slot,
+        &program_id,
+    );
+    let authority_a_destination_token_escrow_pda = TokenEscrow::generate_pda(
+        authority_a,
+        validator,
+        token_mint_b,
+        authority_a_destination_slot,
+        &program_id,
+    );
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*authority_a, true),
+        AccountMeta::new_readonly(*authority_b, true),
+        AccountMeta::new(authority_a_source_token_escrow_pda, false),
+        AccountMeta::new(authority_b_destination_token_escrow_pda, false),
+        AccountMeta::new(authority_b_source_token_escrow_pda, false),
+        AccountMeta::new(authority_a_destination_token_escrow_pda, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_escrow_swap::DISCRIMINANT);
+    token_escrow_swap::Args {
+        validator: *validator,
+        token_mint_a: *token_mint_a,
+        token_mint_b: *token_mint_b,
+        authority_a_source_slot,
+        authority_a_destination_slot,
+        authority_b_source_slot,
+        authority_b_destination_slot,
+        amount_a,
+        amount_b,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
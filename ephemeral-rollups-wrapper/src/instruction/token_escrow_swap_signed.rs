@@ -0,0 +1,128 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar;
+
+use crate::processor::token_escrow_swap_signed;
+use crate::state::config::Config;
+use crate::state::nonce_account::NonceAccount;
+use crate::state::token_escrow::TokenEscrow;
+
+pub fn message(
+    authority_a: &Pubkey,
+    authority_b: &Pubkey,
+    validator: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    authority_a_source_slot: u64,
+    authority_a_destination_slot: u64,
+    amount_a: u64,
+    amount_b: u64,
+    nonce: u64,
+    expiry: i64,
+) -> Vec<u8> {
+    let program_id = crate::ID;
+    token_escrow_swap_signed::message(&token_escrow_swap_signed::Offer {
+        authority_a_source_token_escrow: TokenEscrow::generate_pda(
+            authority_a,
+            validator,
+            token_mint_a,
+            authority_a_source_slot,
+            &program_id,
+        ),
+        authority_a_destination_token_escrow: TokenEscrow::generate_pda(
+            authority_a,
+            validator,
+            token_mint_b,
+            authority_a_destination_slot,
+            &program_id,
+        ),
+        authority_b: *authority_b,
+        amount_a,
+        amount_b,
+        nonce,
+        expiry,
+    })
+}
+
+pub fn instruction(
+    authority_a: &Pubkey,
+    authority_b: &Pubkey,
+    validator: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    authority_a_source_slot: u64,
+    authority_a_destination_slot: u64,
+    authority_b_source_slot: u64,
+    authority_b_destination_slot: u64,
+    amount_a: u64,
+    amount_b: u64,
+    nonce: u64,
+    expiry: i64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let authority_a_source_token_escrow_pda = TokenEscrow::generate_pda(
+        authority_a,
+        validator,
+        token_mint_a,
+        authority_a_source_slot,
+        &program_id,
+    );
+    let authority_b_destination_token_escrow_pda = TokenEscrow::generate_pda(
+        authority_b,
+        validator,
+        token_mint_a,
+        authority_b_destination_slot,
+        &program_id,
+    );
+    let authority_b_source_token_escrow_pda = TokenEscrow::generate_pda(
+        authority_b,
+        validator,
+        token_mint_b,
+        authority_b_source_slot,
+        &program_id,
+    );
+    let authority_a_destination_token_escrow_pda = TokenEscrow::generate_pda(
+        authority_a,
+        validator,
+        token_mint_b,
+        authority_a_destination_slot,
+        &program_id,
+    );
+    let nonce_account_pda =
+        NonceAccount::generate_pda(authority_a, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*authority_b, true),
+        AccountMeta::new(authority_a_source_token_escrow_pda, false),
+        AccountMeta::new(authority_b_destination_token_escrow_pda, false),
+        AccountMeta::new(authority_b_source_token_escrow_pda, false),
+        AccountMeta::new(authority_a_destination_token_escrow_pda, false),
+        AccountMeta::new(nonce_account_pda, false),
+        AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_escrow_swap_signed::DISCRIMINANT);
+    token_escrow_swap_signed::Args {
+        authority_a: *authority_a,
+        validator: *validator,
+        token_mint_a: *token_mint_a,
+        token_mint_b: *token_mint_b,
+        authority_a_source_slot,
+        authority_a_destination_slot,
+        authority_b_source_slot,
+        authority_b_destination_slot,
+        amount_a,
+        amount_b,
+        nonce,
+        expiry,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -4,7 +4,9 @@ use solana_program::instruction::Instruction;
 use solana_program::pubkey::Pubkey;
 
 use crate::processor::token_escrow_transfer;
+use crate::state::config::Config;
 use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_fee::TokenFee;
 
 pub fn instruction(
     source_authority: &Pubkey,
@@ -14,6 +16,8 @@ pub fn instruction(
     source_slot: u64,
     destination_slot: u64,
     amount: u64,
+    fee_collector_authority: &Pubkey,
+    fee_collector_slot: u64,
 ) -> Instruction {
     let program_id = crate::ID;
 
@@ -31,11 +35,23 @@ pub fn instruction(
         destination_slot,
         &program_id,
     );
+    let token_fee_pda =
+        TokenFee::generate_pda(validator, token_mint, &program_id);
+    let fee_collector_token_escrow_pda = TokenEscrow::generate_pda(
+        fee_collector_authority,
+        validator,
+        token_mint,
+        fee_collector_slot,
+        &program_id,
+    );
 
     let accounts = vec![
         AccountMeta::new_readonly(*source_authority, true),
         AccountMeta::new(source_token_escrow_pda, false),
         AccountMeta::new(destination_token_escrow_pda, false),
+        AccountMeta::new_readonly(token_fee_pda, false),
+        AccountMeta::new(fee_collector_token_escrow_pda, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
     ];
 
     let mut data = Vec::new();
@@ -0,0 +1,80 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::token_escrow_transfer_batch;
+use crate::state::config::Config;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_fee::TokenFee;
+
+pub fn instruction(
+    source_authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    source_slot: u64,
+    transfers: &[(Pubkey, u64, u64)],
+    fee_collector_authority: &Pubkey,
+    fee_collector_slot: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let source_token_escrow_pda = TokenEscrow::generate_pda(
+        source_authority,
+        validator,
+        token_mint,
+        source_slot,
+        &program_id,
+    );
+    let token_fee_pda =
+        TokenFee::generate_pda(validator, token_mint, &program_id);
+    let fee_collector_token_escrow_pda = TokenEscrow::generate_pda(
+        fee_collector_authority,
+        validator,
+        token_mint,
+        fee_collector_slot,
+        &program_id,
+    );
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*source_authority, true),
+        AccountMeta::new(source_token_escrow_pda, false),
+        AccountMeta::new_readonly(token_fee_pda, false),
+        AccountMeta::new(fee_collector_token_escrow_pda, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+    for (destination_authority, destination_slot, _) in transfers {
+        accounts.push(AccountMeta::new(
+            TokenEscrow::generate_pda(
+                destination_authority,
+                validator,
+                token_mint,
+                *destination_slot,
+                &program_id,
+            ),
+            false,
+        ));
+    }
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_escrow_transfer_batch::DISCRIMINANT);
+    token_escrow_transfer_batch::Args {
+        validator: *validator,
+        token_mint: *token_mint,
+        source_slot,
+        transfers: transfers
+            .iter()
+            .map(|(destination_authority, destination_slot, amount)| {
+                token_escrow_transfer_batch::ArgsTransfer {
+                    destination_authority: *destination_authority,
+                    destination_slot: *destination_slot,
+                    amount: *amount,
+                }
+            })
+            .collect(),
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
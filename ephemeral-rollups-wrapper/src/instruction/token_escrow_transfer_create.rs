@@ -0,0 +1,80 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::processor::token_escrow_transfer_create;
+use crate::state::config::Config;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_fee::TokenFee;
+use crate::state::validator_registry::ValidatorRegistry;
+
+pub fn instruction(
+    payer: &Pubkey,
+    source_authority: &Pubkey,
+    destination_authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    source_slot: u64,
+    destination_slot: u64,
+    amount: u64,
+    fee_collector_authority: &Pubkey,
+    fee_collector_slot: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let source_token_escrow_pda = TokenEscrow::generate_pda(
+        source_authority,
+        validator,
+        token_mint,
+        source_slot,
+        &program_id,
+    );
+    let destination_token_escrow_pda = TokenEscrow::generate_pda(
+        destination_authority,
+        validator,
+        token_mint,
+        destination_slot,
+        &program_id,
+    );
+    let token_fee_pda =
+        TokenFee::generate_pda(validator, token_mint, &program_id);
+    let fee_collector_token_escrow_pda = TokenEscrow::generate_pda(
+        fee_collector_authority,
+        validator,
+        token_mint,
+        fee_collector_slot,
+        &program_id,
+    );
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*source_authority, true),
+        AccountMeta::new(source_token_escrow_pda, false),
+        AccountMeta::new(destination_token_escrow_pda, false),
+        AccountMeta::new_readonly(token_fee_pda, false),
+        AccountMeta::new(fee_collector_token_escrow_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+        AccountMeta::new_readonly(
+            ValidatorRegistry::generate_pda(&program_id),
+            false,
+        ),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_escrow_transfer_create::DISCRIMINANT);
+    token_escrow_transfer_create::Args {
+        validator: *validator,
+        token_mint: *token_mint,
+        destination_authority: *destination_authority,
+        source_slot,
+        destination_slot,
+        amount,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
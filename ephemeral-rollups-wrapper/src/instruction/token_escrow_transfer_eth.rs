@@ -0,0 +1,180 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::secp256k1_program;
+use solana_program::sysvar;
+
+use crate::processor::token_escrow_transfer_eth;
+use crate::processor::token_escrow_transfer_signed::Intent;
+use crate::state::config::Config;
+use crate::state::eth_authority::eth_authority_generate_pda;
+use crate::state::nonce_account::NonceAccount;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_fee::TokenFee;
+
+pub fn message(
+    source_eth_address: &[u8; 20],
+    destination_authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    source_slot: u64,
+    destination_slot: u64,
+    amount: u64,
+    nonce: u64,
+    expiry: i64,
+    relayer_authority: &Pubkey,
+    relayer_slot: u64,
+    max_relayer_fee: u64,
+) -> Vec<u8> {
+    let program_id = crate::ID;
+    let source_authority =
+        eth_authority_generate_pda(source_eth_address, &program_id);
+    token_escrow_transfer_eth::message(&Intent {
+        source_token_escrow: TokenEscrow::generate_pda(
+            &source_authority,
+            validator,
+            token_mint,
+            source_slot,
+            &program_id,
+        ),
+        destination_token_escrow: TokenEscrow::generate_pda(
+            destination_authority,
+            validator,
+            token_mint,
+            destination_slot,
+            &program_id,
+        ),
+        amount,
+        nonce,
+        expiry,
+        relayer_token_escrow: TokenEscrow::generate_pda(
+            relayer_authority,
+            validator,
+            token_mint,
+            relayer_slot,
+            &program_id,
+        ),
+        max_relayer_fee,
+    })
+}
+
+// The secp256k1 precompile IX that must be placed right before the transfer
+// IX, at the given index in the transaction
+pub fn secp256k1_instruction(
+    eth_address: &[u8; 20],
+    signature: &[u8; 64],
+    recovery_id: u8,
+    message: &[u8],
+    instruction_index: u8,
+) -> Instruction {
+    let eth_address_offset: u16 = 12;
+    let signature_offset = eth_address_offset + 20;
+    let message_offset = signature_offset + 64 + 1;
+
+    let mut data = Vec::new();
+    data.push(1);
+    data.extend_from_slice(&signature_offset.to_le_bytes());
+    data.push(instruction_index);
+    data.extend_from_slice(&eth_address_offset.to_le_bytes());
+    data.push(instruction_index);
+    data.extend_from_slice(&message_offset.to_le_bytes());
+    data.extend_from_slice(
+        &u16::try_from(message.len()).unwrap().to_le_bytes(),
+    );
+    data.push(instruction_index);
+    data.extend_from_slice(eth_address);
+    data.extend_from_slice(signature);
+    data.push(recovery_id);
+    data.extend_from_slice(message);
+
+    Instruction { program_id: secp256k1_program::ID, accounts: vec![], data }
+}
+
+pub fn instruction(
+    source_eth_address: &[u8; 20],
+    destination_authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    source_slot: u64,
+    destination_slot: u64,
+    amount: u64,
+    nonce: u64,
+    expiry: i64,
+    fee_collector_authority: &Pubkey,
+    fee_collector_slot: u64,
+    relayer_authority: &Pubkey,
+    relayer_slot: u64,
+    relayer_fee: u64,
+    max_relayer_fee: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let source_authority =
+        eth_authority_generate_pda(source_eth_address, &program_id);
+    let source_token_escrow_pda = TokenEscrow::generate_pda(
+        &source_authority,
+        validator,
+        token_mint,
+        source_slot,
+        &program_id,
+    );
+    let destination_token_escrow_pda = TokenEscrow::generate_pda(
+        destination_authority,
+        validator,
+        token_mint,
+        destination_slot,
+        &program_id,
+    );
+    let token_fee_pda =
+        TokenFee::generate_pda(validator, token_mint, &program_id);
+    let fee_collector_token_escrow_pda = TokenEscrow::generate_pda(
+        fee_collector_authority,
+        validator,
+        token_mint,
+        fee_collector_slot,
+        &program_id,
+    );
+    let relayer_token_escrow_pda = TokenEscrow::generate_pda(
+        relayer_authority,
+        validator,
+        token_mint,
+        relayer_slot,
+        &program_id,
+    );
+    let nonce_account_pda =
+        NonceAccount::generate_pda(&source_authority, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new(source_token_escrow_pda, false),
+        AccountMeta::new(destination_token_escrow_pda, false),
+        AccountMeta::new_readonly(token_fee_pda, false),
+        AccountMeta::new(fee_collector_token_escrow_pda, false),
+        AccountMeta::new(relayer_token_escrow_pda, false),
+        AccountMeta::new(nonce_account_pda, false),
+        AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_escrow_transfer_eth::DISCRIMINANT);
+    token_escrow_transfer_eth::Args {
+        source_eth_address: *source_eth_address,
+        validator: *validator,
+        token_mint: *token_mint,
+        destination_authority: *destination_authority,
+        source_slot,
+        destination_slot,
+        amount,
+        nonce,
+        expiry,
+        relayer_authority: *relayer_authority,
+        relayer_slot,
+        relayer_fee,
+        max_relayer_fee,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
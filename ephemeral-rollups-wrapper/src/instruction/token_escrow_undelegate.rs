@@ -6,6 +6,7 @@ use solana_program::instruction::Instruction;
 use solana_program::pubkey::Pubkey;
 
 use crate::processor::token_escrow_undelegate;
+use crate::state::config::Config;
 use crate::state::token_escrow::TokenEscrow;
 
 pub fn instruction(
@@ -30,6 +31,7 @@ pub fn instruction(
         AccountMeta::new(token_escrow_pda, false),
         AccountMeta::new(MAGIC_CONTEXT_ID, false),
         AccountMeta::new_readonly(MAGIC_PROGRAM_ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
     ];
 
     let mut data = Vec::new();
@@ -0,0 +1,84 @@
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::consts::MAGIC_CONTEXT_ID;
+use ephemeral_rollups_sdk::consts::MAGIC_PROGRAM_ID;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar;
+
+use crate::processor::token_escrow_delegate_eth::Intent;
+use crate::processor::token_escrow_undelegate_eth;
+use crate::state::config::Config;
+use crate::state::eth_authority::eth_authority_generate_pda;
+use crate::state::nonce_account::NonceAccount;
+use crate::state::token_escrow::TokenEscrow;
+
+pub fn message(
+    eth_address: &[u8; 20],
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    slot: u64,
+    nonce: u64,
+    expiry: i64,
+) -> Vec<u8> {
+    let program_id = crate::ID;
+    let authority = eth_authority_generate_pda(eth_address, &program_id);
+    token_escrow_undelegate_eth::message(&Intent {
+        account: TokenEscrow::generate_pda(
+            &authority,
+            validator,
+            token_mint,
+            slot,
+            &program_id,
+        ),
+        nonce,
+        expiry,
+    })
+}
+
+pub fn instruction(
+    payer: &Pubkey,
+    eth_address: &[u8; 20],
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    slot: u64,
+    nonce: u64,
+    expiry: i64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let authority = eth_authority_generate_pda(eth_address, &program_id);
+    let token_escrow_pda = TokenEscrow::generate_pda(
+        &authority,
+        validator,
+        token_mint,
+        slot,
+        &program_id,
+    );
+    let nonce_account_pda = NonceAccount::generate_pda(&authority, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new(token_escrow_pda, false),
+        AccountMeta::new(nonce_account_pda, false),
+        AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        AccountMeta::new(MAGIC_CONTEXT_ID, false),
+        AccountMeta::new_readonly(MAGIC_PROGRAM_ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_escrow_undelegate_eth::DISCRIMINANT);
+    token_escrow_undelegate_eth::Args {
+        eth_address: *eth_address,
+        validator: *validator,
+        token_mint: *token_mint,
+        slot,
+        nonce,
+        expiry,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
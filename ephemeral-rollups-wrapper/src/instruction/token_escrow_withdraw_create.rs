@@ -0,0 +1,71 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::processor::token_escrow_withdraw_create;
+use crate::state::config::Config;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_vault::token_vault_generate_pda;
+use crate::state::token_vault_state::TokenVaultState;
+use crate::util::associated_token::get_associated_token_address;
+use crate::util::associated_token::ASSOCIATED_TOKEN_PROGRAM_ID;
+
+pub fn instruction(
+    payer: &Pubkey,
+    authority: &Pubkey,
+    destination_wallet: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    token_program_id: &Pubkey,
+    slot: u64,
+    amount: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let destination_token_account = get_associated_token_address(
+        destination_wallet,
+        token_mint,
+        token_program_id,
+    );
+    let token_escrow_pda = TokenEscrow::generate_pda(
+        authority,
+        validator,
+        token_mint,
+        slot,
+        &program_id,
+    );
+    let token_vault_pda =
+        token_vault_generate_pda(validator, token_mint, &program_id);
+    let token_vault_state_pda =
+        TokenVaultState::generate_pda(validator, token_mint, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new_readonly(*destination_wallet, false),
+        AccountMeta::new(destination_token_account, false),
+        AccountMeta::new(token_escrow_pda, false),
+        AccountMeta::new(token_vault_pda, false),
+        AccountMeta::new(token_vault_state_pda, false),
+        AccountMeta::new_readonly(*token_mint, false),
+        AccountMeta::new_readonly(*token_program_id, false),
+        AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_escrow_withdraw_create::DISCRIMINANT);
+    token_escrow_withdraw_create::Args {
+        validator: *validator,
+        token_mint: *token_mint,
+        slot,
+        amount,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
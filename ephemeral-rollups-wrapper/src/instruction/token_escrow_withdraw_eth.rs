@@ -0,0 +1,95 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar;
+
+use crate::processor::token_escrow_withdraw_eth;
+use crate::state::config::Config;
+use crate::state::eth_authority::eth_authority_generate_pda;
+use crate::state::nonce_account::NonceAccount;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_vault::token_vault_generate_pda;
+use crate::state::token_vault_state::TokenVaultState;
+
+pub fn message(
+    eth_address: &[u8; 20],
+    destination_token_account: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    slot: u64,
+    amount: u64,
+    nonce: u64,
+    expiry: i64,
+) -> Vec<u8> {
+    let program_id = crate::ID;
+    let authority = eth_authority_generate_pda(eth_address, &program_id);
+    token_escrow_withdraw_eth::message(&token_escrow_withdraw_eth::Intent {
+        token_escrow: TokenEscrow::generate_pda(
+            &authority,
+            validator,
+            token_mint,
+            slot,
+            &program_id,
+        ),
+        destination_token_account: *destination_token_account,
+        amount,
+        nonce,
+        expiry,
+    })
+}
+
+pub fn instruction(
+    eth_address: &[u8; 20],
+    destination_token_account: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    token_program_id: &Pubkey,
+    slot: u64,
+    amount: u64,
+    nonce: u64,
+    expiry: i64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let authority = eth_authority_generate_pda(eth_address, &program_id);
+    let token_escrow_pda = TokenEscrow::generate_pda(
+        &authority,
+        validator,
+        token_mint,
+        slot,
+        &program_id,
+    );
+    let token_vault_pda =
+        token_vault_generate_pda(validator, token_mint, &program_id);
+    let token_vault_state_pda =
+        TokenVaultState::generate_pda(validator, token_mint, &program_id);
+    let nonce_account_pda = NonceAccount::generate_pda(&authority, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new(*destination_token_account, false),
+        AccountMeta::new(token_escrow_pda, false),
+        AccountMeta::new(token_vault_pda, false),
+        AccountMeta::new(token_vault_state_pda, false),
+        AccountMeta::new(nonce_account_pda, false),
+        AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        AccountMeta::new_readonly(*token_program_id, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_escrow_withdraw_eth::DISCRIMINANT);
+    token_escrow_withdraw_eth::Args {
+        eth_address: *eth_address,
+        validator: *validator,
+        token_mint: *token_mint,
+        slot,
+        amount,
+        nonce,
+        expiry,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,32 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::token_vault_audit;
+use crate::state::token_vault::token_vault_generate_pda;
+use crate::state::token_vault_state::TokenVaultState;
+
+pub fn instruction(
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+) -> Instruction {
+    let program_id = crate::ID;
+    let token_vault_pda =
+        token_vault_generate_pda(validator, token_mint, &program_id);
+    let token_vault_state_pda =
+        TokenVaultState::generate_pda(validator, token_mint, &program_id);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(token_vault_pda, false),
+        AccountMeta::new_readonly(token_vault_state_pda, false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_vault_audit::DISCRIMINANT);
+    token_vault_audit::Args { validator: *validator, token_mint: *token_mint }
+        .serialize(&mut data)
+        .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -4,24 +4,36 @@ use solana_program::pubkey::Pubkey;
 use solana_program::system_program;
 
 use crate::processor::token_vault_init;
+use crate::state::config::Config;
 use crate::state::token_vault::token_vault_generate_pda;
+use crate::state::token_vault_state::TokenVaultState;
+use crate::state::validator_registry::ValidatorRegistry;
 
 pub fn instruction(
     payer: &Pubkey,
     validator: &Pubkey,
     token_mint: &Pubkey,
+    token_program_id: &Pubkey,
 ) -> Instruction {
     let program_id = crate::ID;
     let token_vault_pda =
         token_vault_generate_pda(validator, token_mint, &program_id);
+    let token_vault_state_pda =
+        TokenVaultState::generate_pda(validator, token_mint, &program_id);
 
     let accounts = vec![
         AccountMeta::new(*payer, true),
         AccountMeta::new_readonly(*validator, false),
         AccountMeta::new_readonly(*token_mint, false),
         AccountMeta::new(token_vault_pda, false),
-        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new(token_vault_state_pda, false),
+        AccountMeta::new_readonly(*token_program_id, false),
         AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+        AccountMeta::new_readonly(
+            ValidatorRegistry::generate_pda(&program_id),
+            false,
+        ),
     ];
 
     let mut data = Vec::new();
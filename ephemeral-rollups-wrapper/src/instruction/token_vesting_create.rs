@@ -0,0 +1,74 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::processor::token_vesting_create;
+use crate::state::config::Config;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_vesting::TokenVesting;
+use crate::state::validator_registry::ValidatorRegistry;
+
+pub fn instruction(
+    payer: &Pubkey,
+    funder: &Pubkey,
+    authority: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    funder_slot: u64,
+    slot: u64,
+    amount: u64,
+    start_unix_timestamp: i64,
+    cliff_unix_timestamp: i64,
+    end_unix_timestamp: i64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let funder_token_escrow_pda = TokenEscrow::generate_pda(
+        funder,
+        validator,
+        token_mint,
+        funder_slot,
+        &program_id,
+    );
+    let token_vesting_pda = TokenVesting::generate_pda(
+        authority,
+        funder,
+        validator,
+        token_mint,
+        slot,
+        &program_id,
+    );
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*funder, true),
+        AccountMeta::new(funder_token_escrow_pda, false),
+        AccountMeta::new(token_vesting_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+        AccountMeta::new_readonly(
+            ValidatorRegistry::generate_pda(&program_id),
+            false,
+        ),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_vesting_create::DISCRIMINANT);
+    token_vesting_create::Args {
+        authority: *authority,
+        validator: *validator,
+        token_mint: *token_mint,
+        slot,
+        funder_slot,
+        amount,
+        start_unix_timestamp,
+        cliff_unix_timestamp,
+        end_unix_timestamp,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
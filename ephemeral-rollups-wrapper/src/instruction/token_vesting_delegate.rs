@@ -0,0 +1,75 @@
+use borsh::BorshSerialize;
+use crate::DELEGATION_BUFFER_SEED;
+use ephemeral_rollups_sdk::consts::DELEGATION_PROGRAM_ID;
+use ephemeral_rollups_sdk::pda::delegation_metadata_pda_from_delegated_account;
+use ephemeral_rollups_sdk::pda::delegation_record_pda_from_delegated_account;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::processor::token_vesting_delegate;
+use crate::state::config::Config;
+use crate::state::token_vesting::TokenVesting;
+use crate::state::validator_registry::ValidatorRegistry;
+
+pub fn instruction(
+    payer: &Pubkey,
+    authority: &Pubkey,
+    funder: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    slot: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+    let token_vesting_pda = TokenVesting::generate_pda(
+        authority,
+        funder,
+        validator,
+        token_mint,
+        slot,
+        &program_id,
+    );
+
+    let delegation_buffer_pda = Pubkey::find_program_address(
+        &[DELEGATION_BUFFER_SEED, &token_vesting_pda.to_bytes()],
+        &program_id,
+    )
+    .0;
+
+    let delegation_record_pda =
+        delegation_record_pda_from_delegated_account(&token_vesting_pda);
+    let delegation_metadata_pda =
+        delegation_metadata_pda_from_delegated_account(&token_vesting_pda);
+    let delegation_program_id = DELEGATION_PROGRAM_ID;
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(token_vesting_pda, false),
+        AccountMeta::new(delegation_buffer_pda, false),
+        AccountMeta::new(delegation_record_pda, false),
+        AccountMeta::new(delegation_metadata_pda, false),
+        AccountMeta::new_readonly(delegation_program_id, false),
+        AccountMeta::new_readonly(program_id, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+        AccountMeta::new_readonly(
+            ValidatorRegistry::generate_pda(&program_id),
+            false,
+        ),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_vesting_delegate::DISCRIMINANT);
+    token_vesting_delegate::Args {
+        funder: *funder,
+        validator: *validator,
+        token_mint: *token_mint,
+        slot,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
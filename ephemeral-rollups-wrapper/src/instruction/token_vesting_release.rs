@@ -0,0 +1,55 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::token_vesting_release;
+use crate::state::config::Config;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_vesting::TokenVesting;
+
+pub fn instruction(
+    authority: &Pubkey,
+    funder: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    slot: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+
+    let token_escrow_pda = TokenEscrow::generate_pda(
+        authority,
+        validator,
+        token_mint,
+        slot,
+        &program_id,
+    );
+    let token_vesting_pda = TokenVesting::generate_pda(
+        authority,
+        funder,
+        validator,
+        token_mint,
+        slot,
+        &program_id,
+    );
+
+    let accounts = vec![
+        AccountMeta::new(token_escrow_pda, false),
+        AccountMeta::new(token_vesting_pda, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_vesting_release::DISCRIMINANT);
+    token_vesting_release::Args {
+        authority: *authority,
+        funder: *funder,
+        validator: *validator,
+        token_mint: *token_mint,
+        slot,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,51 @@
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::consts::MAGIC_CONTEXT_ID;
+use ephemeral_rollups_sdk::consts::MAGIC_PROGRAM_ID;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::token_vesting_undelegate;
+use crate::state::config::Config;
+use crate::state::token_vesting::TokenVesting;
+
+pub fn instruction(
+    payer: &Pubkey,
+    authority: &Pubkey,
+    funder: &Pubkey,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    slot: u64,
+) -> Instruction {
+    let program_id = crate::ID;
+    let token_vesting_pda = TokenVesting::generate_pda(
+        authority,
+        funder,
+        validator,
+        token_mint,
+        slot,
+        &program_id,
+    );
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(token_vesting_pda, false),
+        AccountMeta::new(MAGIC_CONTEXT_ID, false),
+        AccountMeta::new_readonly(MAGIC_PROGRAM_ID, false),
+        AccountMeta::new_readonly(Config::generate_pda(&program_id), false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&token_vesting_undelegate::DISCRIMINANT);
+    token_vesting_undelegate::Args {
+        funder: *funder,
+        validator: *validator,
+        token_mint: *token_mint,
+        slot,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    Instruction { program_id, accounts, data }
+}
@@ -0,0 +1,30 @@
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::processor::validator_registry_init;
+use crate::state::config::Config;
+use crate::state::validator_registry::ValidatorRegistry;
+
+pub fn instruction(
+    payer: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let program_id = crate::ID;
+    let config_pda = Config::generate_pda(&program_id);
+    let validator_registry_pda = ValidatorRegistry::generate_pda(&program_id);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(config_pda, false),
+        AccountMeta::new(validator_registry_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&validator_registry_init::DISCRIMINANT);
+
+    Instruction { program_id, accounts, data }
+}
@@ -1,3 +1,5 @@
+#[cfg(not(target_os = "solana"))]
+pub mod audit;
 pub mod entrypoint;
 pub mod instruction;
 pub mod processor;
@@ -0,0 +1,58 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::config::Config;
+use crate::util::ensure::ensure_is_admin;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xA6, 0x93, 0xEB, 0xA0, 0x7A, 0x23, 0xBD, 0x28];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub admin: Pubkey,
+    pub paused: u64,
+    pub allowed_token_programs: Vec<Pubkey>,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [admin, config_pda] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that the config's admin is indeed the one initiating this IX
+    let mut config_data = ensure_is_admin(admin, config_pda, program_id)?;
+
+    // Verify that the new config fits in the account
+    if args.allowed_token_programs.len() > Config::max_allowed_token_programs()
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Update the config data
+    config_data.admin = args.admin;
+    config_data.paused = args.paused;
+    config_data.allowed_token_programs = args.allowed_token_programs;
+    config_data
+        .serialize(&mut &mut config_pda.try_borrow_mut_data()?.as_mut())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Updated the Config");
+    msg!(" - admin: {}", config_data.admin);
+    msg!(" - paused: {:#x}", config_data.paused);
+    msg!(" - allowed_token_programs: {:?}", config_data.allowed_token_programs);
+
+    // Done
+    Ok(())
+}
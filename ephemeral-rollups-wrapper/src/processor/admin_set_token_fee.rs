@@ -0,0 +1,115 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::state::token_fee::TokenFee;
+use crate::token_fee_seeds_generator;
+use crate::util::create::create_pda;
+use crate::util::ensure::ensure_is_admin;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x24, 0xAB, 0x85, 0xBA, 0x44, 0x62, 0x02, 0xBA];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub fee_basis_points: u16,
+    pub fee_minimum: u64,
+    pub collector_authority: Pubkey,
+    pub collector_slot: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, admin, config_pda, token_fee_pda, system_program_id] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify the programs
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the config's admin is indeed the one initiating this IX
+    ensure_is_admin(admin, config_pda, program_id)?;
+
+    // Verify that the fee can never exceed the transferred amount's proportion
+    if args.fee_basis_points > TokenFee::max_fee_basis_points() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Verify the seeds of the fee PDA
+    let token_fee_seeds =
+        token_fee_seeds_generator!(args.validator, args.token_mint);
+    let token_fee_bump =
+        ensure_is_pda(token_fee_pda, token_fee_seeds, program_id)?;
+
+    // Initialize the fee PDA if this mint never had a fee schedule
+    if token_fee_pda.owner == &system_program::ID {
+        create_pda(
+            payer,
+            token_fee_pda,
+            token_fee_seeds,
+            token_fee_bump,
+            TokenFee::space(),
+            program_id,
+            system_program_id,
+        )?;
+    }
+    else {
+        // Verify that the program has proper control of the PDA (and that it's
+        // been initialized)
+        ensure_is_owned_by_program(token_fee_pda, program_id)?;
+        let token_fee_data =
+            TokenFee::try_from_slice(&token_fee_pda.data.borrow())?;
+        if token_fee_data.discriminant != TokenFee::discriminant() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    // Update the fee schedule
+    let token_fee_data = TokenFee {
+        discriminant: TokenFee::discriminant(),
+        fee_basis_points: args.fee_basis_points,
+        fee_minimum: args.fee_minimum,
+        collector_authority: args.collector_authority,
+        collector_slot: args.collector_slot,
+    };
+    token_fee_data
+        .serialize(&mut &mut token_fee_pda.try_borrow_mut_data()?.as_mut())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Updated the TokenFee");
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(
+        " - fee: {} basis points (minimum: {})",
+        args.fee_basis_points,
+        args.fee_minimum
+    );
+    msg!(
+        " - collector_authority: {} (slot: {})",
+        args.collector_authority,
+        args.collector_slot
+    );
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::validator_registry::ValidatorRegistry;
+use crate::state::validator_registry::ValidatorRegistryEntry;
+use crate::state::validator_registry::ValidatorStatus;
+use crate::util::ensure::ensure_is_admin;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::validator_registry_seeds_generator;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x0E, 0x73, 0x5C, 0xFA, 0x4E, 0x33, 0x14, 0x24];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub label: [u8; 32],
+    pub status: ValidatorStatus,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [admin, config_pda, validator_registry_pda] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that the config's admin is indeed the one initiating this IX
+    ensure_is_admin(admin, config_pda, program_id)?;
+
+    // Verify that the program has proper control of the PDA (and that it's been
+    // initialized)
+    ensure_is_owned_by_program(validator_registry_pda, program_id)?;
+
+    // Verify the seeds of the registry PDA
+    ensure_is_pda(
+        validator_registry_pda,
+        validator_registry_seeds_generator!(),
+        program_id,
+    )?;
+
+    // Verify that the registry PDA is properly initalized
+    let mut validator_registry_data = ValidatorRegistry::deserialize(
+        &mut &validator_registry_pda.data.borrow()[..],
+    )?;
+    if validator_registry_data.discriminant != ValidatorRegistry::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Update the validator's entry, or approve a new one if there is room
+    let entry = ValidatorRegistryEntry {
+        validator: args.validator,
+        label: args.label,
+        status: args.status,
+    };
+    if let Some(existing) = validator_registry_data
+        .validators
+        .iter_mut()
+        .find(|existing| existing.validator == args.validator)
+    {
+        *existing = entry;
+    }
+    else {
+        if validator_registry_data.validators.len()
+            >= ValidatorRegistry::max_validators()
+        {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        validator_registry_data.validators.push(entry);
+    }
+    validator_registry_data.serialize(
+        &mut &mut validator_registry_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Updated the ValidatorRegistry");
+    msg!(" - validator: {}", args.validator);
+    msg!(
+        " - label: {}",
+        String::from_utf8_lossy(&args.label).trim_end_matches('\0')
+    );
+    msg!(" - status: {:?}", args.status);
+
+    // Done
+    Ok(())
+}
@@ -13,11 +13,14 @@ use solana_program::system_program;
 
 use crate::bubblegum_escrow_seeds_generator;
 use crate::state::bubblegum_escrow::BubblegumEscrow;
+use crate::state::config::ConfigPause;
 use crate::util::create::create_pda;
 use crate::util::ensure::ensure_is_owned_by_program;
 use crate::util::ensure::ensure_is_pda;
 use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_registered_validator;
 use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
 
 pub const DISCRIMINANT: [u8; 8] =
     [0x34, 0x0B, 0x50, 0x67, 0x14, 0x31, 0x8E, 0x98];
@@ -39,13 +42,23 @@ pub fn process(
     data: &[u8],
 ) -> ProgramResult {
     // Read instruction inputs
-    let [payer, bubblegum_escrow_pda, tree, tree_config_pda, leaf_owner, leaf_delegate, bubblegum_program_id, compression_program_id, noop_program_id, system_program_id] =
+    let [payer, bubblegum_escrow_pda, tree, tree_config_pda, leaf_owner, leaf_delegate, bubblegum_program_id, compression_program_id, noop_program_id, system_program_id, config_pda, validator_registry_pda] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     let args = Args::try_from_slice(data)?;
 
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Deposit, program_id)?;
+
+    // Verify that the validator is approved and still active
+    ensure_is_registered_validator(
+        validator_registry_pda,
+        &args.validator,
+        program_id,
+    )?;
+
     // Verify the programs
     ensure_is_program_id(bubblegum_program_id, &mpl_bubblegum::ID)?;
     ensure_is_program_id(compression_program_id, &spl_account_compression::ID)?;
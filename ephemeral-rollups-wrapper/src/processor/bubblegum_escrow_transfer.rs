@@ -9,9 +9,11 @@ use solana_program::pubkey::Pubkey;
 
 use crate::bubblegum_escrow_seeds_generator;
 use crate::state::bubblegum_escrow::BubblegumEscrow;
+use crate::state::config::ConfigPause;
+use crate::util::ensure::ensure_is_authority;
 use crate::util::ensure::ensure_is_owned_by_program;
 use crate::util::ensure::ensure_is_pda;
-use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
 
 pub const DISCRIMINANT: [u8; 8] =
     [0x85, 0xD7, 0x3A, 0x53, 0x9F, 0xDA, 0xFA, 0x5C];
@@ -30,14 +32,19 @@ pub fn process(
     data: &[u8],
 ) -> ProgramResult {
     // Read instruction inputs
-    let [source_authority, bubblegum_escrow_pda] = accounts
+    let [source_authority, bubblegum_escrow_pda, config_pda, signers @ ..] =
+        accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     let args = Args::try_from_slice(data)?;
 
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Transfer, program_id)?;
+
     // Verify that the authority user is indeed the one initiating this IX
-    ensure_is_signer(source_authority)?;
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(source_authority, signers, program_id)?;
 
     // Verify that the program has proper control of the escrow PDA (and that
     // it's been initialized)
@@ -11,10 +11,13 @@ use solana_program::pubkey::Pubkey;
 
 use crate::bubblegum_escrow_seeds_generator;
 use crate::state::bubblegum_escrow::BubblegumEscrow;
+use crate::state::config::ConfigPause;
+use crate::util::ensure::ensure_is_authority;
 use crate::util::ensure::ensure_is_owned_by_program;
 use crate::util::ensure::ensure_is_pda;
 use crate::util::ensure::ensure_is_program_id;
 use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
 
 pub const DISCRIMINANT: [u8; 8] =
     [0xAA, 0x98, 0xA4, 0x02, 0xA7, 0xEE, 0x30, 0x93];
@@ -31,13 +34,16 @@ pub fn process(
     accounts: &[AccountInfo],
     data: &[u8],
 ) -> ProgramResult {
-    let [payer, authority, bubblegum_escrow_pda, magic_context_pda, magic_program_id] =
+    let [payer, authority, bubblegum_escrow_pda, magic_context_pda, magic_program_id, config_pda, signers @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     let args = Args::try_from_slice(data)?;
 
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Undelegate, program_id)?;
+
     // Verify the programs
     ensure_is_program_id(magic_program_id, &MAGIC_PROGRAM_ID)?;
 
@@ -45,7 +51,8 @@ pub fn process(
     ensure_is_signer(payer)?;
 
     // Verify that the authority user is indeed the one initiating this IX
-    ensure_is_signer(authority)?;
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(authority, signers, program_id)?;
 
     // Verify that the program has proper control of the PDA (and that it's been
     // initialized)
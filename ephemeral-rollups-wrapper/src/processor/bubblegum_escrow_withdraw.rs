@@ -13,11 +13,13 @@ use solana_program::system_program;
 
 use crate::bubblegum_escrow_seeds_generator;
 use crate::state::bubblegum_escrow::BubblegumEscrow;
+use crate::state::config::ConfigPause;
 use crate::util::close::close_pda;
+use crate::util::ensure::ensure_is_authority;
 use crate::util::ensure::ensure_is_owned_by_program;
 use crate::util::ensure::ensure_is_pda;
 use crate::util::ensure::ensure_is_program_id;
-use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
 use crate::util::signer::signer_seeds;
 
 pub const DISCRIMINANT: [u8; 8] =
@@ -39,13 +41,16 @@ pub fn process(
     data: &[u8],
 ) -> ProgramResult {
     // Read instruction inputs
-    let [authority, new_leaf_owner, bubblegum_escrow_pda, spill, tree, tree_config_pda, bubblegum_program_id, compression_program_id, noop_program_id, system_program_id] =
+    let [authority, new_leaf_owner, bubblegum_escrow_pda, spill, tree, tree_config_pda, bubblegum_program_id, compression_program_id, noop_program_id, system_program_id, config_pda, signers @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     let args = Args::try_from_slice(data)?;
 
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Withdraw, program_id)?;
+
     // Verify the programs
     ensure_is_program_id(bubblegum_program_id, &mpl_bubblegum::ID)?;
     ensure_is_program_id(compression_program_id, &spl_account_compression::ID)?;
@@ -53,7 +58,8 @@ pub fn process(
     ensure_is_program_id(system_program_id, &system_program::ID)?;
 
     // Verify that the authority user is indeed the one initiating this IX
-    ensure_is_signer(authority)?;
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(authority, signers, program_id)?;
 
     // Verify that the program has proper control of the escrow PDA (and that
     // it's been initialized)
@@ -0,0 +1,76 @@
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::config_seeds_generator;
+use crate::state::config::Config;
+use crate::util::create::create_pda;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_upgrade_authority;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xEA, 0x3D, 0xE5, 0xE3, 0x7B, 0xD6, 0x0D, 0xBC];
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, admin, config_pda, program_data, system_program_id] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Verify the programs
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the admin is the program's upgrade authority, so that nobody
+    // can front-run the deployer and take control of the config
+    ensure_is_upgrade_authority(admin, program_data, program_id)?;
+
+    // Verify that the config PDA is currently un-initialized (only once)
+    ensure_is_owned_by_program(config_pda, &system_program::ID)?;
+
+    // Verify the seeds of the config PDA
+    let config_seeds = config_seeds_generator!();
+    let config_bump = ensure_is_pda(config_pda, config_seeds, program_id)?;
+
+    // Initialize the config PDA
+    create_pda(
+        payer,
+        config_pda,
+        config_seeds,
+        config_bump,
+        Config::space(),
+        program_id,
+        system_program_id,
+    )?;
+
+    // Initialize the config data, nothing paused and only the spl token program
+    let config_data = Config {
+        discriminant: Config::discriminant(),
+        admin: *admin.key,
+        paused: 0,
+        allowed_token_programs: vec![spl_token::ID],
+    };
+    config_data
+        .serialize(&mut &mut config_pda.try_borrow_mut_data()?.as_mut())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Created the Config");
+    msg!(" - admin: {}", admin.key);
+
+    // Done
+    Ok(())
+}
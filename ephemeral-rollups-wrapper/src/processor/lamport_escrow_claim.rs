@@ -9,10 +9,13 @@ use solana_program::rent::Rent;
 use solana_program::sysvar::Sysvar;
 
 use crate::lamport_escrow_seeds_generator;
+use crate::state::config::ConfigPause;
 use crate::state::lamport_escrow::LamportEscrow;
+use crate::util::ensure::ensure_is_authority;
 use crate::util::ensure::ensure_is_owned_by_program;
 use crate::util::ensure::ensure_is_pda;
-use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::limit::consume_spending_limit;
 
 pub const DISCRIMINANT: [u8; 8] =
     [0x62, 0x2B, 0x40, 0xA9, 0xC1, 0xE1, 0x1D, 0x72];
@@ -30,14 +33,19 @@ pub fn process(
     data: &[u8],
 ) -> ProgramResult {
     // Read instruction inputs
-    let [authority, destination, lamport_escrow_pda] = accounts
+    let [authority, destination, lamport_escrow_pda, config_pda, signers @ ..] =
+        accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     let args = Args::try_from_slice(data)?;
 
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Withdraw, program_id)?;
+
     // Verify that the authority user is indeed the one initiating this IX
-    ensure_is_signer(authority)?;
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(authority, signers, program_id)?;
 
     // Verify that the program has proper control of the PDA (and that it's been
     // initialized)
@@ -52,12 +60,21 @@ pub fn process(
     ensure_is_pda(lamport_escrow_pda, lamport_escrow_seeds, program_id)?;
 
     // Verify that the escrow PDA is properly initalized
-    let lamport_escrow_data =
+    let mut lamport_escrow_data =
         LamportEscrow::try_from_slice(&lamport_escrow_pda.data.borrow())?;
     if lamport_escrow_data.discriminant != LamportEscrow::discriminant() {
         return Err(ProgramError::InvalidAccountData);
     }
 
+    // Verify that the escrow's spending limit allows this amount to leave
+    consume_spending_limit(
+        &mut lamport_escrow_data.spending_limit,
+        args.lamports,
+    )?;
+    lamport_escrow_data.serialize(
+        &mut &mut lamport_escrow_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
     // Verify that the escrow PDA has a sufficient amount of available lamports
     // to claim
     let minimum_lamports = Rent::get()?.minimum_balance(LamportEscrow::space());
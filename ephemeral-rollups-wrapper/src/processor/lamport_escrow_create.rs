@@ -8,12 +8,16 @@ use solana_program::pubkey::Pubkey;
 use solana_program::system_program;
 
 use crate::lamport_escrow_seeds_generator;
+use crate::state::config::ConfigPause;
 use crate::state::lamport_escrow::LamportEscrow;
+use crate::state::spending_limit::SpendingLimit;
 use crate::util::create::create_pda;
 use crate::util::ensure::ensure_is_owned_by_program;
 use crate::util::ensure::ensure_is_pda;
 use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_registered_validator;
 use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
 
 pub const DISCRIMINANT: [u8; 8] =
     [0x1A, 0x92, 0xB7, 0x8B, 0x57, 0xAD, 0x99, 0x02];
@@ -31,12 +35,23 @@ pub fn process(
     data: &[u8],
 ) -> ProgramResult {
     // Read instruction inputs
-    let [payer, lamport_escrow_pda, system_program_id] = accounts
+    let [payer, lamport_escrow_pda, system_program_id, config_pda, validator_registry_pda] =
+        accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     let args = Args::try_from_slice(data)?;
 
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Create, program_id)?;
+
+    // Verify that the validator is approved and still active
+    ensure_is_registered_validator(
+        validator_registry_pda,
+        &args.validator,
+        program_id,
+    )?;
+
     // Verify the programs
     ensure_is_program_id(system_program_id, &system_program::ID)?;
 
@@ -67,8 +82,10 @@ pub fn process(
     )?;
 
     // Initialize the escrow data
-    let lamport_escrow_data =
-        LamportEscrow { discriminant: LamportEscrow::discriminant() };
+    let lamport_escrow_data = LamportEscrow {
+        discriminant: LamportEscrow::discriminant(),
+        spending_limit: SpendingLimit::unlimited(),
+    };
     lamport_escrow_data.serialize(
         &mut &mut lamport_escrow_pda.try_borrow_mut_data()?.as_mut(),
     )?;
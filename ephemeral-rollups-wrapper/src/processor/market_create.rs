@@ -0,0 +1,127 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::market_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::market::Market;
+use crate::util::create::create_pda;
+use crate::util::ensure::ensure_is_admin;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_registered_validator;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x92, 0x9E, 0xE0, 0x99, 0xED, 0xF9, 0xD6, 0x12];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub base_token_mint: Pubkey,
+    pub quote_token_mint: Pubkey,
+    pub base_lot_size: u64,
+    pub min_order_lots: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, admin, authority, market_pda, system_program_id, config_pda, validator_registry_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Create, program_id)?;
+
+    // Verify that the validator is approved and still active
+    ensure_is_registered_validator(
+        validator_registry_pda,
+        &args.validator,
+        program_id,
+    )?;
+
+    // Verify the programs
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the config's admin is listing this market, as there can only
+    // be one per pair
+    ensure_is_admin(admin, config_pda, program_id)?;
+
+    // Verify that the market's authority agreed to manage it
+    // (or that enough of its multisig signers did)
+    ensure_is_authority(authority, signers, program_id)?;
+
+    // Verify that the market is trading two different mints in sensible lots
+    if args.base_token_mint == args.quote_token_mint
+        || args.base_lot_size == 0
+        || args.min_order_lots == 0
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Verify that the market PDA is currently un-initialized
+    ensure_is_owned_by_program(market_pda, &system_program::ID)?;
+
+    // Verify the seeds of the market PDA
+    let market_seeds = market_seeds_generator!(
+        args.validator,
+        args.base_token_mint,
+        args.quote_token_mint
+    );
+    let market_bump = ensure_is_pda(market_pda, market_seeds, program_id)?;
+
+    // Initialize the market PDA
+    create_pda(
+        payer,
+        market_pda,
+        market_seeds,
+        market_bump,
+        Market::space(),
+        program_id,
+        system_program_id,
+    )?;
+
+    // Initialize the market data
+    let market_data = Market {
+        discriminant: Market::discriminant(),
+        authority: *authority.key,
+        base_lot_size: args.base_lot_size,
+        min_order_lots: args.min_order_lots,
+        base_locked: 0,
+        quote_locked: 0,
+        next_order_id: 0,
+        orders: vec![],
+    };
+    market_data
+        .serialize(&mut &mut market_pda.try_borrow_mut_data()?.as_mut())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Created a new Market");
+    msg!(" - authority: {}", authority.key);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - base_token_mint: {}", args.base_token_mint);
+    msg!(" - quote_token_mint: {}", args.quote_token_mint);
+    msg!(" - base_lot_size: {}", args.base_lot_size);
+    msg!(" - min_order_lots: {}", args.min_order_lots);
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::consts::DELEGATION_PROGRAM_ID;
+use ephemeral_rollups_sdk::cpi::delegate_account;
+use ephemeral_rollups_sdk::cpi::DelegateAccounts;
+use ephemeral_rollups_sdk::cpi::DelegateConfig;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::market_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::market::Market;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_registered_validator;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xBC, 0xCA, 0x05, 0x09, 0xE4, 0x90, 0x7C, 0xF5];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub base_token_mint: Pubkey,
+    pub quote_token_mint: Pubkey,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, authority, market_pda, delegation_buffer_pda, delegation_record_pda, delegation_metadata_pda, delegation_program_id, owner_program_id, system_program_id, config_pda, validator_registry_pda] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Delegate, program_id)?;
+
+    // Verify that the validator is approved and still active
+    ensure_is_registered_validator(
+        validator_registry_pda,
+        &args.validator,
+        program_id,
+    )?;
+
+    // Verify the programs
+    ensure_is_program_id(delegation_program_id, &DELEGATION_PROGRAM_ID)?;
+    ensure_is_program_id(owner_program_id, program_id)?;
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the authority user is indeed the one initiating this IX
+    ensure_is_signer(authority)?;
+
+    // Verify that the program has proper control of the PDA (and that it's been
+    // initialized)
+    ensure_is_owned_by_program(market_pda, program_id)?;
+
+    // Verify the seeds of the market PDA
+    let market_seeds = market_seeds_generator!(
+        args.validator,
+        args.base_token_mint,
+        args.quote_token_mint
+    );
+    ensure_is_pda(market_pda, market_seeds, program_id)?;
+
+    // Verify that the market PDA is properly initalized
+    let market_data = Market::deserialize(&mut &market_pda.data.borrow()[..])?;
+    if market_data.discriminant != Market::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if market_data.authority != *authority.key {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Delegate the market, relinquish control on chain (it will become
+    // tradable in the Ephem)
+    let accounts = DelegateAccounts {
+        payer,
+        pda: market_pda,
+        owner_program: owner_program_id,
+        buffer: delegation_buffer_pda,
+        delegation_record: delegation_record_pda,
+        delegation_metadata: delegation_metadata_pda,
+        delegation_program: delegation_program_id,
+        system_program: system_program_id,
+    };
+    delegate_account(accounts, market_seeds, DelegateConfig::default())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Delegated a Market");
+    msg!(" - validator: {}", args.validator);
+    msg!(" - base_token_mint: {}", args.base_token_mint);
+    msg!(" - quote_token_mint: {}", args.quote_token_mint);
+    msg!(" - orders: {}", market_data.orders.len());
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::market_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::market::Market;
+use crate::state::market::MarketOrderSide;
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x7C, 0x5B, 0x2A, 0xDA, 0xE4, 0x06, 0xA2, 0x77];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub base_token_mint: Pubkey,
+    pub quote_token_mint: Pubkey,
+    pub order_id: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [maker, maker_token_escrow_pda, market_pda, config_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Withdraw, program_id)?;
+
+    // Verify that the maker is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(maker, signers, program_id)?;
+
+    // Verify that the program has proper control of the PDAs (and that they've
+    // been initialized)
+    ensure_is_owned_by_program(maker_token_escrow_pda, program_id)?;
+    ensure_is_owned_by_program(market_pda, program_id)?;
+
+    // Verify the seeds of the market PDA
+    let market_seeds = market_seeds_generator!(
+        args.validator,
+        args.base_token_mint,
+        args.quote_token_mint
+    );
+    ensure_is_pda(market_pda, market_seeds, program_id)?;
+
+    // Verify that the market PDA is properly initalized
+    let mut market_data =
+        Market::deserialize(&mut &market_pda.data.borrow()[..])?;
+    if market_data.discriminant != Market::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Find the order, only its maker can cancel it
+    let order_index = market_data
+        .orders
+        .iter()
+        .position(|order| order.id == args.order_id)
+        .ok_or(ProgramError::InvalidArgument)?;
+    let order = market_data.orders.remove(order_index);
+    if order.maker != *maker.key {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Compute what was still locked by the order
+    let (locked_token_mint, locked_amount) = match order.side {
+        MarketOrderSide::Bid => {
+            let quote_amount = market_data
+                .quote_amount(order.quantity, order.price)
+                .ok_or(ProgramError::InvalidAccountData)?;
+            market_data.quote_locked =
+                market_data.quote_locked.checked_sub(quote_amount).unwrap();
+            (args.quote_token_mint, quote_amount)
+        },
+        MarketOrderSide::Ask => {
+            market_data.base_locked =
+                market_data.base_locked.checked_sub(order.quantity).unwrap();
+            (args.base_token_mint, order.quantity)
+        },
+    };
+
+    // Verify the seeds of the escrow PDA the funds were locked from
+    let maker_token_escrow_seeds = token_escrow_seeds_generator!(
+        maker.key,
+        args.validator,
+        locked_token_mint,
+        order.locked_slot()
+    );
+    ensure_is_pda(
+        maker_token_escrow_pda,
+        maker_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Remove the order from the book
+    market_data
+        .serialize(&mut &mut market_pda.try_borrow_mut_data()?.as_mut())?;
+
+    // Unlock the funds back into the maker's escrow
+    let mut maker_token_escrow_data =
+        TokenEscrow::try_from_slice(&maker_token_escrow_pda.data.borrow())?;
+    if maker_token_escrow_data.discriminant != TokenEscrow::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    maker_token_escrow_data.amount =
+        maker_token_escrow_data.amount.checked_add(locked_amount).unwrap();
+    maker_token_escrow_data.serialize(
+        &mut &mut maker_token_escrow_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Cancelled an order on a Market");
+    msg!(" - maker: {} (slot: {})", maker.key, order.locked_slot());
+    msg!(" - validator: {}", args.validator);
+    msg!(" - base_token_mint: {}", args.base_token_mint);
+    msg!(" - quote_token_mint: {}", args.quote_token_mint);
+    msg!(
+        " - order: {} ({:?}, price: {}, quantity: {})",
+        order.id,
+        order.side,
+        order.price,
+        order.quantity
+    );
+    msg!(
+        " - unlocked: {} (total: {})",
+        locked_amount,
+        maker_token_escrow_data.amount
+    );
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,231 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::market_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::market::Market;
+use crate::state::market::MarketOrderSide;
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xB5, 0x18, 0xBE, 0xFB, 0x5B, 0x57, 0x77, 0x01];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub base_token_mint: Pubkey,
+    pub quote_token_mint: Pubkey,
+    pub bid_order_id: u64,
+    pub ask_order_id: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [bid_maker_base_token_escrow_pda, bid_maker_quote_token_escrow_pda, ask_maker_quote_token_escrow_pda, market_pda, config_pda] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Market, program_id)?;
+
+    // Verify that the program has proper control of the PDAs (and that they've
+    // been initialized)
+    ensure_is_owned_by_program(bid_maker_base_token_escrow_pda, program_id)?;
+    ensure_is_owned_by_program(bid_maker_quote_token_escrow_pda, program_id)?;
+    ensure_is_owned_by_program(ask_maker_quote_token_escrow_pda, program_id)?;
+    ensure_is_owned_by_program(market_pda, program_id)?;
+
+    // Verify the seeds of the market PDA
+    let market_seeds = market_seeds_generator!(
+        args.validator,
+        args.base_token_mint,
+        args.quote_token_mint
+    );
+    ensure_is_pda(market_pda, market_seeds, program_id)?;
+
+    // Verify that the market PDA is properly initalized
+    let mut market_data =
+        Market::deserialize(&mut &market_pda.data.borrow()[..])?;
+    if market_data.discriminant != Market::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Find both orders being matched
+    let bid_index = market_data
+        .orders
+        .iter()
+        .position(|order| {
+            order.id == args.bid_order_id && order.side == MarketOrderSide::Bid
+        })
+        .ok_or(ProgramError::InvalidArgument)?;
+    let ask_index = market_data
+        .orders
+        .iter()
+        .position(|order| {
+            order.id == args.ask_order_id && order.side == MarketOrderSide::Ask
+        })
+        .ok_or(ProgramError::InvalidArgument)?;
+    let bid = market_data.orders[bid_index].clone();
+    let ask = market_data.orders[ask_index].clone();
+
+    // The orders can only be matched if their prices cross
+    if bid.price < ask.price {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Verify the seeds of the escrow PDAs receiving the fill
+    let bid_maker_base_token_escrow_seeds = token_escrow_seeds_generator!(
+        bid.maker,
+        args.validator,
+        args.base_token_mint,
+        bid.maker_base_slot
+    );
+    ensure_is_pda(
+        bid_maker_base_token_escrow_pda,
+        bid_maker_base_token_escrow_seeds,
+        program_id,
+    )?;
+    let bid_maker_quote_token_escrow_seeds = token_escrow_seeds_generator!(
+        bid.maker,
+        args.validator,
+        args.quote_token_mint,
+        bid.maker_quote_slot
+    );
+    ensure_is_pda(
+        bid_maker_quote_token_escrow_pda,
+        bid_maker_quote_token_escrow_seeds,
+        program_id,
+    )?;
+    let ask_maker_quote_token_escrow_seeds = token_escrow_seeds_generator!(
+        ask.maker,
+        args.validator,
+        args.quote_token_mint,
+        ask.maker_quote_slot
+    );
+    ensure_is_pda(
+        ask_maker_quote_token_escrow_pda,
+        ask_maker_quote_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // The fill happens at the price of the order that was resting first, any
+    // difference with what the bid locked is refunded to the bid's maker
+    let fill_price = if bid.id < ask.id { bid.price } else { ask.price };
+    let fill_quantity = bid.quantity.min(ask.quantity);
+    let fill_quote_amount = market_data
+        .quote_amount(fill_quantity, fill_price)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let bid_unlocked_quote_amount = market_data
+        .quote_amount(fill_quantity, bid.price)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let bid_refund_quote_amount =
+        bid_unlocked_quote_amount.checked_sub(fill_quote_amount).unwrap();
+
+    // Update the book, removing the orders that were completely filled
+    market_data.base_locked =
+        market_data.base_locked.checked_sub(fill_quantity).unwrap();
+    market_data.quote_locked = market_data
+        .quote_locked
+        .checked_sub(bid_unlocked_quote_amount)
+        .unwrap();
+    market_data.orders[bid_index].quantity =
+        bid.quantity.checked_sub(fill_quantity).unwrap();
+    market_data.orders[ask_index].quantity =
+        ask.quantity.checked_sub(fill_quantity).unwrap();
+    market_data.orders.retain(|order| order.quantity > 0);
+    market_data
+        .serialize(&mut &mut market_pda.try_borrow_mut_data()?.as_mut())?;
+
+    // Credit the base tokens bought by the bid's maker
+    let mut bid_maker_base_token_escrow_data = TokenEscrow::try_from_slice(
+        &bid_maker_base_token_escrow_pda.data.borrow(),
+    )?;
+    if bid_maker_base_token_escrow_data.discriminant
+        != TokenEscrow::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    bid_maker_base_token_escrow_data.amount = bid_maker_base_token_escrow_data
+        .amount
+        .checked_add(fill_quantity)
+        .unwrap();
+    bid_maker_base_token_escrow_data.serialize(
+        &mut &mut bid_maker_base_token_escrow_pda
+            .try_borrow_mut_data()?
+            .as_mut(),
+    )?;
+
+    // Credit the price improvement back to the bid's maker
+    let mut bid_maker_quote_token_escrow_data = TokenEscrow::try_from_slice(
+        &bid_maker_quote_token_escrow_pda.data.borrow(),
+    )?;
+    if bid_maker_quote_token_escrow_data.discriminant
+        != TokenEscrow::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    bid_maker_quote_token_escrow_data.amount =
+        bid_maker_quote_token_escrow_data
+            .amount
+            .checked_add(bid_refund_quote_amount)
+            .unwrap();
+    bid_maker_quote_token_escrow_data.serialize(
+        &mut &mut bid_maker_quote_token_escrow_pda
+            .try_borrow_mut_data()?
+            .as_mut(),
+    )?;
+
+    // Credit the quote tokens earned by the ask's maker
+    let mut ask_maker_quote_token_escrow_data = TokenEscrow::try_from_slice(
+        &ask_maker_quote_token_escrow_pda.data.borrow(),
+    )?;
+    if ask_maker_quote_token_escrow_data.discriminant
+        != TokenEscrow::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    ask_maker_quote_token_escrow_data.amount =
+        ask_maker_quote_token_escrow_data
+            .amount
+            .checked_add(fill_quote_amount)
+            .unwrap();
+    ask_maker_quote_token_escrow_data.serialize(
+        &mut &mut ask_maker_quote_token_escrow_pda
+            .try_borrow_mut_data()?
+            .as_mut(),
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Matched orders on a Market");
+    msg!(" - validator: {}", args.validator);
+    msg!(" - base_token_mint: {}", args.base_token_mint);
+    msg!(" - quote_token_mint: {}", args.quote_token_mint);
+    msg!(" - bid: {} (maker: {})", bid.id, bid.maker);
+    msg!(" - ask: {} (maker: {})", ask.id, ask.maker);
+    msg!(
+        " - fill: {} at {} (quote: {}, refund: {})",
+        fill_quantity,
+        fill_price,
+        fill_quote_amount,
+        bid_refund_quote_amount
+    );
+
+    // Done
+    Ok(())
+}
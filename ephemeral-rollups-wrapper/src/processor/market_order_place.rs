@@ -0,0 +1,189 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::market_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::market::Market;
+use crate::state::market::MarketOrder;
+use crate::state::market::MarketOrderSide;
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_unfrozen;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::limit::consume_spending_limit;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x3C, 0xC2, 0x8A, 0xDA, 0xB7, 0x93, 0x5B, 0x18];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub base_token_mint: Pubkey,
+    pub quote_token_mint: Pubkey,
+    pub maker_base_slot: u64,
+    pub maker_quote_slot: u64,
+    pub side: MarketOrderSide,
+    pub price: u64,
+    pub quantity: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [maker, maker_token_escrow_pda, market_pda, config_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Market, program_id)?;
+
+    // Verify that the maker is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(maker, signers, program_id)?;
+
+    // Verify that the program has proper control of the PDAs (and that they've
+    // been initialized)
+    ensure_is_owned_by_program(maker_token_escrow_pda, program_id)?;
+    ensure_is_owned_by_program(market_pda, program_id)?;
+
+    // Verify the seeds of the market PDA
+    let market_seeds = market_seeds_generator!(
+        args.validator,
+        args.base_token_mint,
+        args.quote_token_mint
+    );
+    ensure_is_pda(market_pda, market_seeds, program_id)?;
+
+    // Bids lock quote tokens, asks lock base tokens
+    let (locked_token_mint, locked_slot) = match args.side {
+        MarketOrderSide::Bid => (args.quote_token_mint, args.maker_quote_slot),
+        MarketOrderSide::Ask => (args.base_token_mint, args.maker_base_slot),
+    };
+
+    // Verify the seeds of the escrow PDA the funds are locked from
+    let maker_token_escrow_seeds = token_escrow_seeds_generator!(
+        maker.key,
+        args.validator,
+        locked_token_mint,
+        locked_slot
+    );
+    ensure_is_pda(
+        maker_token_escrow_pda,
+        maker_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Verify that the market PDA is properly initalized
+    let mut market_data =
+        Market::deserialize(&mut &market_pda.data.borrow()[..])?;
+    if market_data.discriminant != Market::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Verify that the order is well formed, large enough not to be dust, and
+    // that there is room for it
+    if args.price == 0
+        || args.quantity
+            < market_data
+                .base_lot_size
+                .saturating_mul(market_data.min_order_lots)
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if market_data.orders.len() >= Market::max_orders() {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    let quote_amount = market_data
+        .quote_amount(args.quantity, args.price)
+        .ok_or(ProgramError::InvalidArgument)?;
+    let locked_amount = match args.side {
+        MarketOrderSide::Bid => quote_amount,
+        MarketOrderSide::Ask => args.quantity,
+    };
+
+    // Lock the funds out of the maker's escrow (panic if not enough amount
+    // available)
+    let mut maker_token_escrow_data =
+        TokenEscrow::try_from_slice(&maker_token_escrow_pda.data.borrow())?;
+    if maker_token_escrow_data.discriminant != TokenEscrow::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    // Verify that the issuer didn't freeze the escrow
+    ensure_is_unfrozen(&maker_token_escrow_data)?;
+    // Verify that the escrow's spending limit allows this amount to leave
+    consume_spending_limit(
+        &mut maker_token_escrow_data.spending_limit,
+        locked_amount,
+    )?;
+    maker_token_escrow_data.amount =
+        maker_token_escrow_data.amount.checked_sub(locked_amount).unwrap();
+    maker_token_escrow_data.serialize(
+        &mut &mut maker_token_escrow_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Record the order in the book
+    let order_id = market_data.next_order_id;
+    match args.side {
+        MarketOrderSide::Bid => {
+            market_data.quote_locked =
+                market_data.quote_locked.checked_add(locked_amount).unwrap();
+        },
+        MarketOrderSide::Ask => {
+            market_data.base_locked =
+                market_data.base_locked.checked_add(locked_amount).unwrap();
+        },
+    }
+    market_data.next_order_id = order_id.checked_add(1).unwrap();
+    market_data.orders.push(MarketOrder {
+        id: order_id,
+        maker: *maker.key,
+        maker_base_slot: args.maker_base_slot,
+        maker_quote_slot: args.maker_quote_slot,
+        side: args.side,
+        price: args.price,
+        quantity: args.quantity,
+    });
+    market_data
+        .serialize(&mut &mut market_pda.try_borrow_mut_data()?.as_mut())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Placed an order on a Market");
+    msg!(
+        " - maker: {} (base_slot: {}, quote_slot: {})",
+        maker.key,
+        args.maker_base_slot,
+        args.maker_quote_slot
+    );
+    msg!(" - validator: {}", args.validator);
+    msg!(" - base_token_mint: {}", args.base_token_mint);
+    msg!(" - quote_token_mint: {}", args.quote_token_mint);
+    msg!(
+        " - order: {} ({:?}, price: {}, quantity: {})",
+        order_id,
+        args.side,
+        args.price,
+        args.quantity
+    );
+    msg!(
+        " - locked: {} (remaining: {})",
+        locked_amount,
+        maker_token_escrow_data.amount
+    );
+
+    // Done
+    Ok(())
+}
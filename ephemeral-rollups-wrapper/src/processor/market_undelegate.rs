@@ -0,0 +1,92 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::consts::MAGIC_PROGRAM_ID;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::market_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::market::Market;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x42, 0x0E, 0xE4, 0x8C, 0x94, 0x47, 0x1F, 0x59];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub base_token_mint: Pubkey,
+    pub quote_token_mint: Pubkey,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let [payer, authority, market_pda, magic_context_pda, magic_program_id, config_pda] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Undelegate, program_id)?;
+
+    // Verify the programs
+    ensure_is_program_id(magic_program_id, &MAGIC_PROGRAM_ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the authority user is indeed the one initiating this IX
+    ensure_is_signer(authority)?;
+
+    // Verify that the program has proper control of the PDA (and that it's been
+    // initialized)
+    ensure_is_owned_by_program(market_pda, program_id)?;
+
+    // Verify the seeds of the market PDA
+    let market_seeds = market_seeds_generator!(
+        args.validator,
+        args.base_token_mint,
+        args.quote_token_mint
+    );
+    ensure_is_pda(market_pda, market_seeds, program_id)?;
+
+    // Verify that the market PDA is properly initalized
+    let market_data = Market::deserialize(&mut &market_pda.data.borrow()[..])?;
+    if market_data.discriminant != Market::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if market_data.authority != *authority.key {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Request undelegation inside the ER
+    commit_and_undelegate_accounts(
+        payer,
+        vec![market_pda],
+        magic_context_pda,
+        magic_program_id,
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Requested undelegation of a Market");
+    msg!(" - validator: {}", args.validator);
+    msg!(" - base_token_mint: {}", args.base_token_mint);
+    msg!(" - quote_token_mint: {}", args.quote_token_mint);
+    msg!(" - orders: {}", market_data.orders.len());
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::multisig_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::multisig::Multisig;
+use crate::util::create::create_pda;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x9E, 0xDB, 0x32, 0xB1, 0x9C, 0x04, 0x8E, 0x61];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub slot: u64,
+    pub threshold: u8,
+    pub signers: Vec<Pubkey>,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, creator, multisig_pda, system_program_id, config_pda] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Create, program_id)?;
+
+    // Verify the programs
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the creator is indeed the one initiating this IX
+    ensure_is_signer(creator)?;
+
+    // Verify that the multisig PDA is currently un-initialized
+    ensure_is_owned_by_program(multisig_pda, &system_program::ID)?;
+
+    // Verify the seeds of the multisig PDA
+    let multisig_seeds = multisig_seeds_generator!(creator.key, args.slot);
+    let multisig_bump =
+        ensure_is_pda(multisig_pda, multisig_seeds, program_id)?;
+
+    // Verify that the signer set is usable (M-of-N, no duplicates)
+    let multisig_data = Multisig {
+        discriminant: Multisig::discriminant(),
+        threshold: args.threshold,
+        signers: args.signers,
+    };
+    if !multisig_data.is_valid() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Initialize the multisig PDA
+    create_pda(
+        payer,
+        multisig_pda,
+        multisig_seeds,
+        multisig_bump,
+        Multisig::space(),
+        program_id,
+        system_program_id,
+    )?;
+
+    // Initialize the multisig data
+    multisig_data
+        .serialize(&mut &mut multisig_pda.try_borrow_mut_data()?.as_mut())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Created a new Multisig");
+    msg!(" - multisig: {}", multisig_pda.key);
+    msg!(" - creator: {} (slot: {})", creator.key, args.slot);
+    msg!(
+        " - threshold: {} of {}",
+        multisig_data.threshold,
+        multisig_data.signers.len()
+    );
+
+    // Done
+    Ok(())
+}
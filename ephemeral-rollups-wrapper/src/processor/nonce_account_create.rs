@@ -0,0 +1,81 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::nonce_account_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::nonce_account::NonceAccount;
+use crate::util::create::create_pda;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xC5, 0xF0, 0xAD, 0xDB, 0x9B, 0x36, 0xF0, 0x9A];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub authority: Pubkey,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, nonce_account_pda, system_program_id, config_pda] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Create, program_id)?;
+
+    // Verify the programs
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the nonce PDA is currently un-initialized
+    ensure_is_owned_by_program(nonce_account_pda, &system_program::ID)?;
+
+    // Verify the seeds of the nonce PDA
+    let nonce_account_seeds = nonce_account_seeds_generator!(args.authority);
+    let nonce_account_bump =
+        ensure_is_pda(nonce_account_pda, nonce_account_seeds, program_id)?;
+
+    // Initialize the nonce PDA
+    create_pda(
+        payer,
+        nonce_account_pda,
+        nonce_account_seeds,
+        nonce_account_bump,
+        NonceAccount::space(),
+        program_id,
+        system_program_id,
+    )?;
+
+    // Initialize the nonce data
+    let nonce_account_data =
+        NonceAccount { discriminant: NonceAccount::discriminant(), nonce: 0 };
+    nonce_account_data.serialize(
+        &mut &mut nonce_account_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Created a new NonceAccount");
+    msg!(" - authority: {}", args.authority);
+
+    // Done
+    Ok(())
+}
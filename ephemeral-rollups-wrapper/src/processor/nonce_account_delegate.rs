@@ -0,0 +1,105 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::consts::DELEGATION_PROGRAM_ID;
+use ephemeral_rollups_sdk::cpi::delegate_account;
+use ephemeral_rollups_sdk::cpi::DelegateAccounts;
+use ephemeral_rollups_sdk::cpi::DelegateConfig;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::nonce_account_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::nonce_account::NonceAccount;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_registered_validator;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x9F, 0x25, 0x05, 0x8D, 0x42, 0xE7, 0xB0, 0x07];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, authority, nonce_account_pda, delegation_buffer_pda, delegation_record_pda, delegation_metadata_pda, delegation_program_id, owner_program_id, system_program_id, config_pda, validator_registry_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Delegate, program_id)?;
+
+    // Verify that the validator is approved and still active
+    ensure_is_registered_validator(
+        validator_registry_pda,
+        &args.validator,
+        program_id,
+    )?;
+
+    // Verify the programs
+    ensure_is_program_id(delegation_program_id, &DELEGATION_PROGRAM_ID)?;
+    ensure_is_program_id(owner_program_id, program_id)?;
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the authority user is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(authority, signers, program_id)?;
+
+    // Verify that the program has proper control of the PDA (and that it's been
+    // initialized)
+    ensure_is_owned_by_program(nonce_account_pda, program_id)?;
+
+    // Verify the seeds of the nonce PDA
+    let nonce_account_seeds = nonce_account_seeds_generator!(authority.key);
+    ensure_is_pda(nonce_account_pda, nonce_account_seeds, program_id)?;
+
+    // Verify that the nonce PDA is properly initalized
+    let nonce_account_data =
+        NonceAccount::try_from_slice(&nonce_account_pda.data.borrow())?;
+    if nonce_account_data.discriminant != NonceAccount::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Delegate the nonce account, relinquish control on chain (it will become
+    // usable in the Ephem)
+    let accounts = DelegateAccounts {
+        payer,
+        pda: nonce_account_pda,
+        owner_program: owner_program_id,
+        buffer: delegation_buffer_pda,
+        delegation_record: delegation_record_pda,
+        delegation_metadata: delegation_metadata_pda,
+        delegation_program: delegation_program_id,
+        system_program: system_program_id,
+    };
+    delegate_account(accounts, nonce_account_seeds, DelegateConfig::default())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Delegated a NonceAccount");
+    msg!(" - authority: {}", authority.key);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - nonce: {}", nonce_account_data.nonce);
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use borsh::BorshDeserialize;
+use ephemeral_rollups_sdk::consts::MAGIC_PROGRAM_ID;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::nonce_account_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::nonce_account::NonceAccount;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x1D, 0xAC, 0xF1, 0x86, 0xAE, 0x0F, 0x5D, 0x50];
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    let [payer, authority, nonce_account_pda, magic_context_pda, magic_program_id, config_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Undelegate, program_id)?;
+
+    // Verify the programs
+    ensure_is_program_id(magic_program_id, &MAGIC_PROGRAM_ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the authority user is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(authority, signers, program_id)?;
+
+    // Verify that the program has proper control of the PDA (and that it's been
+    // initialized)
+    ensure_is_owned_by_program(nonce_account_pda, program_id)?;
+
+    // Verify the seeds of the nonce PDA
+    let nonce_account_seeds = nonce_account_seeds_generator!(authority.key);
+    ensure_is_pda(nonce_account_pda, nonce_account_seeds, program_id)?;
+
+    // Verify that the nonce PDA is properly initalized
+    let nonce_account_data =
+        NonceAccount::try_from_slice(&nonce_account_pda.data.borrow())?;
+    if nonce_account_data.discriminant != NonceAccount::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Request undelegation inside the ER
+    commit_and_undelegate_accounts(
+        payer,
+        vec![nonce_account_pda],
+        magic_context_pda,
+        magic_program_id,
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Requested undelegation of a NonceAccount");
+    msg!(" - authority: {}", authority.key);
+    msg!(" - nonce: {}", nonce_account_data.nonce);
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::consts::MAGIC_PROGRAM_ID;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::token_escrow_delegate_eth::Intent;
+use crate::state::config::ConfigPause;
+use crate::state::eth_authority::eth_authority_generate_pda;
+use crate::state::nonce_account::NonceAccount;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::nonce::consume_nonce;
+use crate::util::signature::eip191_message;
+use crate::util::signature::ensure_is_secp256k1_signed;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x08, 0x5A, 0x66, 0xE8, 0x18, 0x10, 0x9A, 0x22];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub eth_address: [u8; 20],
+    pub nonce: u64,
+    pub expiry: i64,
+}
+
+// The bytes the Ethereum wallet signs off-chain with personal_sign (EIP-191),
+// prefixed with the IX discriminant so that they can't be mistaken for another
+// kind of intent
+pub fn message(intent: &Intent) -> Vec<u8> {
+    let mut payload = Vec::new();
+    payload.extend_from_slice(&DISCRIMINANT);
+    intent.serialize(&mut payload).unwrap();
+    eip191_message(&payload)
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let [payer, nonce_account_pda, instructions_sysvar, magic_context_pda, magic_program_id, config_pda] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Undelegate, program_id)?;
+
+    // Verify the programs
+    ensure_is_program_id(magic_program_id, &MAGIC_PROGRAM_ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // The nonce account of the Ethereum wallet is owned by its derived
+    // authority
+    let authority = eth_authority_generate_pda(&args.eth_address, program_id);
+
+    // Verify that the Ethereum wallet signed this exact undelegation off-chain
+    ensure_is_secp256k1_signed(
+        instructions_sysvar,
+        &args.eth_address,
+        &message(&Intent {
+            account: *nonce_account_pda.key,
+            nonce: args.nonce,
+            expiry: args.expiry,
+        }),
+    )?;
+
+    // Verify that the intent is not stale and was never submitted before (this
+    // also verifies the seeds of the nonce PDA and that it's been delegated)
+    consume_nonce(
+        nonce_account_pda,
+        &authority,
+        args.nonce,
+        args.expiry,
+        program_id,
+    )?;
+    let nonce_account_data =
+        NonceAccount::try_from_slice(&nonce_account_pda.data.borrow())?;
+
+    // Request undelegation inside the ER
+    commit_and_undelegate_accounts(
+        payer,
+        vec![nonce_account_pda],
+        magic_context_pda,
+        magic_program_id,
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Requested undelegation of an Ethereum NonceAccount intent");
+    msg!(" - authority: {}", authority);
+    msg!(" - eth_address: {:02x?}", args.eth_address);
+    msg!(" - nonce: {}", nonce_account_data.nonce);
+
+    // Done
+    Ok(())
+}
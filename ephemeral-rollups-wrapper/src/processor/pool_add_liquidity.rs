@@ -0,0 +1,193 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::pool_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::pool::Pool;
+use crate::state::pool::PoolPosition;
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_unfrozen;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::limit::consume_spending_limit;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xF0, 0xAD, 0xB4, 0x07, 0x09, 0x12, 0x3A, 0x8F];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub provider_slot: u64,
+    pub amount_a: u64,
+    pub amount_b: u64,
+    pub minimum_shares: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [provider, provider_token_escrow_a_pda, provider_token_escrow_b_pda, pool_pda, config_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Pool, program_id)?;
+
+    // Verify that the provider is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(provider, signers, program_id)?;
+
+    // Verify that the program has proper control of the PDAs (and that they've
+    // been initialized)
+    ensure_is_owned_by_program(provider_token_escrow_a_pda, program_id)?;
+    ensure_is_owned_by_program(provider_token_escrow_b_pda, program_id)?;
+    ensure_is_owned_by_program(pool_pda, program_id)?;
+
+    // Verify the seeds of the pool PDA
+    let pool_seeds = pool_seeds_generator!(
+        args.validator,
+        args.token_mint_a,
+        args.token_mint_b
+    );
+    ensure_is_pda(pool_pda, pool_seeds, program_id)?;
+
+    // Verify the seeds of the escrow PDAs providing the liquidity
+    let provider_token_escrow_a_seeds = token_escrow_seeds_generator!(
+        provider.key,
+        args.validator,
+        args.token_mint_a,
+        args.provider_slot
+    );
+    ensure_is_pda(
+        provider_token_escrow_a_pda,
+        provider_token_escrow_a_seeds,
+        program_id,
+    )?;
+    let provider_token_escrow_b_seeds = token_escrow_seeds_generator!(
+        provider.key,
+        args.validator,
+        args.token_mint_b,
+        args.provider_slot
+    );
+    ensure_is_pda(
+        provider_token_escrow_b_pda,
+        provider_token_escrow_b_seeds,
+        program_id,
+    )?;
+
+    // Verify that the pool PDA is properly initalized
+    let mut pool_data = Pool::deserialize(&mut &pool_pda.data.borrow()[..])?;
+    if pool_data.discriminant != Pool::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Compute the shares owed to the provider, any amount provided in excess
+    // of the current reserves ratio is donated to the pool
+    let shares = pool_data
+        .compute_shares_minted(args.amount_a, args.amount_b)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if shares == 0 || shares < args.minimum_shares {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Update the provider's position
+    match pool_data
+        .positions
+        .iter_mut()
+        .find(|position| position.provider == *provider.key)
+    {
+        Some(position) => {
+            position.shares = position.shares.checked_add(shares).unwrap();
+        },
+        None => {
+            if pool_data.positions.len() >= Pool::max_positions() {
+                return Err(ProgramError::AccountDataTooSmall);
+            }
+            // Positions are limited in number, so they can't be dust
+            if shares < pool_data.min_position_shares {
+                return Err(ProgramError::InvalidArgument);
+            }
+            pool_data
+                .positions
+                .push(PoolPosition { provider: *provider.key, shares });
+        },
+    }
+
+    // Update the pool reserves
+    pool_data.reserve_a =
+        pool_data.reserve_a.checked_add(args.amount_a).unwrap();
+    pool_data.reserve_b =
+        pool_data.reserve_b.checked_add(args.amount_b).unwrap();
+    pool_data.total_shares =
+        pool_data.total_shares.checked_add(shares).unwrap();
+    pool_data.serialize(&mut &mut pool_pda.try_borrow_mut_data()?.as_mut())?;
+
+    // Update the provider's escrow amounts (panic if not enough amount
+    // available)
+    let mut provider_token_escrow_a_data = TokenEscrow::try_from_slice(
+        &provider_token_escrow_a_pda.data.borrow(),
+    )?;
+    if provider_token_escrow_a_data.discriminant != TokenEscrow::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    // Verify that the issuer didn't freeze the escrow
+    ensure_is_unfrozen(&provider_token_escrow_a_data)?;
+    // Verify that the escrow's spending limit allows this amount to leave
+    consume_spending_limit(
+        &mut provider_token_escrow_a_data.spending_limit,
+        args.amount_a,
+    )?;
+    provider_token_escrow_a_data.amount =
+        provider_token_escrow_a_data.amount.checked_sub(args.amount_a).unwrap();
+    provider_token_escrow_a_data.serialize(
+        &mut &mut provider_token_escrow_a_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+    let mut provider_token_escrow_b_data = TokenEscrow::try_from_slice(
+        &provider_token_escrow_b_pda.data.borrow(),
+    )?;
+    if provider_token_escrow_b_data.discriminant != TokenEscrow::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    // Verify that the issuer didn't freeze the escrow
+    ensure_is_unfrozen(&provider_token_escrow_b_data)?;
+    // Verify that the escrow's spending limit allows this amount to leave
+    consume_spending_limit(
+        &mut provider_token_escrow_b_data.spending_limit,
+        args.amount_b,
+    )?;
+    provider_token_escrow_b_data.amount =
+        provider_token_escrow_b_data.amount.checked_sub(args.amount_b).unwrap();
+    provider_token_escrow_b_data.serialize(
+        &mut &mut provider_token_escrow_b_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Added liquidity to a Pool");
+    msg!(" - provider: {} (slot: {})", provider.key, args.provider_slot);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint_a: {}", args.token_mint_a);
+    msg!(" - token_mint_b: {}", args.token_mint_b);
+    msg!(" - amounts: {} / {}", args.amount_a, args.amount_b);
+    msg!(" - shares: {} (total: {})", shares, pool_data.total_shares);
+
+    // Done
+    Ok(())
+}
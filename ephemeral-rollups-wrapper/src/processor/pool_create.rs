@@ -0,0 +1,127 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::pool_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::pool::Pool;
+use crate::util::create::create_pda;
+use crate::util::ensure::ensure_is_admin;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_registered_validator;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x61, 0x4B, 0x29, 0x96, 0x0F, 0x6D, 0xC5, 0x75];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub fee_basis_points: u16,
+    pub min_position_shares: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, admin, authority, pool_pda, system_program_id, config_pda, validator_registry_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Create, program_id)?;
+
+    // Verify that the validator is approved and still active
+    ensure_is_registered_validator(
+        validator_registry_pda,
+        &args.validator,
+        program_id,
+    )?;
+
+    // Verify the programs
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the config's admin is listing this pool, as there can only
+    // be one per pair
+    ensure_is_admin(admin, config_pda, program_id)?;
+
+    // Verify that the pool's authority agreed to manage it
+    // (or that enough of its multisig signers did)
+    ensure_is_authority(authority, signers, program_id)?;
+
+    // Verify that the pool is between two different mints in canonical order
+    // (so that a pair can't have two pools), with a sensible fee
+    if args.token_mint_a >= args.token_mint_b
+        || args.fee_basis_points > Pool::max_fee_basis_points()
+        || args.min_position_shares == 0
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Verify that the pool PDA is currently un-initialized
+    ensure_is_owned_by_program(pool_pda, &system_program::ID)?;
+
+    // Verify the seeds of the pool PDA
+    let pool_seeds = pool_seeds_generator!(
+        args.validator,
+        args.token_mint_a,
+        args.token_mint_b
+    );
+    let pool_bump = ensure_is_pda(pool_pda, pool_seeds, program_id)?;
+
+    // Initialize the pool PDA
+    create_pda(
+        payer,
+        pool_pda,
+        pool_seeds,
+        pool_bump,
+        Pool::space(),
+        program_id,
+        system_program_id,
+    )?;
+
+    // Initialize the pool data
+    let pool_data = Pool {
+        discriminant: Pool::discriminant(),
+        authority: *authority.key,
+        fee_basis_points: args.fee_basis_points,
+        min_position_shares: args.min_position_shares,
+        reserve_a: 0,
+        reserve_b: 0,
+        total_shares: 0,
+        positions: vec![],
+    };
+    pool_data.serialize(&mut &mut pool_pda.try_borrow_mut_data()?.as_mut())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Created a new Pool");
+    msg!(" - authority: {}", authority.key);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint_a: {}", args.token_mint_a);
+    msg!(" - token_mint_b: {}", args.token_mint_b);
+    msg!(" - fee_basis_points: {}", args.fee_basis_points);
+    msg!(" - min_position_shares: {}", args.min_position_shares);
+
+    // Done
+    Ok(())
+}
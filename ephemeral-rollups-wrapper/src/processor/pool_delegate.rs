@@ -0,0 +1,112 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::consts::DELEGATION_PROGRAM_ID;
+use ephemeral_rollups_sdk::cpi::delegate_account;
+use ephemeral_rollups_sdk::cpi::DelegateAccounts;
+use ephemeral_rollups_sdk::cpi::DelegateConfig;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::pool_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::pool::Pool;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_registered_validator;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x11, 0x7B, 0x2D, 0x51, 0x0C, 0x04, 0x19, 0x9F];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, authority, pool_pda, delegation_buffer_pda, delegation_record_pda, delegation_metadata_pda, delegation_program_id, owner_program_id, system_program_id, config_pda, validator_registry_pda] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Delegate, program_id)?;
+
+    // Verify that the validator is approved and still active
+    ensure_is_registered_validator(
+        validator_registry_pda,
+        &args.validator,
+        program_id,
+    )?;
+
+    // Verify the programs
+    ensure_is_program_id(delegation_program_id, &DELEGATION_PROGRAM_ID)?;
+    ensure_is_program_id(owner_program_id, program_id)?;
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the authority user is indeed the one initiating this IX
+    ensure_is_signer(authority)?;
+
+    // Verify that the program has proper control of the PDA (and that it's been
+    // initialized)
+    ensure_is_owned_by_program(pool_pda, program_id)?;
+
+    // Verify the seeds of the pool PDA
+    let pool_seeds = pool_seeds_generator!(
+        args.validator,
+        args.token_mint_a,
+        args.token_mint_b
+    );
+    ensure_is_pda(pool_pda, pool_seeds, program_id)?;
+
+    // Verify that the pool PDA is properly initalized
+    let pool_data = Pool::deserialize(&mut &pool_pda.data.borrow()[..])?;
+    if pool_data.discriminant != Pool::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if pool_data.authority != *authority.key {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Delegate the pool, relinquish control on chain (it will become
+    // swappable in the Ephem)
+    let accounts = DelegateAccounts {
+        payer,
+        pda: pool_pda,
+        owner_program: owner_program_id,
+        buffer: delegation_buffer_pda,
+        delegation_record: delegation_record_pda,
+        delegation_metadata: delegation_metadata_pda,
+        delegation_program: delegation_program_id,
+        system_program: system_program_id,
+    };
+    delegate_account(accounts, pool_seeds, DelegateConfig::default())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Delegated a Pool");
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint_a: {}", args.token_mint_a);
+    msg!(" - token_mint_b: {}", args.token_mint_b);
+    msg!(" - reserves: {} / {}", pool_data.reserve_a, pool_data.reserve_b);
+
+    // Done
+    Ok(())
+}
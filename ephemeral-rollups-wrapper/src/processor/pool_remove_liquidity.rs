@@ -0,0 +1,165 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::pool_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::pool::Pool;
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xBA, 0x83, 0x10, 0x2E, 0xF2, 0xAA, 0x3A, 0x80];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub provider_slot: u64,
+    pub shares: u64,
+    pub minimum_amount_a: u64,
+    pub minimum_amount_b: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [provider, provider_token_escrow_a_pda, provider_token_escrow_b_pda, pool_pda, config_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Withdraw, program_id)?;
+
+    // Verify that the provider is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(provider, signers, program_id)?;
+
+    // Verify that the program has proper control of the PDAs (and that they've
+    // been initialized)
+    ensure_is_owned_by_program(provider_token_escrow_a_pda, program_id)?;
+    ensure_is_owned_by_program(provider_token_escrow_b_pda, program_id)?;
+    ensure_is_owned_by_program(pool_pda, program_id)?;
+
+    // Verify the seeds of the pool PDA
+    let pool_seeds = pool_seeds_generator!(
+        args.validator,
+        args.token_mint_a,
+        args.token_mint_b
+    );
+    ensure_is_pda(pool_pda, pool_seeds, program_id)?;
+
+    // Verify the seeds of the escrow PDAs receiving the liquidity
+    let provider_token_escrow_a_seeds = token_escrow_seeds_generator!(
+        provider.key,
+        args.validator,
+        args.token_mint_a,
+        args.provider_slot
+    );
+    ensure_is_pda(
+        provider_token_escrow_a_pda,
+        provider_token_escrow_a_seeds,
+        program_id,
+    )?;
+    let provider_token_escrow_b_seeds = token_escrow_seeds_generator!(
+        provider.key,
+        args.validator,
+        args.token_mint_b,
+        args.provider_slot
+    );
+    ensure_is_pda(
+        provider_token_escrow_b_pda,
+        provider_token_escrow_b_seeds,
+        program_id,
+    )?;
+
+    // Verify that the pool PDA is properly initalized
+    let mut pool_data = Pool::deserialize(&mut &pool_pda.data.borrow()[..])?;
+    if pool_data.discriminant != Pool::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Compute the amounts owed for the redeemed shares
+    let (amount_a, amount_b) = pool_data
+        .compute_amounts_redeemed(args.shares)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if amount_a < args.minimum_amount_a || amount_b < args.minimum_amount_b {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Update the provider's position (panic if not enough shares available)
+    let position_index = pool_data
+        .positions
+        .iter()
+        .position(|position| position.provider == *provider.key)
+        .ok_or(ProgramError::InvalidArgument)?;
+    let position = &mut pool_data.positions[position_index];
+    position.shares = position.shares.checked_sub(args.shares).unwrap();
+    if position.shares == 0 {
+        pool_data.positions.remove(position_index);
+    }
+    // Positions are limited in number, so they can't be left as dust
+    else if position.shares < pool_data.min_position_shares {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Update the pool reserves
+    pool_data.reserve_a = pool_data.reserve_a.checked_sub(amount_a).unwrap();
+    pool_data.reserve_b = pool_data.reserve_b.checked_sub(amount_b).unwrap();
+    pool_data.total_shares =
+        pool_data.total_shares.checked_sub(args.shares).unwrap();
+    pool_data.serialize(&mut &mut pool_pda.try_borrow_mut_data()?.as_mut())?;
+
+    // Update the provider's escrow amounts (if everything else succeeded)
+    let mut provider_token_escrow_a_data = TokenEscrow::try_from_slice(
+        &provider_token_escrow_a_pda.data.borrow(),
+    )?;
+    if provider_token_escrow_a_data.discriminant != TokenEscrow::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    provider_token_escrow_a_data.amount =
+        provider_token_escrow_a_data.amount.checked_add(amount_a).unwrap();
+    provider_token_escrow_a_data.serialize(
+        &mut &mut provider_token_escrow_a_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+    let mut provider_token_escrow_b_data = TokenEscrow::try_from_slice(
+        &provider_token_escrow_b_pda.data.borrow(),
+    )?;
+    if provider_token_escrow_b_data.discriminant != TokenEscrow::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    provider_token_escrow_b_data.amount =
+        provider_token_escrow_b_data.amount.checked_add(amount_b).unwrap();
+    provider_token_escrow_b_data.serialize(
+        &mut &mut provider_token_escrow_b_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Removed liquidity from a Pool");
+    msg!(" - provider: {} (slot: {})", provider.key, args.provider_slot);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint_a: {}", args.token_mint_a);
+    msg!(" - token_mint_b: {}", args.token_mint_b);
+    msg!(" - amounts: {} / {}", amount_a, amount_b);
+    msg!(" - shares: {} (total: {})", args.shares, pool_data.total_shares);
+
+    // Done
+    Ok(())
+}
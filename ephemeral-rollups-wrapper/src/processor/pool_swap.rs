@@ -0,0 +1,184 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::pool_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::pool::Pool;
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_unfrozen;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::limit::consume_spending_limit;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xC8, 0xE8, 0x8B, 0x65, 0xB5, 0xE0, 0xD5, 0x06];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub source_slot: u64,
+    pub destination_slot: u64,
+    pub a_to_b: bool,
+    pub amount_in: u64,
+    pub minimum_amount_out: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [trader, source_token_escrow_pda, destination_token_escrow_pda, pool_pda, config_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Pool, program_id)?;
+
+    // Verify that the trader is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(trader, signers, program_id)?;
+
+    // Verify that the program has proper control of the PDAs (and that they've
+    // been initialized)
+    ensure_is_owned_by_program(source_token_escrow_pda, program_id)?;
+    ensure_is_owned_by_program(destination_token_escrow_pda, program_id)?;
+    ensure_is_owned_by_program(pool_pda, program_id)?;
+
+    // Verify the seeds of the pool PDA
+    let pool_seeds = pool_seeds_generator!(
+        args.validator,
+        args.token_mint_a,
+        args.token_mint_b
+    );
+    ensure_is_pda(pool_pda, pool_seeds, program_id)?;
+
+    // Which direction the swap is going
+    let (source_token_mint, destination_token_mint) = if args.a_to_b {
+        (args.token_mint_a, args.token_mint_b)
+    }
+    else {
+        (args.token_mint_b, args.token_mint_a)
+    };
+
+    // Verify the seeds of the escrow PDAs
+    let source_token_escrow_seeds = token_escrow_seeds_generator!(
+        trader.key,
+        args.validator,
+        source_token_mint,
+        args.source_slot
+    );
+    ensure_is_pda(
+        source_token_escrow_pda,
+        source_token_escrow_seeds,
+        program_id,
+    )?;
+    let destination_token_escrow_seeds = token_escrow_seeds_generator!(
+        trader.key,
+        args.validator,
+        destination_token_mint,
+        args.destination_slot
+    );
+    ensure_is_pda(
+        destination_token_escrow_pda,
+        destination_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Verify that the pool PDA is properly initalized
+    let mut pool_data = Pool::deserialize(&mut &pool_pda.data.borrow()[..])?;
+    if pool_data.discriminant != Pool::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Compute the output of the swap against the constant product curve
+    let (reserve_in, reserve_out) = if args.a_to_b {
+        (pool_data.reserve_a, pool_data.reserve_b)
+    }
+    else {
+        (pool_data.reserve_b, pool_data.reserve_a)
+    };
+    let amount_out = pool_data
+        .compute_amount_out(args.amount_in, reserve_in, reserve_out)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if amount_out == 0 || amount_out < args.minimum_amount_out {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Update the pool reserves, the fee stays in the pool for the providers
+    let reserve_in = reserve_in.checked_add(args.amount_in).unwrap();
+    let reserve_out = reserve_out.checked_sub(amount_out).unwrap();
+    if args.a_to_b {
+        pool_data.reserve_a = reserve_in;
+        pool_data.reserve_b = reserve_out;
+    }
+    else {
+        pool_data.reserve_b = reserve_in;
+        pool_data.reserve_a = reserve_out;
+    }
+    pool_data.serialize(&mut &mut pool_pda.try_borrow_mut_data()?.as_mut())?;
+
+    // Update the source escrow amount (panic if not enough amount available)
+    let mut source_token_escrow_data =
+        TokenEscrow::try_from_slice(&source_token_escrow_pda.data.borrow())?;
+    if source_token_escrow_data.discriminant != TokenEscrow::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    // Verify that the issuer didn't freeze the escrow
+    ensure_is_unfrozen(&source_token_escrow_data)?;
+    // Verify that the escrow's spending limit allows this amount to leave
+    consume_spending_limit(
+        &mut source_token_escrow_data.spending_limit,
+        args.amount_in,
+    )?;
+    source_token_escrow_data.amount =
+        source_token_escrow_data.amount.checked_sub(args.amount_in).unwrap();
+    source_token_escrow_data.serialize(
+        &mut &mut source_token_escrow_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Update the destination escrow amount (if everything else suceeded)
+    let mut destination_token_escrow_data = TokenEscrow::try_from_slice(
+        &destination_token_escrow_pda.data.borrow(),
+    )?;
+    if destination_token_escrow_data.discriminant != TokenEscrow::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    destination_token_escrow_data.amount =
+        destination_token_escrow_data.amount.checked_add(amount_out).unwrap();
+    destination_token_escrow_data.serialize(
+        &mut &mut destination_token_escrow_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Swapped through a Pool");
+    msg!(
+        " - trader: {} (source slot: {}, destination slot: {})",
+        trader.key,
+        args.source_slot,
+        args.destination_slot
+    );
+    msg!(" - validator: {}", args.validator);
+    msg!(" - source_token_mint: {}", source_token_mint);
+    msg!(" - destination_token_mint: {}", destination_token_mint);
+    msg!(" - amounts: {} in, {} out", args.amount_in, amount_out);
+    msg!(" - reserves: {} / {}", pool_data.reserve_a, pool_data.reserve_b);
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::consts::MAGIC_PROGRAM_ID;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::pool_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::pool::Pool;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xF5, 0xDB, 0x27, 0xF4, 0x5A, 0xA7, 0xA6, 0x66];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let [payer, authority, pool_pda, magic_context_pda, magic_program_id, config_pda] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Undelegate, program_id)?;
+
+    // Verify the programs
+    ensure_is_program_id(magic_program_id, &MAGIC_PROGRAM_ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the authority user is indeed the one initiating this IX
+    ensure_is_signer(authority)?;
+
+    // Verify that the program has proper control of the PDA (and that it's been
+    // initialized)
+    ensure_is_owned_by_program(pool_pda, program_id)?;
+
+    // Verify the seeds of the pool PDA
+    let pool_seeds = pool_seeds_generator!(
+        args.validator,
+        args.token_mint_a,
+        args.token_mint_b
+    );
+    ensure_is_pda(pool_pda, pool_seeds, program_id)?;
+
+    // Verify that the pool PDA is properly initalized
+    let pool_data = Pool::deserialize(&mut &pool_pda.data.borrow()[..])?;
+    if pool_data.discriminant != Pool::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if pool_data.authority != *authority.key {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Request undelegation inside the ER
+    commit_and_undelegate_accounts(
+        payer,
+        vec![pool_pda],
+        magic_context_pda,
+        magic_program_id,
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Requested undelegation of a Pool");
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint_a: {}", args.token_mint_a);
+    msg!(" - token_mint_b: {}", args.token_mint_b);
+    msg!(" - reserves: {} / {}", pool_data.reserve_a, pool_data.reserve_b);
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::recovery_seeds_generator;
+use crate::state::recovery::Recovery;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x9E, 0xCE, 0x54, 0xEC, 0x5A, 0x84, 0xAA, 0x78];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub authority: Pubkey,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [proposed_authority, recovery_pda, signers @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that the program has proper control of the PDA (and that it's been
+    // initialized)
+    ensure_is_owned_by_program(recovery_pda, program_id)?;
+
+    // Verify the seeds of the recovery PDA
+    ensure_is_pda(
+        recovery_pda,
+        recovery_seeds_generator!(args.authority),
+        program_id,
+    )?;
+
+    // Verify that there is a recovery in progress for this new authority
+    let mut recovery_data =
+        Recovery::deserialize(&mut &recovery_pda.data.borrow()[..])?;
+    if recovery_data.discriminant != Recovery::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if !recovery_data.pending
+        || recovery_data.proposed_authority != *proposed_authority.key
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Verify that the recovered authority is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(proposed_authority, signers, program_id)?;
+
+    // The recovery is over, it can't be replayed on escrows funded later
+    recovery_data.pending = false;
+    recovery_data.proposed_authority = Pubkey::default();
+    recovery_data.proposed_at = 0;
+    recovery_data
+        .serialize(&mut &mut recovery_pda.try_borrow_mut_data()?.as_mut())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Closed a Recovery");
+    msg!(" - authority: {}", args.authority);
+    msg!(" - proposed_authority: {}", proposed_authority.key);
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,184 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::recovery_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::recovery::Recovery;
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_unfrozen;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::transfer::credit_token_escrow;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x49, 0x5C, 0x27, 0x29, 0xBD, 0x75, 0x70, 0x34];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub authority: Pubkey,
+    pub token_escrows: Vec<ArgsTokenEscrow>,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct ArgsTokenEscrow {
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub slot: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [proposed_authority, recovery_pda, config_pda, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // The (source, destination) escrow pairs come first, followed by the
+    // multisig signers
+    let token_escrow_pdas_len = args
+        .token_escrows
+        .len()
+        .checked_mul(2)
+        .ok_or(ProgramError::InvalidArgument)?;
+    if remaining.len() < token_escrow_pdas_len {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (token_escrow_pdas, signers) =
+        remaining.split_at(token_escrow_pdas_len);
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Transfer, program_id)?;
+
+    // Verify that the program has proper control of the PDA (and that it's been
+    // initialized)
+    ensure_is_owned_by_program(recovery_pda, program_id)?;
+
+    // Verify the seeds of the recovery PDA
+    ensure_is_pda(
+        recovery_pda,
+        recovery_seeds_generator!(args.authority),
+        program_id,
+    )?;
+
+    // Verify that a proposal went through its whole waiting period unvetoed
+    let recovery_data =
+        Recovery::deserialize(&mut &recovery_pda.data.borrow()[..])?;
+    if recovery_data.discriminant != Recovery::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if !recovery_data.is_executable(Clock::get()?.unix_timestamp) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Verify that the recovered authority is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    if recovery_data.proposed_authority != *proposed_authority.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+    ensure_is_authority(proposed_authority, signers, program_id)?;
+
+    // Verify that every escrow is given its (source, destination) pair, so
+    // that none of them can be silently skipped
+    if token_escrow_pdas.len() != 2 * args.token_escrows.len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // Empty each of the lost authority's escrows into the recovered authority's
+    let mut amounts = Vec::with_capacity(args.token_escrows.len());
+    for (token_escrow, token_escrow_pdas) in
+        args.token_escrows.iter().zip(token_escrow_pdas.chunks_exact(2))
+    {
+        let [source_token_escrow_pda, destination_token_escrow_pda] =
+            token_escrow_pdas
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Verify that the program has proper control of the escrow PDA (and
+        // that it's been initialized)
+        ensure_is_owned_by_program(source_token_escrow_pda, program_id)?;
+
+        // Verify that the program has proper control of the escrow PDA (and
+        // that it's been initialized)
+        ensure_is_owned_by_program(destination_token_escrow_pda, program_id)?;
+
+        // Verify the seeds of the escrow PDA
+        let source_token_escrow_seeds = token_escrow_seeds_generator!(
+            args.authority,
+            token_escrow.validator,
+            token_escrow.token_mint,
+            token_escrow.slot
+        );
+        ensure_is_pda(
+            source_token_escrow_pda,
+            source_token_escrow_seeds,
+            program_id,
+        )?;
+
+        // Verify the seeds of the escrow PDA (owned by the recovered authority)
+        let destination_token_escrow_seeds = token_escrow_seeds_generator!(
+            proposed_authority.key,
+            token_escrow.validator,
+            token_escrow.token_mint,
+            token_escrow.slot
+        );
+        ensure_is_pda(
+            destination_token_escrow_pda,
+            destination_token_escrow_seeds,
+            program_id,
+        )?;
+
+        // Empty the lost authority's escrow entirely
+        let mut source_token_escrow_data = TokenEscrow::try_from_slice(
+            &source_token_escrow_pda.data.borrow(),
+        )?;
+        if source_token_escrow_data.discriminant != TokenEscrow::discriminant()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // Verify that the issuer didn't freeze the escrow
+        ensure_is_unfrozen(&source_token_escrow_data)?;
+        let amount = source_token_escrow_data.amount;
+        source_token_escrow_data.amount = 0;
+        source_token_escrow_data.serialize(
+            &mut &mut source_token_escrow_pda.try_borrow_mut_data()?.as_mut(),
+        )?;
+
+        // Update the destination escrow amount (if everything else suceeded)
+        credit_token_escrow(destination_token_escrow_pda, amount)?;
+        amounts.push(amount);
+    }
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Executed a Recovery");
+    msg!(" - authority: {}", args.authority);
+    msg!(" - proposed_authority: {}", proposed_authority.key);
+    for (token_escrow, amount) in args.token_escrows.iter().zip(amounts) {
+        msg!(
+            " - token_mint: {} (validator: {}, slot: {}, amount: {})",
+            token_escrow.token_mint,
+            token_escrow.validator,
+            token_escrow.slot,
+            amount
+        );
+    }
+
+    // Done
+    Ok(())
+}
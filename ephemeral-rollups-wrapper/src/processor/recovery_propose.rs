@@ -0,0 +1,93 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::recovery_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::recovery::Recovery;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xB7, 0xD2, 0x57, 0x66, 0xE2, 0x72, 0xF5, 0x8C];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub authority: Pubkey,
+    pub proposed_authority: Pubkey,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [recovery_pda, config_pda, guardians @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Transfer, program_id)?;
+
+    // Verify that the program has proper control of the PDA (and that it's been
+    // initialized)
+    ensure_is_owned_by_program(recovery_pda, program_id)?;
+
+    // Verify the seeds of the recovery PDA
+    ensure_is_pda(
+        recovery_pda,
+        recovery_seeds_generator!(args.authority),
+        program_id,
+    )?;
+
+    // Verify that enough of the guardians chosen by the authority signed
+    let mut recovery_data =
+        Recovery::deserialize(&mut &recovery_pda.data.borrow()[..])?;
+    if recovery_data.discriminant != Recovery::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let guardians = guardians
+        .iter()
+        .filter(|guardian| guardian.is_signer)
+        .map(|guardian| guardian.key)
+        .collect::<Vec<_>>();
+    let approvals = recovery_data.count_approvals(&guardians);
+    if approvals < usize::from(recovery_data.threshold) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Start the waiting period (restarting it if a proposal was pending)
+    recovery_data.pending = true;
+    recovery_data.proposed_authority = args.proposed_authority;
+    recovery_data.proposed_at = Clock::get()?.unix_timestamp;
+    recovery_data
+        .serialize(&mut &mut recovery_pda.try_borrow_mut_data()?.as_mut())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Proposed a Recovery");
+    msg!(" - authority: {}", args.authority);
+    msg!(
+        " - approvals: {} (threshold: {})",
+        approvals,
+        recovery_data.threshold
+    );
+    msg!(" - proposed_authority: {}", args.proposed_authority);
+    msg!(
+        " - proposed_at: {} (delay_seconds: {})",
+        recovery_data.proposed_at,
+        recovery_data.delay_seconds
+    );
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::recovery_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::recovery::Recovery;
+use crate::util::create::create_pda;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x45, 0x27, 0x42, 0xCB, 0xFE, 0x73, 0xC0, 0x1E];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub threshold: u8,
+    pub guardians: Vec<Pubkey>,
+    pub delay_seconds: i64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, authority, recovery_pda, system_program_id, config_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Create, program_id)?;
+
+    // Verify the programs
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the authority user is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(authority, signers, program_id)?;
+
+    // Verify that the guardian set is usable and that its waiting period is
+    // long enough for the authority to notice and veto a rogue proposal
+    let recovery_data = Recovery {
+        discriminant: Recovery::discriminant(),
+        threshold: args.threshold,
+        guardians: args.guardians,
+        delay_seconds: args.delay_seconds,
+        pending: false,
+        proposed_authority: Pubkey::default(),
+        proposed_at: 0,
+    };
+    if !recovery_data.is_valid() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Verify the seeds of the recovery PDA
+    let recovery_seeds = recovery_seeds_generator!(authority.key);
+    let recovery_bump =
+        ensure_is_pda(recovery_pda, recovery_seeds, program_id)?;
+
+    // Initialize the recovery PDA if this authority never had guardians
+    if recovery_pda.owner == &system_program::ID {
+        create_pda(
+            payer,
+            recovery_pda,
+            recovery_seeds,
+            recovery_bump,
+            Recovery::space(),
+            program_id,
+            system_program_id,
+        )?;
+    }
+    else {
+        // Verify that the program has proper control of the PDA (and that it's
+        // been initialized)
+        ensure_is_owned_by_program(recovery_pda, program_id)?;
+        if recovery_pda.data.borrow().get(..8)
+            != Some(&Recovery::discriminant().to_le_bytes()[..])
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    // Update the guardians, dropping any pending recovery proposal
+    recovery_data
+        .serialize(&mut &mut recovery_pda.try_borrow_mut_data()?.as_mut())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Updated the Recovery");
+    msg!(" - authority: {}", authority.key);
+    msg!(
+        " - guardians: {} (threshold: {})",
+        recovery_data.guardians.len(),
+        recovery_data.threshold
+    );
+    msg!(" - delay_seconds: {}", recovery_data.delay_seconds);
+
+    // Done
+    Ok(())
+}
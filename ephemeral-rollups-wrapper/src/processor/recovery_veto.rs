@@ -0,0 +1,64 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::recovery_seeds_generator;
+use crate::state::recovery::Recovery;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xD7, 0x12, 0x75, 0x31, 0x6B, 0xE5, 0x8E, 0x34];
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [authority, recovery_pda, signers @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Verify that the authority user is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(authority, signers, program_id)?;
+
+    // Verify that the program has proper control of the PDA (and that it's been
+    // initialized)
+    ensure_is_owned_by_program(recovery_pda, program_id)?;
+
+    // Verify the seeds of the recovery PDA
+    ensure_is_pda(
+        recovery_pda,
+        recovery_seeds_generator!(authority.key),
+        program_id,
+    )?;
+
+    // Drop the pending proposal, if any
+    let mut recovery_data =
+        Recovery::deserialize(&mut &recovery_pda.data.borrow()[..])?;
+    if recovery_data.discriminant != Recovery::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let proposed_authority = recovery_data.proposed_authority;
+    recovery_data.pending = false;
+    recovery_data.proposed_authority = Pubkey::default();
+    recovery_data.proposed_at = 0;
+    recovery_data
+        .serialize(&mut &mut recovery_pda.try_borrow_mut_data()?.as_mut())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Vetoed a Recovery");
+    msg!(" - authority: {}", authority.key);
+    msg!(" - proposed_authority: {}", proposed_authority);
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::split_config_seeds_generator;
+use crate::state::config::ConfigPause;
+use crate::state::split_config::SplitConfig;
+use crate::state::split_config::SplitConfigRecipient;
+use crate::util::create::create_pda;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xE8, 0xDD, 0x38, 0x13, 0xD9, 0xC1, 0x43, 0xD4];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub slot: u64,
+    pub recipients: Vec<SplitConfigRecipient>,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, creator, split_config_pda, system_program_id, config_pda] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Create, program_id)?;
+
+    // Verify the programs
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the creator is indeed the one initiating this IX
+    ensure_is_signer(creator)?;
+
+    // Verify that the split config PDA is currently un-initialized
+    ensure_is_owned_by_program(split_config_pda, &system_program::ID)?;
+
+    // Verify the seeds of the split config PDA
+    let split_config_seeds =
+        split_config_seeds_generator!(creator.key, args.slot);
+    let split_config_bump =
+        ensure_is_pda(split_config_pda, split_config_seeds, program_id)?;
+
+    // Verify that the shares are usable (adding up to exactly 100%)
+    let split_config_data = SplitConfig {
+        discriminant: SplitConfig::discriminant(),
+        recipients: args.recipients,
+    };
+    if !split_config_data.is_valid() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Initialize the split config PDA
+    create_pda(
+        payer,
+        split_config_pda,
+        split_config_seeds,
+        split_config_bump,
+        SplitConfig::space(),
+        program_id,
+        system_program_id,
+    )?;
+
+    // Initialize the split config data
+    split_config_data.serialize(
+        &mut &mut split_config_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Created a new SplitConfig");
+    msg!(" - split_config: {}", split_config_pda.key);
+    msg!(" - creator: {} (slot: {})", creator.key, args.slot);
+    for recipient in &split_config_data.recipients {
+        msg!(
+            " - destination_authority: {} (slot: {}, share: {} basis points)",
+            recipient.destination_authority,
+            recipient.destination_slot,
+            recipient.share_basis_points
+        );
+    }
+
+    // Done
+    Ok(())
+}
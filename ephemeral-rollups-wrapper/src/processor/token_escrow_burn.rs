@@ -0,0 +1,154 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::config::ConfigPause;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_vault_state::TokenVaultState;
+use crate::token_escrow_seeds_generator;
+use crate::token_vault_seeds_generator;
+use crate::token_vault_state_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_token_program;
+use crate::util::ensure::ensure_is_unfrozen;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::limit::consume_spending_limit;
+use crate::util::signer::signer_seeds;
+use crate::util::token::burn;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x8A, 0x6F, 0xB2, 0x51, 0x75, 0x52, 0xBA, 0x97];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub slot: u64,
+    pub amount: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [authority, token_mint, token_escrow_pda, token_vault_pda, token_vault_state_pda, token_program_id, config_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Withdraw, program_id)?;
+
+    // Verify the programs
+    ensure_is_token_program(token_program_id)?;
+
+    // Verify that the burned mint is the one of the escrow
+    if token_mint.key != &args.token_mint {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Verify that the authority user is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(authority, signers, program_id)?;
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized)
+    ensure_is_owned_by_program(token_escrow_pda, program_id)?;
+
+    // Verify that the vault has been initialized properly
+    ensure_is_owned_by_program(token_vault_pda, token_program_id.key)?;
+    ensure_is_owned_by_program(token_vault_state_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDA
+    let token_escrow_seeds = token_escrow_seeds_generator!(
+        authority.key,
+        args.validator,
+        args.token_mint,
+        args.slot
+    );
+    ensure_is_pda(token_escrow_pda, token_escrow_seeds, program_id)?;
+
+    // Verify the seeds of the vault PDA
+    let token_vault_seeds =
+        token_vault_seeds_generator!(args.validator, args.token_mint);
+    let token_vault_bump =
+        ensure_is_pda(token_vault_pda, token_vault_seeds, program_id)?;
+
+    // Verify the seeds of the vault state PDA
+    let token_vault_state_seeds =
+        token_vault_state_seeds_generator!(args.validator, args.token_mint);
+    ensure_is_pda(token_vault_state_pda, token_vault_state_seeds, program_id)?;
+
+    // Update the escrow amount (panic if not enough amount available)
+    let mut token_escrow_data =
+        TokenEscrow::try_from_slice(&token_escrow_pda.data.borrow())?;
+    if token_escrow_data.discriminant != TokenEscrow::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    // Verify that the issuer didn't freeze the escrow
+    ensure_is_unfrozen(&token_escrow_data)?;
+    // Verify that the escrow's spending limit allows this amount to leave
+    consume_spending_limit(&mut token_escrow_data.spending_limit, args.amount)?;
+    token_escrow_data.amount =
+        token_escrow_data.amount.checked_sub(args.amount).unwrap();
+    token_escrow_data.serialize(
+        &mut &mut token_escrow_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Update the vault accounting, keeping track of the escrowed supply
+    let mut token_vault_state_data =
+        TokenVaultState::try_from_slice(&token_vault_state_pda.data.borrow())?;
+    if token_vault_state_data.discriminant != TokenVaultState::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    token_vault_state_data.total_withdrawn = token_vault_state_data
+        .total_withdrawn
+        .checked_add(args.amount)
+        .unwrap();
+    token_vault_state_data.outstanding_amount = token_vault_state_data
+        .outstanding_amount
+        .checked_sub(args.amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    token_vault_state_data.serialize(
+        &mut &mut token_vault_state_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Proceed to burn the amount from token_vault_pda (if everything else
+    // succeeded)
+    invoke_signed(
+        &burn(
+            token_program_id.key,
+            token_vault_pda.key,
+            token_mint.key,
+            token_vault_pda.key,
+            args.amount,
+        ),
+        &[token_vault_pda.clone(), token_mint.clone(), token_vault_pda.clone()],
+        &[&signer_seeds(token_vault_seeds, &[token_vault_bump])],
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Burned from TokenEscrow");
+    msg!(" - authority: {} (slot: {})", authority.key, args.slot);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(
+        " - amount: {} (remaining: {})",
+        args.amount,
+        token_escrow_data.amount
+    );
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::config::ConfigPause;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_vault_state::TokenVaultState;
+use crate::token_escrow_seeds_generator;
+use crate::token_vault_seeds_generator;
+use crate::token_vault_state_seeds_generator;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::mint::load_decimals;
+use crate::util::mint::load_permanent_delegate;
+use crate::util::signer::signer_seeds;
+use crate::util::token::transfer_checked;
+use crate::util::token::TOKEN_2022_PROGRAM_ID;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xD4, 0x03, 0x94, 0x78, 0xBC, 0x5A, 0x85, 0x25];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub authority: Pubkey,
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub slot: u64,
+    pub amount: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [permanent_delegate, token_mint, destination_token_account, token_escrow_pda, token_vault_pda, token_vault_state_pda, token_program_id, config_pda] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Withdraw, program_id)?;
+
+    // Verify the programs
+    ensure_is_program_id(token_program_id, &TOKEN_2022_PROGRAM_ID)?;
+
+    // Verify that the mint's permanent delegate is the one initiating this IX
+    ensure_is_signer(permanent_delegate)?;
+    if token_mint.key != &args.token_mint {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if load_permanent_delegate(token_mint)? != Some(*permanent_delegate.key) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized)
+    ensure_is_owned_by_program(token_escrow_pda, program_id)?;
+
+    // Verify that the vault has been initialized properly
+    ensure_is_owned_by_program(token_vault_pda, token_program_id.key)?;
+    ensure_is_owned_by_program(token_vault_state_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDA
+    let token_escrow_seeds = token_escrow_seeds_generator!(
+        args.authority,
+        args.validator,
+        args.token_mint,
+        args.slot
+    );
+    ensure_is_pda(token_escrow_pda, token_escrow_seeds, program_id)?;
+
+    // Verify the seeds of the vault PDA
+    let token_vault_seeds =
+        token_vault_seeds_generator!(args.validator, args.token_mint);
+    let token_vault_bump =
+        ensure_is_pda(token_vault_pda, token_vault_seeds, program_id)?;
+
+    // Verify the seeds of the vault state PDA
+    let token_vault_state_seeds =
+        token_vault_state_seeds_generator!(args.validator, args.token_mint);
+    ensure_is_pda(token_vault_state_pda, token_vault_state_seeds, program_id)?;
+
+    // Update the escrow amount (the issuer's clawback ignores the escrow's
+    // frozen flag and spending limit)
+    let mut token_escrow_data =
+        TokenEscrow::try_from_slice(&token_escrow_pda.data.borrow())?;
+    if token_escrow_data.discriminant != TokenEscrow::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    token_escrow_data.amount =
+        token_escrow_data.amount.checked_sub(args.amount).unwrap();
+    token_escrow_data.serialize(
+        &mut &mut token_escrow_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Update the vault accounting, keeping track of the escrowed supply
+    let mut token_vault_state_data =
+        TokenVaultState::try_from_slice(&token_vault_state_pda.data.borrow())?;
+    if token_vault_state_data.discriminant != TokenVaultState::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    token_vault_state_data.total_withdrawn = token_vault_state_data
+        .total_withdrawn
+        .checked_add(args.amount)
+        .unwrap();
+    token_vault_state_data.outstanding_amount = token_vault_state_data
+        .outstanding_amount
+        .checked_sub(args.amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    token_vault_state_data.serialize(
+        &mut &mut token_vault_state_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Proceed to transfer from token_vault_pda to destination_token_account (if
+    // everything else succeeded)
+    invoke_signed(
+        &transfer_checked(
+            token_program_id.key,
+            token_vault_pda.key,
+            token_mint.key,
+            destination_token_account.key,
+            token_vault_pda.key,
+            args.amount,
+            load_decimals(token_mint)?,
+        ),
+        &[
+            token_vault_pda.clone(),
+            token_mint.clone(),
+            destination_token_account.clone(),
+            token_vault_pda.clone(),
+        ],
+        &[&signer_seeds(token_vault_seeds, &[token_vault_bump])],
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Clawed back from TokenEscrow");
+    msg!(" - authority: {} (slot: {})", args.authority, args.slot);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(" - permanent_delegate: {}", permanent_delegate.key);
+    msg!(" - destination_token_account: {}", destination_token_account.key);
+    msg!(
+        " - amount: {} (remaining: {})",
+        args.amount,
+        token_escrow_data.amount
+    );
+
+    // Done
+    Ok(())
+}
@@ -7,13 +7,17 @@ use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 use solana_program::system_program;
 
+use crate::state::config::ConfigPause;
+use crate::state::spending_limit::SpendingLimit;
 use crate::state::token_escrow::TokenEscrow;
 use crate::token_escrow_seeds_generator;
 use crate::util::create::create_pda;
 use crate::util::ensure::ensure_is_owned_by_program;
 use crate::util::ensure::ensure_is_pda;
 use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_registered_validator;
 use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
 
 pub const DISCRIMINANT: [u8; 8] =
     [0xFE, 0x25, 0x5A, 0x94, 0x2E, 0x8E, 0x50, 0xAC];
@@ -32,12 +36,23 @@ pub fn process(
     data: &[u8],
 ) -> ProgramResult {
     // Read instruction inputs
-    let [payer, token_escrow_pda, system_program_id] = accounts
+    let [payer, token_escrow_pda, system_program_id, config_pda, validator_registry_pda] =
+        accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     let args = Args::try_from_slice(data)?;
 
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Create, program_id)?;
+
+    // Verify that the validator is approved and still active
+    ensure_is_registered_validator(
+        validator_registry_pda,
+        &args.validator,
+        program_id,
+    )?;
+
     // Verify the programs
     ensure_is_program_id(system_program_id, &system_program::ID)?;
 
@@ -69,8 +84,12 @@ pub fn process(
     )?;
 
     // Initialize the escrow data
-    let token_escrow_data =
-        TokenEscrow { discriminant: TokenEscrow::discriminant(), amount: 0 };
+    let token_escrow_data = TokenEscrow {
+        discriminant: TokenEscrow::discriminant(),
+        amount: 0,
+        spending_limit: SpendingLimit::unlimited(),
+        frozen: false,
+    };
     token_escrow_data.serialize(
         &mut &mut token_escrow_pda.try_borrow_mut_data()?.as_mut(),
     )?;
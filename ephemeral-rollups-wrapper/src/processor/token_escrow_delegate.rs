@@ -11,12 +11,17 @@ use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 use solana_program::system_program;
 
+use crate::state::config::ConfigPause;
 use crate::state::token_escrow::TokenEscrow;
 use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
 use crate::util::ensure::ensure_is_owned_by_program;
 use crate::util::ensure::ensure_is_pda;
 use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_registered_validator;
 use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unfrozen;
+use crate::util::ensure::ensure_is_unpaused;
 
 pub const DISCRIMINANT: [u8; 8] =
     [0xC6, 0xD6, 0x5C, 0x5F, 0xF8, 0xCC, 0xE0, 0x2C];
@@ -34,13 +39,23 @@ pub fn process(
     data: &[u8],
 ) -> ProgramResult {
     // Read instruction inputs
-    let [payer, authority, token_escrow_pda, delegation_buffer_pda, delegation_record_pda, delegation_metadata_pda, delegation_program_id, owner_program_id, system_program_id] =
+    let [payer, authority, token_escrow_pda, delegation_buffer_pda, delegation_record_pda, delegation_metadata_pda, delegation_program_id, owner_program_id, system_program_id, config_pda, validator_registry_pda, signers @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     let args = Args::try_from_slice(data)?;
 
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Delegate, program_id)?;
+
+    // Verify that the validator is approved and still active
+    ensure_is_registered_validator(
+        validator_registry_pda,
+        &args.validator,
+        program_id,
+    )?;
+
     // Verify the programs
     ensure_is_program_id(delegation_program_id, &DELEGATION_PROGRAM_ID)?;
     ensure_is_program_id(owner_program_id, program_id)?;
@@ -50,7 +65,8 @@ pub fn process(
     ensure_is_signer(payer)?;
 
     // Verify that the authority user is indeed the one initiating this IX
-    ensure_is_signer(authority)?;
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(authority, signers, program_id)?;
 
     // Verify that the program has proper control of the PDA (and that it's been
     // initialized)
@@ -72,6 +88,9 @@ pub fn process(
         return Err(ProgramError::InvalidAccountData);
     }
 
+    // Verify that a frozen escrow stays on chain, within the issuer's reach
+    ensure_is_unfrozen(&token_escrow_data)?;
+
     // Delegate the escrow, relinquish control on chain (it will become usable
     // in the Ephem)
     let accounts = DelegateAccounts {
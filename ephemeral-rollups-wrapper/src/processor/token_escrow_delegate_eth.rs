@@ -0,0 +1,161 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::consts::DELEGATION_PROGRAM_ID;
+use ephemeral_rollups_sdk::cpi::delegate_account;
+use ephemeral_rollups_sdk::cpi::DelegateAccounts;
+use ephemeral_rollups_sdk::cpi::DelegateConfig;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::state::config::ConfigPause;
+use crate::state::eth_authority::eth_authority_generate_pda;
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_registered_validator;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unfrozen;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::nonce::consume_nonce;
+use crate::util::signature::eip191_message;
+use crate::util::signature::ensure_is_secp256k1_signed;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xAF, 0xB9, 0xCB, 0xDE, 0x37, 0x87, 0x7A, 0xCC];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub eth_address: [u8; 20],
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub slot: u64,
+    pub nonce: u64,
+    pub expiry: i64,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Intent {
+    pub account: Pubkey,
+    pub nonce: u64,
+    pub expiry: i64,
+}
+
+// The bytes the Ethereum wallet signs off-chain with personal_sign (EIP-191),
+// prefixed with the IX discriminant so that they can't be mistaken for another
+// kind of intent
+pub fn message(intent: &Intent) -> Vec<u8> {
+    let mut payload = Vec::new();
+    payload.extend_from_slice(&DISCRIMINANT);
+    intent.serialize(&mut payload).unwrap();
+    eip191_message(&payload)
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, token_escrow_pda, delegation_buffer_pda, delegation_record_pda, delegation_metadata_pda, delegation_program_id, owner_program_id, system_program_id, nonce_account_pda, instructions_sysvar, config_pda, validator_registry_pda] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Delegate, program_id)?;
+
+    // Verify that the validator is approved and still active
+    ensure_is_registered_validator(
+        validator_registry_pda,
+        &args.validator,
+        program_id,
+    )?;
+
+    // Verify the programs
+    ensure_is_program_id(delegation_program_id, &DELEGATION_PROGRAM_ID)?;
+    ensure_is_program_id(owner_program_id, program_id)?;
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // The escrows of the Ethereum wallet are owned by its derived authority
+    let authority = eth_authority_generate_pda(&args.eth_address, program_id);
+
+    // Verify that the Ethereum wallet signed this exact delegation off-chain
+    ensure_is_secp256k1_signed(
+        instructions_sysvar,
+        &args.eth_address,
+        &message(&Intent {
+            account: *token_escrow_pda.key,
+            nonce: args.nonce,
+            expiry: args.expiry,
+        }),
+    )?;
+
+    // Verify that the intent is not stale and was never submitted before
+    consume_nonce(
+        nonce_account_pda,
+        &authority,
+        args.nonce,
+        args.expiry,
+        program_id,
+    )?;
+
+    // Verify that the program has proper control of the PDA (and that it's been
+    // initialized)
+    ensure_is_owned_by_program(token_escrow_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDA
+    let token_escrow_seeds = token_escrow_seeds_generator!(
+        authority,
+        args.validator,
+        args.token_mint,
+        args.slot
+    );
+    ensure_is_pda(token_escrow_pda, token_escrow_seeds, program_id)?;
+
+    // Verify that the escrow PDA is properly initalized
+    let token_escrow_data =
+        TokenEscrow::try_from_slice(&token_escrow_pda.data.borrow())?;
+    if token_escrow_data.discriminant != TokenEscrow::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Verify that a frozen escrow stays on chain, within the issuer's reach
+    ensure_is_unfrozen(&token_escrow_data)?;
+
+    // Delegate the escrow, relinquish control on chain (it will become usable
+    // in the Ephem)
+    let accounts = DelegateAccounts {
+        payer,
+        pda: token_escrow_pda,
+        owner_program: owner_program_id,
+        buffer: delegation_buffer_pda,
+        delegation_record: delegation_record_pda,
+        delegation_metadata: delegation_metadata_pda,
+        delegation_program: delegation_program_id,
+        system_program: system_program_id,
+    };
+    delegate_account(accounts, token_escrow_seeds, DelegateConfig::default())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Delegated an Ethereum TokenEscrow intent");
+    msg!(" - authority: {} (slot: {})", authority, args.slot);
+    msg!(" - eth_address: {:02x?}", args.eth_address);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(" - amount: {}", token_escrow_data.amount);
+    msg!(" - nonce: {} (expiry: {})", args.nonce, args.expiry);
+
+    // Done
+    Ok(())
+}
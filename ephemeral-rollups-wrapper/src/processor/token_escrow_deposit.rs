@@ -6,14 +6,18 @@ use solana_program::msg;
 use solana_program::program::invoke;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
-use spl_token::instruction::transfer;
 
+use crate::state::config::ConfigPause;
 use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_vault_state::TokenVaultState;
 use crate::token_escrow_seeds_generator;
 use crate::token_vault_seeds_generator;
+use crate::token_vault_state_seeds_generator;
+use crate::util::ensure::ensure_is_allowed_token_program;
 use crate::util::ensure::ensure_is_owned_by_program;
 use crate::util::ensure::ensure_is_pda;
-use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::token::transfer;
 
 pub const DISCRIMINANT: [u8; 8] =
     [0xE0, 0x6C, 0xBE, 0x01, 0x34, 0xE4, 0x4B, 0xF2];
@@ -33,15 +37,19 @@ pub fn process(
     data: &[u8],
 ) -> ProgramResult {
     // Read instruction inputs
-    let [source_authority, source_token_account, token_escrow_pda, token_vault_pda, token_program_id] =
+    let [source_authority, source_token_account, token_escrow_pda, token_vault_pda, token_vault_state_pda, token_program_id, config_pda] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     let args = Args::try_from_slice(data)?;
 
-    // Verify the programs
-    ensure_is_program_id(token_program_id, &spl_token::ID)?;
+    // Verify that this family of IX is not currently paused
+    let config_data =
+        ensure_is_unpaused(config_pda, ConfigPause::Deposit, program_id)?;
+
+    // Verify the programs (only allowed token programs can be deposited)
+    ensure_is_allowed_token_program(token_program_id, &config_data)?;
 
     // Verify that the program has proper control of the escrow PDA (and that
     // it's been initialized)
@@ -49,6 +57,7 @@ pub fn process(
 
     // Verify that the vault has been initialized properly
     ensure_is_owned_by_program(token_vault_pda, token_program_id.key)?;
+    ensure_is_owned_by_program(token_vault_state_pda, program_id)?;
 
     // Verify the seeds of the escrow PDA
     let token_escrow_seeds = token_escrow_seeds_generator!(
@@ -64,6 +73,11 @@ pub fn process(
         token_vault_seeds_generator!(args.validator, args.token_mint);
     ensure_is_pda(token_vault_pda, token_vault_seeds, program_id)?;
 
+    // Verify the seeds of the vault state PDA
+    let token_vault_state_seeds =
+        token_vault_state_seeds_generator!(args.validator, args.token_mint);
+    ensure_is_pda(token_vault_state_pda, token_vault_state_seeds, program_id)?;
+
     // Proceed to transfer the token amount from source_token_account to vault
     invoke(
         &transfer(
@@ -71,9 +85,8 @@ pub fn process(
             source_token_account.key,
             token_vault_pda.key,
             source_authority.key,
-            &[],
             args.amount,
-        )?,
+        ),
         &[
             source_token_account.clone(),
             token_vault_pda.clone(),
@@ -93,6 +106,24 @@ pub fn process(
         &mut &mut token_escrow_pda.try_borrow_mut_data()?.as_mut(),
     )?;
 
+    // Update the vault accounting, keeping track of the escrowed supply
+    let mut token_vault_state_data =
+        TokenVaultState::try_from_slice(&token_vault_state_pda.data.borrow())?;
+    if token_vault_state_data.discriminant != TokenVaultState::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    token_vault_state_data.total_deposited = token_vault_state_data
+        .total_deposited
+        .checked_add(args.amount)
+        .unwrap();
+    token_vault_state_data.outstanding_amount = token_vault_state_data
+        .outstanding_amount
+        .checked_add(args.amount)
+        .unwrap();
+    token_vault_state_data.serialize(
+        &mut &mut token_vault_state_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
     // Log outcome
     msg!("Ephemeral Rollups Wrapper: Deposited to TokenEscrow");
     msg!(" - authority: {} (slot: {})", args.authority, args.slot);
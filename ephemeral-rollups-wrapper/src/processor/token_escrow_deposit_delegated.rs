@@ -0,0 +1,158 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_option::COption;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::config::ConfigPause;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_vault_state::TokenVaultState;
+use crate::token_escrow_seeds_generator;
+use crate::token_vault_seeds_generator;
+use crate::token_vault_state_seeds_generator;
+use crate::util::ensure::ensure_is_allowed_token_program;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::signer::signer_seeds;
+use crate::util::token::transfer;
+use crate::util::token::unpack_token_account;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xA4, 0xD9, 0x99, 0x94, 0xFE, 0x25, 0x3E, 0x47];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub authority: Pubkey,
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub slot: u64,
+    pub amount: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [source_token_account, token_escrow_pda, token_vault_pda, token_vault_state_pda, token_program_id, config_pda] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    let config_data =
+        ensure_is_unpaused(config_pda, ConfigPause::Deposit, program_id)?;
+
+    // Verify the programs (only allowed token programs can be deposited)
+    ensure_is_allowed_token_program(token_program_id, &config_data)?;
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized)
+    ensure_is_owned_by_program(token_escrow_pda, program_id)?;
+
+    // Verify that the vault has been initialized properly
+    ensure_is_owned_by_program(token_vault_pda, token_program_id.key)?;
+    ensure_is_owned_by_program(token_vault_state_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDA
+    let token_escrow_seeds = token_escrow_seeds_generator!(
+        args.authority,
+        args.validator,
+        args.token_mint,
+        args.slot
+    );
+    ensure_is_pda(token_escrow_pda, token_escrow_seeds, program_id)?;
+
+    // Verify the seeds of the vault PDA
+    let token_vault_seeds =
+        token_vault_seeds_generator!(args.validator, args.token_mint);
+    let token_vault_bump =
+        ensure_is_pda(token_vault_pda, token_vault_seeds, program_id)?;
+
+    // Verify the seeds of the vault state PDA
+    let token_vault_state_seeds =
+        token_vault_state_seeds_generator!(args.validator, args.token_mint);
+    ensure_is_pda(token_vault_state_pda, token_vault_state_seeds, program_id)?;
+
+    // Verify that the source belongs to the escrow's authority and that it
+    // approved the vault to pull from it (the crank can't redirect the funds)
+    ensure_is_owned_by_program(source_token_account, token_program_id.key)?;
+    let source_token_account_data =
+        unpack_token_account(&source_token_account.data.borrow())?;
+    if source_token_account_data.owner != args.authority {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if source_token_account_data.delegate != COption::Some(*token_vault_pda.key)
+    {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Proceed to pull the token amount from source_token_account to vault,
+    // using the vault's delegated allowance (fails above the approved amount)
+    invoke_signed(
+        &transfer(
+            token_program_id.key,
+            source_token_account.key,
+            token_vault_pda.key,
+            token_vault_pda.key,
+            args.amount,
+        ),
+        &[
+            source_token_account.clone(),
+            token_vault_pda.clone(),
+            token_vault_pda.clone(),
+        ],
+        &[&signer_seeds(token_vault_seeds, &[token_vault_bump])],
+    )?;
+
+    // Update the escrow amount (if the transfer succeeded)
+    let mut token_escrow_data =
+        TokenEscrow::try_from_slice(&token_escrow_pda.data.borrow())?;
+    if token_escrow_data.discriminant != TokenEscrow::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    token_escrow_data.amount =
+        token_escrow_data.amount.checked_add(args.amount).unwrap();
+    token_escrow_data.serialize(
+        &mut &mut token_escrow_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Update the vault accounting, keeping track of the escrowed supply
+    let mut token_vault_state_data =
+        TokenVaultState::try_from_slice(&token_vault_state_pda.data.borrow())?;
+    if token_vault_state_data.discriminant != TokenVaultState::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    token_vault_state_data.total_deposited = token_vault_state_data
+        .total_deposited
+        .checked_add(args.amount)
+        .unwrap();
+    token_vault_state_data.outstanding_amount = token_vault_state_data
+        .outstanding_amount
+        .checked_add(args.amount)
+        .unwrap();
+    token_vault_state_data.serialize(
+        &mut &mut token_vault_state_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Log outcome
+    msg!(
+        "Ephemeral Rollups Wrapper: Deposited to TokenEscrow from an approval"
+    );
+    msg!(" - authority: {} (slot: {})", args.authority, args.slot);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(" - source_token_account: {}", source_token_account.key);
+    msg!(" - amount: {} (total: {})", args.amount, token_escrow_data.amount);
+
+    // Done
+    Ok(())
+}
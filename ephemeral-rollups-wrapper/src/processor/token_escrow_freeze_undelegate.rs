@@ -0,0 +1,105 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::consts::MAGIC_PROGRAM_ID;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::program_option::COption;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::mint::load_freeze_authority;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x0F, 0xF6, 0xF8, 0x80, 0x54, 0x27, 0x76, 0xD5];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub authority: Pubkey,
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub slot: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, freeze_authority, token_mint, token_escrow_pda, magic_context_pda, magic_program_id] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify the programs
+    ensure_is_program_id(magic_program_id, &MAGIC_PROGRAM_ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the mint's freeze authority is the one initiating this IX
+    // (the escrow's authority and the config's pause flags can't prevent it)
+    ensure_is_signer(freeze_authority)?;
+    if token_mint.key != &args.token_mint {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if load_freeze_authority(token_mint)?
+        != COption::Some(*freeze_authority.key)
+    {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Verify that the program has proper control of the PDA (and that it's been
+    // initialized)
+    ensure_is_owned_by_program(token_escrow_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDA
+    let token_escrow_seeds = token_escrow_seeds_generator!(
+        args.authority,
+        args.validator,
+        args.token_mint,
+        args.slot
+    );
+    ensure_is_pda(token_escrow_pda, token_escrow_seeds, program_id)?;
+
+    // Freeze the escrow before it gets committed
+    let mut token_escrow_data =
+        TokenEscrow::try_from_slice(&token_escrow_pda.data.borrow())?;
+    if token_escrow_data.discriminant != TokenEscrow::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    token_escrow_data.frozen = true;
+    token_escrow_data.serialize(
+        &mut &mut token_escrow_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Request undelegation inside the ER, bringing the frozen escrow back on
+    // chain where the issuer can claw it back
+    commit_and_undelegate_accounts(
+        payer,
+        vec![token_escrow_pda],
+        magic_context_pda,
+        magic_program_id,
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Froze and requested undelegation of a TokenEscrow");
+    msg!(" - authority: {} (slot: {})", args.authority, args.slot);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(" - freeze_authority: {}", freeze_authority.key);
+    msg!(" - amount: {}", token_escrow_data.amount);
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::config::ConfigPause;
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::close::close_pda;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_undelegated;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::transfer::credit_token_escrow;
+use crate::util::transfer::debit_token_escrow;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xF4, 0x69, 0x00, 0x3C, 0xFB, 0xC5, 0xF6, 0xBA];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub source_slot: u64,
+    pub destination_slot: u64,
+    pub close_source: bool,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [authority, source_token_escrow_pda, destination_token_escrow_pda, spill, delegation_record_pda, config_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Transfer, program_id)?;
+
+    // Verify that the authority user is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(authority, signers, program_id)?;
+
+    // Merging an escrow into itself would close it with its balance
+    if args.source_slot == args.destination_slot {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized)
+    ensure_is_owned_by_program(source_token_escrow_pda, program_id)?;
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized)
+    ensure_is_owned_by_program(destination_token_escrow_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDA
+    let source_token_escrow_seeds = token_escrow_seeds_generator!(
+        authority.key,
+        args.validator,
+        args.token_mint,
+        args.source_slot
+    );
+    ensure_is_pda(
+        source_token_escrow_pda,
+        source_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Verify the seeds of the escrow PDA
+    let destination_token_escrow_seeds = token_escrow_seeds_generator!(
+        authority.key,
+        args.validator,
+        args.token_mint,
+        args.destination_slot
+    );
+    ensure_is_pda(
+        destination_token_escrow_pda,
+        destination_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Verify that the escrow is not delegated before closing it, closing is
+    // only possible on chain
+    if args.close_source {
+        ensure_is_undelegated(source_token_escrow_pda, delegation_record_pda)?;
+    }
+
+    // Empty the source escrow entirely
+    let amount =
+        TokenEscrow::try_from_slice(&source_token_escrow_pda.data.borrow())?
+            .amount;
+    debit_token_escrow(source_token_escrow_pda, amount)?;
+
+    // Update the destination escrow amount (if everything else suceeded)
+    let destination_amount =
+        credit_token_escrow(destination_token_escrow_pda, amount)?;
+
+    // Close the emptied escrow PDA
+    if args.close_source {
+        close_pda(source_token_escrow_pda, spill)?;
+    }
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Merged a TokenEscrow into another");
+    msg!(" - authority: {}", authority.key);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(
+        " - slot: {} -> {} (closed: {})",
+        args.source_slot,
+        args.destination_slot,
+        args.close_source
+    );
+    msg!(" - amount: {} (destination: {})", amount, destination_amount);
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,244 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::config::ConfigPause;
+use crate::state::token_vault_state::TokenVaultState;
+use crate::token_escrow_seeds_generator;
+use crate::token_vault_seeds_generator;
+use crate::token_vault_state_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_registered_validator;
+use crate::util::ensure::ensure_is_token_program;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::signer::signer_seeds;
+use crate::util::token::transfer;
+use crate::util::transfer::credit_token_escrow;
+use crate::util::transfer::debit_token_escrow;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xD8, 0xAA, 0x7A, 0x77, 0xD9, 0xF2, 0x14, 0xC0];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub source_validator: Pubkey,
+    pub destination_validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub source_slot: u64,
+    pub destination_slot: u64,
+    pub amount: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [authority, source_token_escrow_pda, source_token_vault_pda, source_token_vault_state_pda, destination_token_escrow_pda, destination_token_vault_pda, destination_token_vault_state_pda, token_program_id, config_pda, validator_registry_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that both families of IX involved are not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Withdraw, program_id)?;
+    ensure_is_unpaused(config_pda, ConfigPause::Deposit, program_id)?;
+
+    // Verify that the new validator is approved and still active
+    ensure_is_registered_validator(
+        validator_registry_pda,
+        &args.destination_validator,
+        program_id,
+    )?;
+
+    // Verify the programs
+    ensure_is_token_program(token_program_id)?;
+
+    // Verify that the authority user is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(authority, signers, program_id)?;
+
+    // Verify that the program has proper control of both escrow PDAs (they
+    // must be initialized and not currently delegated)
+    ensure_is_owned_by_program(source_token_escrow_pda, program_id)?;
+    ensure_is_owned_by_program(destination_token_escrow_pda, program_id)?;
+
+    // Verify that both vaults have been initialized properly
+    ensure_is_owned_by_program(source_token_vault_pda, token_program_id.key)?;
+    ensure_is_owned_by_program(source_token_vault_state_pda, program_id)?;
+    ensure_is_owned_by_program(
+        destination_token_vault_pda,
+        token_program_id.key,
+    )?;
+    ensure_is_owned_by_program(destination_token_vault_state_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDAs
+    let source_token_escrow_seeds = token_escrow_seeds_generator!(
+        authority.key,
+        args.source_validator,
+        args.token_mint,
+        args.source_slot
+    );
+    ensure_is_pda(
+        source_token_escrow_pda,
+        source_token_escrow_seeds,
+        program_id,
+    )?;
+    let destination_token_escrow_seeds = token_escrow_seeds_generator!(
+        authority.key,
+        args.destination_validator,
+        args.token_mint,
+        args.destination_slot
+    );
+    ensure_is_pda(
+        destination_token_escrow_pda,
+        destination_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Verify the seeds of the vault PDAs
+    let source_token_vault_seeds =
+        token_vault_seeds_generator!(args.source_validator, args.token_mint);
+    let source_token_vault_bump = ensure_is_pda(
+        source_token_vault_pda,
+        source_token_vault_seeds,
+        program_id,
+    )?;
+    let destination_token_vault_seeds = token_vault_seeds_generator!(
+        args.destination_validator,
+        args.token_mint
+    );
+    ensure_is_pda(
+        destination_token_vault_pda,
+        destination_token_vault_seeds,
+        program_id,
+    )?;
+
+    // Verify the seeds of the vault state PDAs
+    let source_token_vault_state_seeds = token_vault_state_seeds_generator!(
+        args.source_validator,
+        args.token_mint
+    );
+    ensure_is_pda(
+        source_token_vault_state_pda,
+        source_token_vault_state_seeds,
+        program_id,
+    )?;
+    let destination_token_vault_state_seeds = token_vault_state_seeds_generator!(
+        args.destination_validator,
+        args.token_mint
+    );
+    ensure_is_pda(
+        destination_token_vault_state_pda,
+        destination_token_vault_state_seeds,
+        program_id,
+    )?;
+
+    // Update the source escrow amount (fail if not enough amount available)
+    let source_amount =
+        debit_token_escrow(source_token_escrow_pda, args.amount)?;
+
+    // Update the source vault accounting, the tokens are leaving it
+    let mut source_token_vault_state_data = TokenVaultState::try_from_slice(
+        &source_token_vault_state_pda.data.borrow(),
+    )?;
+    if source_token_vault_state_data.discriminant
+        != TokenVaultState::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    source_token_vault_state_data.total_withdrawn =
+        source_token_vault_state_data
+            .total_withdrawn
+            .checked_add(args.amount)
+            .unwrap();
+    source_token_vault_state_data.outstanding_amount =
+        source_token_vault_state_data
+            .outstanding_amount
+            .checked_sub(args.amount)
+            .ok_or(ProgramError::InsufficientFunds)?;
+    source_token_vault_state_data.serialize(
+        &mut &mut source_token_vault_state_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Update the destination vault accounting, the tokens are entering it
+    let mut destination_token_vault_state_data =
+        TokenVaultState::try_from_slice(
+            &destination_token_vault_state_pda.data.borrow(),
+        )?;
+    if destination_token_vault_state_data.discriminant
+        != TokenVaultState::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    destination_token_vault_state_data.total_deposited =
+        destination_token_vault_state_data
+            .total_deposited
+            .checked_add(args.amount)
+            .unwrap();
+    destination_token_vault_state_data.outstanding_amount =
+        destination_token_vault_state_data
+            .outstanding_amount
+            .checked_add(args.amount)
+            .unwrap();
+    destination_token_vault_state_data.serialize(
+        &mut &mut destination_token_vault_state_pda
+            .try_borrow_mut_data()?
+            .as_mut(),
+    )?;
+
+    // Update the destination escrow amount
+    let destination_amount =
+        credit_token_escrow(destination_token_escrow_pda, args.amount)?;
+
+    // Proceed to move the tokens from one vault to the other (if everything
+    // else succeeded)
+    invoke_signed(
+        &transfer(
+            token_program_id.key,
+            source_token_vault_pda.key,
+            destination_token_vault_pda.key,
+            source_token_vault_pda.key,
+            args.amount,
+        ),
+        &[
+            source_token_vault_pda.clone(),
+            destination_token_vault_pda.clone(),
+            source_token_vault_pda.clone(),
+        ],
+        &[&signer_seeds(source_token_vault_seeds, &[source_token_vault_bump])],
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Migrated TokenEscrow between validators");
+    msg!(" - authority: {}", authority.key);
+    msg!(
+        " - source_validator: {} (slot: {})",
+        args.source_validator,
+        args.source_slot
+    );
+    msg!(
+        " - destination_validator: {} (slot: {})",
+        args.destination_validator,
+        args.destination_slot
+    );
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(
+        " - amount: {} (source: {}, destination: {})",
+        args.amount,
+        source_amount,
+        destination_amount
+    );
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::realloc::realloc_pda;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x87, 0x4F, 0x1C, 0xE2, 0x88, 0x17, 0x27, 0xCF];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub authority: Pubkey,
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub slot: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, token_escrow_pda, system_program_id] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify the programs
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized, delegated escrows must be undelegated first)
+    ensure_is_owned_by_program(token_escrow_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDA
+    let token_escrow_seeds = token_escrow_seeds_generator!(
+        args.authority,
+        args.validator,
+        args.token_mint,
+        args.slot
+    );
+    ensure_is_pda(token_escrow_pda, token_escrow_seeds, program_id)?;
+
+    // Verify that the escrow was created with an older, shorter layout
+    let previous_data_len = token_escrow_pda.data_len();
+    if previous_data_len >= TokenEscrow::space() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let discriminant = token_escrow_pda
+        .data
+        .borrow()
+        .get(..8)
+        .ok_or(ProgramError::InvalidAccountData)?
+        .to_vec();
+    if discriminant != TokenEscrow::discriminant().to_le_bytes() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Grow the escrow to the current layout, the fields added since then are
+    // zeroed (no spending limit and not frozen)
+    realloc_pda(
+        payer,
+        token_escrow_pda,
+        TokenEscrow::space(),
+        system_program_id,
+    )?;
+
+    // Verify that the escrow can now be read with the current layout
+    let token_escrow_data =
+        TokenEscrow::try_from_slice(&token_escrow_pda.data.borrow())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Migrated a TokenEscrow to its current layout");
+    msg!(" - authority: {} (slot: {})", args.authority, args.slot);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(" - space: {} -> {}", previous_data_len, TokenEscrow::space());
+    msg!(" - amount: {}", token_escrow_data.amount);
+
+    // Done
+    Ok(())
+}
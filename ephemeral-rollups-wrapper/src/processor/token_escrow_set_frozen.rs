@@ -0,0 +1,86 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::program_option::COption;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::mint::load_freeze_authority;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x5C, 0xBC, 0xC7, 0x56, 0x4D, 0xD5, 0x96, 0x20];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub authority: Pubkey,
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub slot: u64,
+    pub frozen: bool,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [freeze_authority, token_mint, token_escrow_pda] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that the mint's freeze authority is the one initiating this IX
+    ensure_is_signer(freeze_authority)?;
+    if token_mint.key != &args.token_mint {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if load_freeze_authority(token_mint)?
+        != COption::Some(*freeze_authority.key)
+    {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized)
+    ensure_is_owned_by_program(token_escrow_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDA
+    let token_escrow_seeds = token_escrow_seeds_generator!(
+        args.authority,
+        args.validator,
+        args.token_mint,
+        args.slot
+    );
+    ensure_is_pda(token_escrow_pda, token_escrow_seeds, program_id)?;
+
+    // Update the escrow frozen flag
+    let mut token_escrow_data =
+        TokenEscrow::try_from_slice(&token_escrow_pda.data.borrow())?;
+    if token_escrow_data.discriminant != TokenEscrow::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    token_escrow_data.frozen = args.frozen;
+    token_escrow_data.serialize(
+        &mut &mut token_escrow_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Set a TokenEscrow frozen flag");
+    msg!(" - authority: {} (slot: {})", args.authority, args.slot);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(" - freeze_authority: {}", freeze_authority.key);
+    msg!(" - frozen: {}", args.frozen);
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_undelegated;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x15, 0x87, 0xB6, 0xA4, 0x14, 0x8C, 0xB1, 0x2C];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub slot: u64,
+    pub max_per_transaction: u64,
+    pub max_per_window: u64,
+    pub window_seconds: i64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [authority, token_escrow_pda, delegation_record_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that the authority user is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(authority, signers, program_id)?;
+
+    // Verify that a window limit comes with a window to measure it against
+    if args.max_per_window != 0 && args.window_seconds <= 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized)
+    ensure_is_owned_by_program(token_escrow_pda, program_id)?;
+
+    // Verify that the escrow is not delegated, the limits can only be changed
+    // on chain
+    ensure_is_undelegated(token_escrow_pda, delegation_record_pda)?;
+
+    // Verify the seeds of the escrow PDA
+    let token_escrow_seeds = token_escrow_seeds_generator!(
+        authority.key,
+        args.validator,
+        args.token_mint,
+        args.slot
+    );
+    ensure_is_pda(token_escrow_pda, token_escrow_seeds, program_id)?;
+
+    // Update the limits, the current window's spending is kept so that
+    // changing the limits can't be used to reset it
+    let mut token_escrow_data =
+        TokenEscrow::try_from_slice(&token_escrow_pda.data.borrow())?;
+    if token_escrow_data.discriminant != TokenEscrow::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    token_escrow_data.spending_limit.max_per_transaction =
+        args.max_per_transaction;
+    token_escrow_data.spending_limit.max_per_window = args.max_per_window;
+    token_escrow_data.spending_limit.window_seconds = args.window_seconds;
+    token_escrow_data.serialize(
+        &mut &mut token_escrow_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Set a TokenEscrow spending limit");
+    msg!(" - authority: {} (slot: {})", authority.key, args.slot);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(" - max_per_transaction: {}", args.max_per_transaction);
+    msg!(
+        " - max_per_window: {} (window_seconds: {})",
+        args.max_per_window,
+        args.window_seconds
+    );
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::state::config::ConfigPause;
+use crate::state::spending_limit::SpendingLimit;
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::create::create_pda;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_registered_validator;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::transfer::credit_token_escrow;
+use crate::util::transfer::debit_token_escrow;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x4C, 0x2C, 0x03, 0xD6, 0x4B, 0x35, 0x9A, 0xFA];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub source_slot: u64,
+    pub destination_slot: u64,
+    pub amount: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, authority, source_token_escrow_pda, destination_token_escrow_pda, system_program_id, config_pda, validator_registry_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Transfer, program_id)?;
+
+    // Verify the programs
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the authority user is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(authority, signers, program_id)?;
+
+    // Splitting an escrow into itself is meaningless
+    if args.source_slot == args.destination_slot {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized)
+    ensure_is_owned_by_program(source_token_escrow_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDA
+    let source_token_escrow_seeds = token_escrow_seeds_generator!(
+        authority.key,
+        args.validator,
+        args.token_mint,
+        args.source_slot
+    );
+    ensure_is_pda(
+        source_token_escrow_pda,
+        source_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Verify the seeds of the escrow PDA
+    let destination_token_escrow_seeds = token_escrow_seeds_generator!(
+        authority.key,
+        args.validator,
+        args.token_mint,
+        args.destination_slot
+    );
+    let destination_token_escrow_bump = ensure_is_pda(
+        destination_token_escrow_pda,
+        destination_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Create the destination escrow if it doesn't exist yet (only possible
+    // on-chain, in the ER the destination must already be delegated)
+    if destination_token_escrow_pda.owner == &system_program::ID {
+        // Verify that escrows can currently be created for this validator
+        ensure_is_unpaused(config_pda, ConfigPause::Create, program_id)?;
+        ensure_is_registered_validator(
+            validator_registry_pda,
+            &args.validator,
+            program_id,
+        )?;
+
+        // Verify that the payer is allowed to pay for the rent fees
+        ensure_is_signer(payer)?;
+
+        // Initialize the escrow PDA
+        create_pda(
+            payer,
+            destination_token_escrow_pda,
+            destination_token_escrow_seeds,
+            destination_token_escrow_bump,
+            TokenEscrow::space(),
+            program_id,
+            system_program_id,
+        )?;
+
+        // Initialize the escrow data
+        let destination_token_escrow_data = TokenEscrow {
+            discriminant: TokenEscrow::discriminant(),
+            amount: 0,
+            spending_limit: SpendingLimit::unlimited(),
+            frozen: false,
+        };
+        destination_token_escrow_data.serialize(
+            &mut &mut destination_token_escrow_pda
+                .try_borrow_mut_data()?
+                .as_mut(),
+        )?;
+    }
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized)
+    ensure_is_owned_by_program(destination_token_escrow_pda, program_id)?;
+
+    // Update the source escrow amount (fail if not enough amount available)
+    let source_amount =
+        debit_token_escrow(source_token_escrow_pda, args.amount)?;
+
+    // Update the destination escrow amount (if everything else suceeded)
+    let destination_amount =
+        credit_token_escrow(destination_token_escrow_pda, args.amount)?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Split a TokenEscrow into another");
+    msg!(" - authority: {}", authority.key);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(" - slot: {} -> {}", args.source_slot, args.destination_slot);
+    msg!(
+        " - amount: {} (source: {}, destination: {})",
+        args.amount,
+        source_amount,
+        destination_amount
+    );
+
+    // Done
+    Ok(())
+}
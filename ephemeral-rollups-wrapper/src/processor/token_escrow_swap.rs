@@ -0,0 +1,251 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::config::ConfigPause;
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_unfrozen;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::limit::consume_spending_limit;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xFE, 0x53, 0xCD, 0x69, 0xE0, 0x65, 0xE7, 0x56];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub authority_a_source_slot: u64,
+    pub authority_a_destination_slot: u64,
+    pub authority_b_source_slot: u64,
+    pub authority_b_destination_slot: u64,
+    pub amount_a: u64,
+    pub amount_b: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [authority_a, authority_b, authority_a_source_token_escrow_pda, authority_b_destination_token_escrow_pda, authority_b_source_token_escrow_pda, authority_a_destination_token_escrow_pda, config_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Transfer, program_id)?;
+
+    // A swap only makes sense between two different mints
+    if args.token_mint_a == args.token_mint_b {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Verify that both parties of the swap agreed to it
+    ensure_is_authority(authority_a, signers, program_id)?;
+    ensure_is_authority(authority_b, signers, program_id)?;
+
+    // Verify that the program has proper control of the escrow PDAs (and that
+    // they've been initialized)
+    ensure_is_owned_by_program(
+        authority_a_source_token_escrow_pda,
+        program_id,
+    )?;
+    ensure_is_owned_by_program(
+        authority_b_destination_token_escrow_pda,
+        program_id,
+    )?;
+    ensure_is_owned_by_program(
+        authority_b_source_token_escrow_pda,
+        program_id,
+    )?;
+    ensure_is_owned_by_program(
+        authority_a_destination_token_escrow_pda,
+        program_id,
+    )?;
+
+    // Verify the seeds of the escrow PDA sending token_mint_a
+    let authority_a_source_token_escrow_seeds = token_escrow_seeds_generator!(
+        authority_a.key,
+        args.validator,
+        args.token_mint_a,
+        args.authority_a_source_slot
+    );
+    ensure_is_pda(
+        authority_a_source_token_escrow_pda,
+        authority_a_source_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Verify the seeds of the escrow PDA receiving token_mint_a
+    let authority_b_destination_token_escrow_seeds = token_escrow_seeds_generator!(
+        authority_b.key,
+        args.validator,
+        args.token_mint_a,
+        args.authority_b_destination_slot
+    );
+    ensure_is_pda(
+        authority_b_destination_token_escrow_pda,
+        authority_b_destination_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Verify the seeds of the escrow PDA sending token_mint_b
+    let authority_b_source_token_escrow_seeds = token_escrow_seeds_generator!(
+        authority_b.key,
+        args.validator,
+        args.token_mint_b,
+        args.authority_b_source_slot
+    );
+    ensure_is_pda(
+        authority_b_source_token_escrow_pda,
+        authority_b_source_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Verify the seeds of the escrow PDA receiving token_mint_b
+    let authority_a_destination_token_escrow_seeds = token_escrow_seeds_generator!(
+        authority_a.key,
+        args.validator,
+        args.token_mint_b,
+        args.authority_a_destination_slot
+    );
+    ensure_is_pda(
+        authority_a_destination_token_escrow_pda,
+        authority_a_destination_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Update the token_mint_a source escrow amount (panic if not enough amount
+    // available)
+    let mut authority_a_source_token_escrow_data = TokenEscrow::try_from_slice(
+        &authority_a_source_token_escrow_pda.data.borrow(),
+    )?;
+    if authority_a_source_token_escrow_data.discriminant
+        != TokenEscrow::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    // Verify that the issuer didn't freeze the escrow
+    ensure_is_unfrozen(&authority_a_source_token_escrow_data)?;
+    // Verify that the escrow's spending limit allows this amount to leave
+    consume_spending_limit(
+        &mut authority_a_source_token_escrow_data.spending_limit,
+        args.amount_a,
+    )?;
+    authority_a_source_token_escrow_data.amount =
+        authority_a_source_token_escrow_data
+            .amount
+            .checked_sub(args.amount_a)
+            .unwrap();
+    authority_a_source_token_escrow_data.serialize(
+        &mut &mut authority_a_source_token_escrow_pda
+            .try_borrow_mut_data()?
+            .as_mut(),
+    )?;
+
+    // Update the token_mint_b source escrow amount (panic if not enough amount
+    // available)
+    let mut authority_b_source_token_escrow_data = TokenEscrow::try_from_slice(
+        &authority_b_source_token_escrow_pda.data.borrow(),
+    )?;
+    if authority_b_source_token_escrow_data.discriminant
+        != TokenEscrow::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    // Verify that the issuer didn't freeze the escrow
+    ensure_is_unfrozen(&authority_b_source_token_escrow_data)?;
+    // Verify that the escrow's spending limit allows this amount to leave
+    consume_spending_limit(
+        &mut authority_b_source_token_escrow_data.spending_limit,
+        args.amount_b,
+    )?;
+    authority_b_source_token_escrow_data.amount =
+        authority_b_source_token_escrow_data
+            .amount
+            .checked_sub(args.amount_b)
+            .unwrap();
+    authority_b_source_token_escrow_data.serialize(
+        &mut &mut authority_b_source_token_escrow_pda
+            .try_borrow_mut_data()?
+            .as_mut(),
+    )?;
+
+    // Update the token_mint_a destination escrow amount (if everything else
+    // suceeded)
+    let mut authority_b_destination_token_escrow_data =
+        TokenEscrow::try_from_slice(
+            &authority_b_destination_token_escrow_pda.data.borrow(),
+        )?;
+    if authority_b_destination_token_escrow_data.discriminant
+        != TokenEscrow::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    authority_b_destination_token_escrow_data.amount =
+        authority_b_destination_token_escrow_data
+            .amount
+            .checked_add(args.amount_a)
+            .unwrap();
+    authority_b_destination_token_escrow_data.serialize(
+        &mut &mut authority_b_destination_token_escrow_pda
+            .try_borrow_mut_data()?
+            .as_mut(),
+    )?;
+
+    // Update the token_mint_b destination escrow amount (if everything else
+    // suceeded)
+    let mut authority_a_destination_token_escrow_data =
+        TokenEscrow::try_from_slice(
+            &authority_a_destination_token_escrow_pda.data.borrow(),
+        )?;
+    if authority_a_destination_token_escrow_data.discriminant
+        != TokenEscrow::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    authority_a_destination_token_escrow_data.amount =
+        authority_a_destination_token_escrow_data
+            .amount
+            .checked_add(args.amount_b)
+            .unwrap();
+    authority_a_destination_token_escrow_data.serialize(
+        &mut &mut authority_a_destination_token_escrow_pda
+            .try_borrow_mut_data()?
+            .as_mut(),
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Swapped between TokenEscrows");
+    msg!(" - validator: {}", args.validator);
+    msg!(
+        " - authority_a: {} (source slot: {}, destination slot: {})",
+        authority_a.key,
+        args.authority_a_source_slot,
+        args.authority_a_destination_slot
+    );
+    msg!(
+        " - authority_b: {} (source slot: {}, destination slot: {})",
+        authority_b.key,
+        args.authority_b_source_slot,
+        args.authority_b_destination_slot
+    );
+    msg!(" - token_mint_a: {} (amount: {})", args.token_mint_a, args.amount_a);
+    msg!(" - token_mint_b: {} (amount: {})", args.token_mint_b, args.amount_b);
+
+    // Done
+    Ok(())
+}
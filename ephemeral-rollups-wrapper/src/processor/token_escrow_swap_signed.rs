@@ -0,0 +1,216 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::config::ConfigPause;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::nonce::consume_nonce;
+use crate::util::signature::ensure_is_ed25519_signed;
+use crate::util::transfer::transfer_token_escrow_amount;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xB1, 0x68, 0xDB, 0x39, 0xBA, 0x19, 0xE8, 0x28];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub authority_a: Pubkey,
+    pub validator: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub authority_a_source_slot: u64,
+    pub authority_a_destination_slot: u64,
+    pub authority_b_source_slot: u64,
+    pub authority_b_destination_slot: u64,
+    pub amount_a: u64,
+    pub amount_b: u64,
+    pub nonce: u64,
+    pub expiry: i64,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Offer {
+    pub authority_a_source_token_escrow: Pubkey,
+    pub authority_a_destination_token_escrow: Pubkey,
+    pub authority_b: Pubkey,
+    pub amount_a: u64,
+    pub amount_b: u64,
+    pub nonce: u64,
+    pub expiry: i64,
+}
+
+// The bytes the offering authority signs off-chain, prefixed with the IX
+// discriminant so that they can't be mistaken for another kind of intent
+pub fn message(offer: &Offer) -> Vec<u8> {
+    let mut message = Vec::new();
+    message.extend_from_slice(&DISCRIMINANT);
+    offer.serialize(&mut message).unwrap();
+    message
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [authority_b, authority_a_source_token_escrow_pda, authority_b_destination_token_escrow_pda, authority_b_source_token_escrow_pda, authority_a_destination_token_escrow_pda, nonce_account_pda, instructions_sysvar, config_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Transfer, program_id)?;
+
+    // A swap only makes sense between two different mints
+    if args.token_mint_a == args.token_mint_b {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Verify that the offering authority signed this exact offer off-chain
+    ensure_is_ed25519_signed(
+        instructions_sysvar,
+        &args.authority_a,
+        &message(&Offer {
+            authority_a_source_token_escrow:
+                *authority_a_source_token_escrow_pda.key,
+            authority_a_destination_token_escrow:
+                *authority_a_destination_token_escrow_pda.key,
+            authority_b: *authority_b.key,
+            amount_a: args.amount_a,
+            amount_b: args.amount_b,
+            nonce: args.nonce,
+            expiry: args.expiry,
+        }),
+    )?;
+
+    // Verify that the offer is not stale and was never taken before
+    consume_nonce(
+        nonce_account_pda,
+        &args.authority_a,
+        args.nonce,
+        args.expiry,
+        program_id,
+    )?;
+
+    // Verify that the counterparty of the offer is the one taking it
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(authority_b, signers, program_id)?;
+
+    // Verify that the program has proper control of the escrow PDAs (and that
+    // they've been initialized)
+    ensure_is_owned_by_program(
+        authority_a_source_token_escrow_pda,
+        program_id,
+    )?;
+    ensure_is_owned_by_program(
+        authority_b_destination_token_escrow_pda,
+        program_id,
+    )?;
+    ensure_is_owned_by_program(
+        authority_b_source_token_escrow_pda,
+        program_id,
+    )?;
+    ensure_is_owned_by_program(
+        authority_a_destination_token_escrow_pda,
+        program_id,
+    )?;
+
+    // Verify the seeds of the escrow PDA sending token_mint_a
+    ensure_is_pda(
+        authority_a_source_token_escrow_pda,
+        token_escrow_seeds_generator!(
+            args.authority_a,
+            args.validator,
+            args.token_mint_a,
+            args.authority_a_source_slot
+        ),
+        program_id,
+    )?;
+
+    // Verify the seeds of the escrow PDA receiving token_mint_a
+    ensure_is_pda(
+        authority_b_destination_token_escrow_pda,
+        token_escrow_seeds_generator!(
+            authority_b.key,
+            args.validator,
+            args.token_mint_a,
+            args.authority_b_destination_slot
+        ),
+        program_id,
+    )?;
+
+    // Verify the seeds of the escrow PDA sending token_mint_b
+    ensure_is_pda(
+        authority_b_source_token_escrow_pda,
+        token_escrow_seeds_generator!(
+            authority_b.key,
+            args.validator,
+            args.token_mint_b,
+            args.authority_b_source_slot
+        ),
+        program_id,
+    )?;
+
+    // Verify the seeds of the escrow PDA receiving token_mint_b
+    ensure_is_pda(
+        authority_a_destination_token_escrow_pda,
+        token_escrow_seeds_generator!(
+            args.authority_a,
+            args.validator,
+            args.token_mint_b,
+            args.authority_a_destination_slot
+        ),
+        program_id,
+    )?;
+
+    // Move both legs of the swap, failing entirely if either one can't be
+    // paid (swaps don't carry any fee)
+    transfer_token_escrow_amount(
+        authority_a_source_token_escrow_pda,
+        authority_b_destination_token_escrow_pda,
+        authority_b_destination_token_escrow_pda,
+        None,
+        args.amount_a,
+        0,
+    )?;
+    transfer_token_escrow_amount(
+        authority_b_source_token_escrow_pda,
+        authority_a_destination_token_escrow_pda,
+        authority_a_destination_token_escrow_pda,
+        None,
+        args.amount_b,
+        0,
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Swapped a signed TokenEscrow offer");
+    msg!(" - validator: {}", args.validator);
+    msg!(
+        " - authority_a: {} (source slot: {}, destination slot: {})",
+        args.authority_a,
+        args.authority_a_source_slot,
+        args.authority_a_destination_slot
+    );
+    msg!(
+        " - authority_b: {} (source slot: {}, destination slot: {})",
+        authority_b.key,
+        args.authority_b_source_slot,
+        args.authority_b_destination_slot
+    );
+    msg!(" - token_mint_a: {} (amount: {})", args.token_mint_a, args.amount_a);
+    msg!(" - token_mint_b: {} (amount: {})", args.token_mint_b, args.amount_b);
+    msg!(" - nonce: {} (expiry: {})", args.nonce, args.expiry);
+
+    // Done
+    Ok(())
+}
@@ -6,11 +6,14 @@ use solana_program::msg;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
-use crate::state::token_escrow::TokenEscrow;
+use crate::state::config::ConfigPause;
 use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
 use crate::util::ensure::ensure_is_owned_by_program;
 use crate::util::ensure::ensure_is_pda;
-use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::fee::load_token_fee;
+use crate::util::transfer::transfer_token_escrow_amount;
 
 pub const DISCRIMINANT: [u8; 8] =
     [0x01, 0x1D, 0xE7, 0xCB, 0x37, 0x6E, 0x04, 0x70];
@@ -31,13 +34,16 @@ pub fn process(
     data: &[u8],
 ) -> ProgramResult {
     // Read instruction inputs
-    let [source_authority, source_token_escrow_pda, destination_token_escrow_pda, ..] =
+    let [source_authority, source_token_escrow_pda, destination_token_escrow_pda, token_fee_pda, fee_collector_token_escrow_pda, config_pda, signers @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     let args = Args::try_from_slice(data)?;
 
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Transfer, program_id)?;
+
     // check if the destination_authority is the user_platform_authority
     let user_platform_authority =
         get_user_platform_authority(*source_authority.key, args.validator);
@@ -45,7 +51,8 @@ pub fn process(
     // dont check signer if the destination authority is the user_platform_authority
     if !(user_platform_authority == args.destination_authority && args.destination_slot == 0) {
         // Verify that the authority user is indeed the one initiating this IX
-        ensure_is_signer(source_authority)?;
+        // (or that enough of its multisig signers are)
+        ensure_is_authority(source_authority, signers, program_id)?;
     }
 
     // Verify that the program has proper control of the escrow PDA (and that
@@ -82,30 +89,30 @@ pub fn process(
         program_id,
     )?;
 
-    // Update the source escrow amount (panic if not enough amount available)
-    let mut source_token_escrow_data =
-        TokenEscrow::try_from_slice(&source_token_escrow_pda.data.borrow())?;
-    if source_token_escrow_data.discriminant != TokenEscrow::discriminant() {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    source_token_escrow_data.amount =
-        source_token_escrow_data.amount.checked_sub(args.amount).unwrap();
-    source_token_escrow_data.serialize(
-        &mut &mut source_token_escrow_pda.try_borrow_mut_data()?.as_mut(),
-    )?;
+    // Compute the fee owed to the collector (no fee if no schedule was set)
+    let fee = match load_token_fee(
+        token_fee_pda,
+        fee_collector_token_escrow_pda,
+        &args.validator,
+        &args.token_mint,
+        program_id,
+    )? {
+        Some(token_fee_data) => {
+            token_fee_data
+                .compute_fee(args.amount)
+                .ok_or(ProgramError::InsufficientFunds)?
+        },
+        None => 0,
+    };
 
-    // Update the destination escrow amount (if everything else suceeded)
-    let mut destination_token_escrow_data = TokenEscrow::try_from_slice(
-        &destination_token_escrow_pda.data.borrow(),
-    )?;
-    if destination_token_escrow_data.discriminant != TokenEscrow::discriminant()
-    {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    destination_token_escrow_data.amount =
-        destination_token_escrow_data.amount.checked_add(args.amount).unwrap();
-    destination_token_escrow_data.serialize(
-        &mut &mut destination_token_escrow_pda.try_borrow_mut_data()?.as_mut(),
+    // Move the amount, the fee being split off to the collector
+    let (source_amount, destination_amount) = transfer_token_escrow_amount(
+        source_token_escrow_pda,
+        destination_token_escrow_pda,
+        fee_collector_token_escrow_pda,
+        None,
+        args.amount,
+        fee,
     )?;
 
     // Log outcome
@@ -125,9 +132,10 @@ pub fn process(
     msg!(
         " - amount: {} (source: {}, destination: {})",
         args.amount,
-        source_token_escrow_data.amount,
-        destination_token_escrow_data.amount
+        source_amount,
+        destination_amount
     );
+    msg!(" - fee: {}", fee);
 
     // Done
     Ok(())
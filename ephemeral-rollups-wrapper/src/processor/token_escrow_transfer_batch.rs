@@ -0,0 +1,167 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::config::ConfigPause;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::fee::collect_token_fee;
+use crate::util::fee::load_token_fee;
+use crate::util::transfer::credit_token_escrow;
+use crate::util::transfer::debit_token_escrow;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xC4, 0x82, 0xA2, 0xF8, 0x3A, 0x1C, 0xFB, 0x6E];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub source_slot: u64,
+    pub transfers: Vec<ArgsTransfer>,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct ArgsTransfer {
+    pub destination_authority: Pubkey,
+    pub destination_slot: u64,
+    pub amount: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [source_authority, source_token_escrow_pda, token_fee_pda, fee_collector_token_escrow_pda, config_pda, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // The destination escrows come first, followed by the multisig signers
+    if remaining.len() < args.transfers.len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (destination_token_escrow_pdas, signers) =
+        remaining.split_at(args.transfers.len());
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Transfer, program_id)?;
+
+    // Verify that the authority user is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(source_authority, signers, program_id)?;
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized)
+    ensure_is_owned_by_program(source_token_escrow_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDA
+    let source_token_escrow_seeds = token_escrow_seeds_generator!(
+        source_authority.key,
+        args.validator,
+        args.token_mint,
+        args.source_slot
+    );
+    ensure_is_pda(
+        source_token_escrow_pda,
+        source_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Verify all the destination escrows and compute the total being debited
+    let token_fee_data = load_token_fee(
+        token_fee_pda,
+        fee_collector_token_escrow_pda,
+        &args.validator,
+        &args.token_mint,
+        program_id,
+    )?;
+    let mut fees = Vec::with_capacity(args.transfers.len());
+    let mut total_amount = 0u64;
+    let mut total_fee = 0u64;
+    for (transfer, destination_token_escrow_pda) in
+        args.transfers.iter().zip(destination_token_escrow_pdas)
+    {
+        ensure_is_owned_by_program(destination_token_escrow_pda, program_id)?;
+        let destination_token_escrow_seeds = token_escrow_seeds_generator!(
+            transfer.destination_authority,
+            args.validator,
+            args.token_mint,
+            transfer.destination_slot
+        );
+        ensure_is_pda(
+            destination_token_escrow_pda,
+            destination_token_escrow_seeds,
+            program_id,
+        )?;
+        let fee = match &token_fee_data {
+            Some(token_fee_data) => {
+                token_fee_data
+                    .compute_fee(transfer.amount)
+                    .ok_or(ProgramError::InsufficientFunds)?
+            },
+            None => 0,
+        };
+        total_amount = total_amount
+            .checked_add(transfer.amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        total_fee = total_fee.checked_add(fee).unwrap();
+        fees.push(fee);
+    }
+
+    // Update the source escrow amount once (fail if the total is not available)
+    let source_amount =
+        debit_token_escrow(source_token_escrow_pda, total_amount)?;
+
+    // Update every destination escrow amount (if everything else suceeded)
+    for ((transfer, fee), destination_token_escrow_pda) in
+        args.transfers.iter().zip(&fees).zip(destination_token_escrow_pdas)
+    {
+        credit_token_escrow(
+            destination_token_escrow_pda,
+            transfer.amount - fee,
+        )?;
+    }
+
+    // Update the collector escrow amount with the fees that were split off
+    collect_token_fee(fee_collector_token_escrow_pda, total_fee)?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Transfered in batch from TokenEscrow");
+    msg!(
+        " - source_authority: {} (slot: {})",
+        source_authority.key,
+        args.source_slot
+    );
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(
+        " - amount: {} (source: {}, transfers: {})",
+        total_amount,
+        source_amount,
+        args.transfers.len()
+    );
+    msg!(" - fee: {}", total_fee);
+    for transfer in &args.transfers {
+        msg!(
+            " - destination_authority: {} (slot: {}, amount: {})",
+            transfer.destination_authority,
+            transfer.destination_slot,
+            transfer.amount
+        );
+    }
+
+    // Done
+    Ok(())
+}
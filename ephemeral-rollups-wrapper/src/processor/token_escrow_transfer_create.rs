@@ -0,0 +1,183 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::state::config::ConfigPause;
+use crate::state::spending_limit::SpendingLimit;
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::create::create_pda;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_registered_validator;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::fee::load_token_fee;
+use crate::util::transfer::transfer_token_escrow_amount;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xCA, 0xD8, 0xE4, 0xB8, 0xDE, 0x20, 0x8A, 0x72];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub destination_authority: Pubkey,
+    pub source_slot: u64,
+    pub destination_slot: u64,
+    pub amount: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, source_authority, source_token_escrow_pda, destination_token_escrow_pda, token_fee_pda, fee_collector_token_escrow_pda, system_program_id, config_pda, validator_registry_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Transfer, program_id)?;
+
+    // Verify the programs
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the authority user is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(source_authority, signers, program_id)?;
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized)
+    ensure_is_owned_by_program(source_token_escrow_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDA
+    let source_token_escrow_seeds = token_escrow_seeds_generator!(
+        source_authority.key,
+        args.validator,
+        args.token_mint,
+        args.source_slot
+    );
+    ensure_is_pda(
+        source_token_escrow_pda,
+        source_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Verify the seeds of the escrow PDA
+    let destination_token_escrow_seeds = token_escrow_seeds_generator!(
+        args.destination_authority,
+        args.validator,
+        args.token_mint,
+        args.destination_slot
+    );
+    let destination_token_escrow_bump = ensure_is_pda(
+        destination_token_escrow_pda,
+        destination_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Create the destination escrow if the recipient never created it
+    if destination_token_escrow_pda.owner == &system_program::ID {
+        // Verify that escrows can currently be created for this validator
+        ensure_is_unpaused(config_pda, ConfigPause::Create, program_id)?;
+        ensure_is_registered_validator(
+            validator_registry_pda,
+            &args.validator,
+            program_id,
+        )?;
+
+        // Initialize the escrow PDA
+        create_pda(
+            payer,
+            destination_token_escrow_pda,
+            destination_token_escrow_seeds,
+            destination_token_escrow_bump,
+            TokenEscrow::space(),
+            program_id,
+            system_program_id,
+        )?;
+
+        // Initialize the escrow data
+        let destination_token_escrow_data = TokenEscrow {
+            discriminant: TokenEscrow::discriminant(),
+            amount: 0,
+            spending_limit: SpendingLimit::unlimited(),
+            frozen: false,
+        };
+        destination_token_escrow_data.serialize(
+            &mut &mut destination_token_escrow_pda
+                .try_borrow_mut_data()?
+                .as_mut(),
+        )?;
+    }
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized)
+    ensure_is_owned_by_program(destination_token_escrow_pda, program_id)?;
+
+    // Compute the fee owed to the collector (no fee if no schedule was set)
+    let fee = match load_token_fee(
+        token_fee_pda,
+        fee_collector_token_escrow_pda,
+        &args.validator,
+        &args.token_mint,
+        program_id,
+    )? {
+        Some(token_fee_data) => {
+            token_fee_data
+                .compute_fee(args.amount)
+                .ok_or(ProgramError::InsufficientFunds)?
+        },
+        None => 0,
+    };
+
+    // Move the amount, the fee being split off to the collector
+    let (source_amount, destination_amount) = transfer_token_escrow_amount(
+        source_token_escrow_pda,
+        destination_token_escrow_pda,
+        fee_collector_token_escrow_pda,
+        None,
+        args.amount,
+        fee,
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Transfered into a created TokenEscrow");
+    msg!(
+        " - source_authority: {} (slot: {})",
+        source_authority.key,
+        args.source_slot
+    );
+    msg!(
+        " - destination_authority: {} (slot: {})",
+        args.destination_authority,
+        args.destination_slot
+    );
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(
+        " - amount: {} (source: {}, destination: {})",
+        args.amount,
+        source_amount,
+        destination_amount
+    );
+    msg!(" - fee: {}", fee);
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,204 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::token_escrow_transfer_signed::Intent;
+use crate::state::config::ConfigPause;
+use crate::state::eth_authority::eth_authority_generate_pda;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::fee::load_relayer_fee;
+use crate::util::fee::load_token_fee;
+use crate::util::nonce::consume_nonce;
+use crate::util::signature::eip191_message;
+use crate::util::signature::ensure_is_secp256k1_signed;
+use crate::util::transfer::transfer_token_escrow_amount;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xD8, 0x16, 0xD1, 0x7A, 0x44, 0xFF, 0x50, 0x90];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub source_eth_address: [u8; 20],
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub destination_authority: Pubkey,
+    pub source_slot: u64,
+    pub destination_slot: u64,
+    pub amount: u64,
+    pub nonce: u64,
+    pub expiry: i64,
+    pub relayer_authority: Pubkey,
+    pub relayer_slot: u64,
+    pub relayer_fee: u64,
+    pub max_relayer_fee: u64,
+}
+
+// The bytes the Ethereum wallet signs off-chain with personal_sign (EIP-191),
+// prefixed with the IX discriminant so that they can't be mistaken for another
+// kind of intent
+pub fn message(intent: &Intent) -> Vec<u8> {
+    let mut payload = Vec::new();
+    payload.extend_from_slice(&DISCRIMINANT);
+    intent.serialize(&mut payload).unwrap();
+    eip191_message(&payload)
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [source_token_escrow_pda, destination_token_escrow_pda, token_fee_pda, fee_collector_token_escrow_pda, relayer_token_escrow_pda, nonce_account_pda, instructions_sysvar, config_pda] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Transfer, program_id)?;
+
+    // The escrows of the Ethereum wallet are owned by its derived authority
+    let source_authority =
+        eth_authority_generate_pda(&args.source_eth_address, program_id);
+
+    // Verify that the Ethereum wallet signed this exact transfer off-chain
+    ensure_is_secp256k1_signed(
+        instructions_sysvar,
+        &args.source_eth_address,
+        &message(&Intent {
+            source_token_escrow: *source_token_escrow_pda.key,
+            destination_token_escrow: *destination_token_escrow_pda.key,
+            amount: args.amount,
+            nonce: args.nonce,
+            expiry: args.expiry,
+            relayer_token_escrow: *relayer_token_escrow_pda.key,
+            max_relayer_fee: args.max_relayer_fee,
+        }),
+    )?;
+
+    // Verify that the intent is not stale and was never submitted before
+    consume_nonce(
+        nonce_account_pda,
+        &source_authority,
+        args.nonce,
+        args.expiry,
+        program_id,
+    )?;
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized)
+    ensure_is_owned_by_program(source_token_escrow_pda, program_id)?;
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized)
+    ensure_is_owned_by_program(destination_token_escrow_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDA
+    let source_token_escrow_seeds = token_escrow_seeds_generator!(
+        source_authority,
+        args.validator,
+        args.token_mint,
+        args.source_slot
+    );
+    ensure_is_pda(
+        source_token_escrow_pda,
+        source_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Verify the seeds of the escrow PDA
+    let destination_token_escrow_seeds = token_escrow_seeds_generator!(
+        args.destination_authority,
+        args.validator,
+        args.token_mint,
+        args.destination_slot
+    );
+    ensure_is_pda(
+        destination_token_escrow_pda,
+        destination_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Compute the fee owed to the collector (no fee if no schedule was set)
+    let fee = match load_token_fee(
+        token_fee_pda,
+        fee_collector_token_escrow_pda,
+        &args.validator,
+        &args.token_mint,
+        program_id,
+    )? {
+        Some(token_fee_data) => {
+            token_fee_data
+                .compute_fee(args.amount)
+                .ok_or(ProgramError::InsufficientFunds)?
+        },
+        None => 0,
+    };
+
+    // Compute the fee owed to the relayer who submitted the intent
+    let relayer_fee = load_relayer_fee(
+        relayer_token_escrow_pda,
+        token_escrow_seeds_generator!(
+            args.relayer_authority,
+            args.validator,
+            args.token_mint,
+            args.relayer_slot
+        ),
+        args.relayer_fee,
+        args.max_relayer_fee,
+        program_id,
+    )?;
+
+    // Move the amount, the fee and the relayer's compensation
+    let (source_amount, destination_amount) = transfer_token_escrow_amount(
+        source_token_escrow_pda,
+        destination_token_escrow_pda,
+        fee_collector_token_escrow_pda,
+        Some((relayer_token_escrow_pda, relayer_fee)),
+        args.amount,
+        fee,
+    )?;
+
+    // Log outcome
+    msg!(
+        "Ephemeral Rollups Wrapper: Transfered an Ethereum TokenEscrow intent"
+    );
+    msg!(
+        " - source_authority: {} (slot: {})",
+        source_authority,
+        args.source_slot
+    );
+    msg!(" - source_eth_address: {:02x?}", args.source_eth_address);
+    msg!(
+        " - destination_authority: {} (slot: {})",
+        args.destination_authority,
+        args.destination_slot
+    );
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(
+        " - amount: {} (source: {}, destination: {})",
+        args.amount,
+        source_amount,
+        destination_amount
+    );
+    msg!(" - fee: {}", fee);
+    msg!(
+        " - relayer_fee: {} (relayer: {})",
+        relayer_fee,
+        args.relayer_authority
+    );
+    msg!(" - nonce: {} (expiry: {})", args.nonce, args.expiry);
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::config::ConfigPause;
+use crate::state::split_config::SplitConfig;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::fee::collect_token_fee;
+use crate::util::fee::load_token_fee;
+use crate::util::transfer::credit_token_escrow;
+use crate::util::transfer::debit_token_escrow;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x13, 0x62, 0x29, 0x09, 0x9E, 0x1C, 0x17, 0x9C];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub source_slot: u64,
+    pub amount: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [source_authority, source_token_escrow_pda, split_config_pda, token_fee_pda, fee_collector_token_escrow_pda, config_pda, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Transfer, program_id)?;
+
+    // Verify that the program has proper control of the split config PDA (and
+    // that it's been initialized)
+    ensure_is_owned_by_program(split_config_pda, program_id)?;
+    let split_config_data =
+        SplitConfig::deserialize(&mut &split_config_pda.data.borrow()[..])?;
+    if split_config_data.discriminant != SplitConfig::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The destination escrows come first, followed by the multisig signers
+    if remaining.len() < split_config_data.recipients.len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (destination_token_escrow_pdas, signers) =
+        remaining.split_at(split_config_data.recipients.len());
+
+    // Verify that the authority user is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(source_authority, signers, program_id)?;
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized)
+    ensure_is_owned_by_program(source_token_escrow_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDA
+    let source_token_escrow_seeds = token_escrow_seeds_generator!(
+        source_authority.key,
+        args.validator,
+        args.token_mint,
+        args.source_slot
+    );
+    ensure_is_pda(
+        source_token_escrow_pda,
+        source_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Verify that the destination escrows are the ones of the split config
+    for (recipient, destination_token_escrow_pda) in
+        split_config_data.recipients.iter().zip(destination_token_escrow_pdas)
+    {
+        ensure_is_owned_by_program(destination_token_escrow_pda, program_id)?;
+        let destination_token_escrow_seeds = token_escrow_seeds_generator!(
+            recipient.destination_authority,
+            args.validator,
+            args.token_mint,
+            recipient.destination_slot
+        );
+        ensure_is_pda(
+            destination_token_escrow_pda,
+            destination_token_escrow_seeds,
+            program_id,
+        )?;
+    }
+
+    // Compute the fee owed to the collector (no fee if no schedule was set)
+    let fee = match load_token_fee(
+        token_fee_pda,
+        fee_collector_token_escrow_pda,
+        &args.validator,
+        &args.token_mint,
+        program_id,
+    )? {
+        Some(token_fee_data) => {
+            token_fee_data
+                .compute_fee(args.amount)
+                .ok_or(ProgramError::InsufficientFunds)?
+        },
+        None => 0,
+    };
+
+    // Compute each recipient's share of what remains after the fee
+    let amounts = split_config_data
+        .compute_amounts(args.amount - fee)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Update the source escrow amount (fail if not enough amount available)
+    let source_amount =
+        debit_token_escrow(source_token_escrow_pda, args.amount)?;
+
+    // Update every destination escrow amount (if everything else suceeded)
+    for (amount, destination_token_escrow_pda) in
+        amounts.iter().zip(destination_token_escrow_pdas)
+    {
+        credit_token_escrow(destination_token_escrow_pda, *amount)?;
+    }
+
+    // Update the collector escrow amount with the fee that was split off
+    collect_token_fee(fee_collector_token_escrow_pda, fee)?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Transfered split from TokenEscrow");
+    msg!(
+        " - source_authority: {} (slot: {})",
+        source_authority.key,
+        args.source_slot
+    );
+    msg!(" - split_config: {}", split_config_pda.key);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(" - amount: {} (source: {})", args.amount, source_amount);
+    msg!(" - fee: {}", fee);
+    for (recipient, amount) in split_config_data.recipients.iter().zip(&amounts)
+    {
+        msg!(
+            " - destination_authority: {} (slot: {}, amount: {})",
+            recipient.destination_authority,
+            recipient.destination_slot,
+            amount
+        );
+    }
+
+    // Done
+    Ok(())
+}
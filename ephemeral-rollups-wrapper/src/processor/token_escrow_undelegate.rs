@@ -8,12 +8,15 @@ use solana_program::msg;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
+use crate::state::config::ConfigPause;
 use crate::state::token_escrow::TokenEscrow;
 use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
 use crate::util::ensure::ensure_is_owned_by_program;
 use crate::util::ensure::ensure_is_pda;
 use crate::util::ensure::ensure_is_program_id;
 use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
 
 pub const DISCRIMINANT: [u8; 8] =
     [0x4B, 0x9C, 0x96, 0x18, 0xDF, 0x98, 0x31, 0x24];
@@ -30,13 +33,16 @@ pub fn process(
     accounts: &[AccountInfo],
     data: &[u8],
 ) -> ProgramResult {
-    let [payer, authority, token_escrow_pda, magic_context_pda, magic_program_id] =
+    let [payer, authority, token_escrow_pda, magic_context_pda, magic_program_id, config_pda, signers @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     let args = Args::try_from_slice(data)?;
 
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Undelegate, program_id)?;
+
     // Verify the programs
     ensure_is_program_id(magic_program_id, &MAGIC_PROGRAM_ID)?;
 
@@ -44,7 +50,8 @@ pub fn process(
     ensure_is_signer(payer)?;
 
     // Verify that the authority user is indeed the one initiating this IX
-    ensure_is_signer(authority)?;
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(authority, signers, program_id)?;
 
     // Verify that the program has proper control of the PDA (and that it's been
     // initialized)
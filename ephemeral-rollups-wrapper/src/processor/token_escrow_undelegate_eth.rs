@@ -0,0 +1,132 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::consts::MAGIC_PROGRAM_ID;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::processor::token_escrow_delegate_eth::Intent;
+use crate::state::config::ConfigPause;
+use crate::state::eth_authority::eth_authority_generate_pda;
+use crate::state::token_escrow::TokenEscrow;
+use crate::token_escrow_seeds_generator;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::nonce::consume_nonce;
+use crate::util::signature::eip191_message;
+use crate::util::signature::ensure_is_secp256k1_signed;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x71, 0x91, 0x01, 0x6D, 0x3F, 0x60, 0x5A, 0x78];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub eth_address: [u8; 20],
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub slot: u64,
+    pub nonce: u64,
+    pub expiry: i64,
+}
+
+// The bytes the Ethereum wallet signs off-chain with personal_sign (EIP-191),
+// prefixed with the IX discriminant so that they can't be mistaken for another
+// kind of intent
+pub fn message(intent: &Intent) -> Vec<u8> {
+    let mut payload = Vec::new();
+    payload.extend_from_slice(&DISCRIMINANT);
+    intent.serialize(&mut payload).unwrap();
+    eip191_message(&payload)
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let [payer, token_escrow_pda, nonce_account_pda, instructions_sysvar, magic_context_pda, magic_program_id, config_pda] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Undelegate, program_id)?;
+
+    // Verify the programs
+    ensure_is_program_id(magic_program_id, &MAGIC_PROGRAM_ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // The escrows of the Ethereum wallet are owned by its derived authority
+    let authority = eth_authority_generate_pda(&args.eth_address, program_id);
+
+    // Verify that the Ethereum wallet signed this exact undelegation off-chain
+    ensure_is_secp256k1_signed(
+        instructions_sysvar,
+        &args.eth_address,
+        &message(&Intent {
+            account: *token_escrow_pda.key,
+            nonce: args.nonce,
+            expiry: args.expiry,
+        }),
+    )?;
+
+    // Verify that the intent is not stale and was never submitted before (the
+    // nonce account has to be delegated alongside the escrow)
+    consume_nonce(
+        nonce_account_pda,
+        &authority,
+        args.nonce,
+        args.expiry,
+        program_id,
+    )?;
+
+    // Verify that the program has proper control of the PDA (and that it's been
+    // initialized)
+    ensure_is_owned_by_program(token_escrow_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDA
+    let token_escrow_seeds = token_escrow_seeds_generator!(
+        authority,
+        args.validator,
+        args.token_mint,
+        args.slot
+    );
+    ensure_is_pda(token_escrow_pda, token_escrow_seeds, program_id)?;
+
+    // Verify that the escrow PDA is properly initalized
+    let token_escrow_data =
+        TokenEscrow::try_from_slice(&token_escrow_pda.data.borrow())?;
+    if token_escrow_data.discriminant != TokenEscrow::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Request undelegation inside the ER
+    commit_and_undelegate_accounts(
+        payer,
+        vec![token_escrow_pda],
+        magic_context_pda,
+        magic_program_id,
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Requested undelegation of an Ethereum TokenEscrow intent");
+    msg!(" - authority: {} (slot: {})", authority, args.slot);
+    msg!(" - eth_address: {:02x?}", args.eth_address);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(" - amount: {}", token_escrow_data.amount);
+    msg!(" - nonce: {} (expiry: {})", args.nonce, args.expiry);
+
+    // Done
+    Ok(())
+}
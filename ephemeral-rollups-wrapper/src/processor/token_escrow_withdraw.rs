@@ -6,16 +6,22 @@ use solana_program::msg;
 use solana_program::program::invoke_signed;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
-use spl_token::instruction::transfer;
 
+use crate::state::config::ConfigPause;
 use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_vault_state::TokenVaultState;
 use crate::token_escrow_seeds_generator;
 use crate::token_vault_seeds_generator;
+use crate::token_vault_state_seeds_generator;
+use crate::util::ensure::ensure_is_authority;
 use crate::util::ensure::ensure_is_owned_by_program;
 use crate::util::ensure::ensure_is_pda;
-use crate::util::ensure::ensure_is_program_id;
-use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_token_program;
+use crate::util::ensure::ensure_is_unfrozen;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::limit::consume_spending_limit;
 use crate::util::signer::signer_seeds;
+use crate::util::token::transfer;
 
 pub const DISCRIMINANT: [u8; 8] =
     [0xDA, 0xCF, 0x42, 0xDD, 0x24, 0x78, 0x76, 0x44];
@@ -34,18 +40,22 @@ pub fn process(
     data: &[u8],
 ) -> ProgramResult {
     // Read instruction inputs
-    let [authority, destination_token_account, token_escrow_pda, token_vault_pda, token_program_id] =
+    let [authority, destination_token_account, token_escrow_pda, token_vault_pda, token_vault_state_pda, token_program_id, config_pda, signers @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     let args = Args::try_from_slice(data)?;
 
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Withdraw, program_id)?;
+
     // Verify the programs
-    ensure_is_program_id(token_program_id, &spl_token::ID)?;
+    ensure_is_token_program(token_program_id)?;
 
     // Verify that the authority user is indeed the one initiating this IX
-    ensure_is_signer(authority)?;
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(authority, signers, program_id)?;
 
     // Verify that the program has proper control of the escrow PDA (and that
     // it's been initialized)
@@ -53,6 +63,7 @@ pub fn process(
 
     // Verify that the vault has been initialized properly
     ensure_is_owned_by_program(token_vault_pda, token_program_id.key)?;
+    ensure_is_owned_by_program(token_vault_state_pda, program_id)?;
 
     // Verify the seeds of the escrow PDA
     let token_escrow_seeds = token_escrow_seeds_generator!(
@@ -69,18 +80,45 @@ pub fn process(
     let token_vault_bump =
         ensure_is_pda(token_vault_pda, token_vault_seeds, program_id)?;
 
+    // Verify the seeds of the vault state PDA
+    let token_vault_state_seeds =
+        token_vault_state_seeds_generator!(args.validator, args.token_mint);
+    ensure_is_pda(token_vault_state_pda, token_vault_state_seeds, program_id)?;
+
     // Update the escrow amount (panic if not enough amount available)
     let mut token_escrow_data =
         TokenEscrow::try_from_slice(&token_escrow_pda.data.borrow())?;
     if token_escrow_data.discriminant != TokenEscrow::discriminant() {
         return Err(ProgramError::InvalidAccountData);
     }
+    // Verify that the issuer didn't freeze the escrow
+    ensure_is_unfrozen(&token_escrow_data)?;
+    // Verify that the escrow's spending limit allows this amount to leave
+    consume_spending_limit(&mut token_escrow_data.spending_limit, args.amount)?;
     token_escrow_data.amount =
         token_escrow_data.amount.checked_sub(args.amount).unwrap();
     token_escrow_data.serialize(
         &mut &mut token_escrow_pda.try_borrow_mut_data()?.as_mut(),
     )?;
 
+    // Update the vault accounting, keeping track of the escrowed supply
+    let mut token_vault_state_data =
+        TokenVaultState::try_from_slice(&token_vault_state_pda.data.borrow())?;
+    if token_vault_state_data.discriminant != TokenVaultState::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    token_vault_state_data.total_withdrawn = token_vault_state_data
+        .total_withdrawn
+        .checked_add(args.amount)
+        .unwrap();
+    token_vault_state_data.outstanding_amount = token_vault_state_data
+        .outstanding_amount
+        .checked_sub(args.amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    token_vault_state_data.serialize(
+        &mut &mut token_vault_state_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
     // Proceed to transfer from token_vault_pda to destination_token_account (if
     // everything else succeeded)
     invoke_signed(
@@ -89,9 +127,8 @@ pub fn process(
             token_vault_pda.key,
             destination_token_account.key,
             token_vault_pda.key,
-            &[],
             args.amount,
-        )?,
+        ),
         &[
             token_vault_pda.clone(),
             destination_token_account.clone(),
@@ -0,0 +1,202 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::config::ConfigPause;
+use crate::state::eth_authority::eth_authority_generate_pda;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_vault_state::TokenVaultState;
+use crate::token_escrow_seeds_generator;
+use crate::token_vault_seeds_generator;
+use crate::token_vault_state_seeds_generator;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_token_program;
+use crate::util::ensure::ensure_is_unfrozen;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::limit::consume_spending_limit;
+use crate::util::nonce::consume_nonce;
+use crate::util::signature::eip191_message;
+use crate::util::signature::ensure_is_secp256k1_signed;
+use crate::util::signer::signer_seeds;
+use crate::util::token::transfer;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x4B, 0x1F, 0x60, 0x2F, 0x7B, 0x74, 0x41, 0x46];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub eth_address: [u8; 20],
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub slot: u64,
+    pub amount: u64,
+    pub nonce: u64,
+    pub expiry: i64,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Intent {
+    pub token_escrow: Pubkey,
+    pub destination_token_account: Pubkey,
+    pub amount: u64,
+    pub nonce: u64,
+    pub expiry: i64,
+}
+
+// The bytes the Ethereum wallet signs off-chain with personal_sign (EIP-191),
+// prefixed with the IX discriminant so that they can't be mistaken for another
+// kind of intent
+pub fn message(intent: &Intent) -> Vec<u8> {
+    let mut payload = Vec::new();
+    payload.extend_from_slice(&DISCRIMINANT);
+    intent.serialize(&mut payload).unwrap();
+    eip191_message(&payload)
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [destination_token_account, token_escrow_pda, token_vault_pda, token_vault_state_pda, nonce_account_pda, instructions_sysvar, token_program_id, config_pda] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Withdraw, program_id)?;
+
+    // Verify the programs
+    ensure_is_token_program(token_program_id)?;
+
+    // The escrows of the Ethereum wallet are owned by its derived authority
+    let authority = eth_authority_generate_pda(&args.eth_address, program_id);
+
+    // Verify that the Ethereum wallet signed this exact withdrawal off-chain
+    ensure_is_secp256k1_signed(
+        instructions_sysvar,
+        &args.eth_address,
+        &message(&Intent {
+            token_escrow: *token_escrow_pda.key,
+            destination_token_account: *destination_token_account.key,
+            amount: args.amount,
+            nonce: args.nonce,
+            expiry: args.expiry,
+        }),
+    )?;
+
+    // Verify that the intent is not stale and was never submitted before
+    consume_nonce(
+        nonce_account_pda,
+        &authority,
+        args.nonce,
+        args.expiry,
+        program_id,
+    )?;
+
+    // Verify that the program has proper control of the escrow PDA (and that
+    // it's been initialized)
+    ensure_is_owned_by_program(token_escrow_pda, program_id)?;
+
+    // Verify that the vault has been initialized properly
+    ensure_is_owned_by_program(token_vault_pda, token_program_id.key)?;
+    ensure_is_owned_by_program(token_vault_state_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDA
+    let token_escrow_seeds = token_escrow_seeds_generator!(
+        authority,
+        args.validator,
+        args.token_mint,
+        args.slot
+    );
+    ensure_is_pda(token_escrow_pda, token_escrow_seeds, program_id)?;
+
+    // Verify the seeds of the vault PDA
+    let token_vault_seeds =
+        token_vault_seeds_generator!(args.validator, args.token_mint);
+    let token_vault_bump =
+        ensure_is_pda(token_vault_pda, token_vault_seeds, program_id)?;
+
+    // Verify the seeds of the vault state PDA
+    let token_vault_state_seeds =
+        token_vault_state_seeds_generator!(args.validator, args.token_mint);
+    ensure_is_pda(token_vault_state_pda, token_vault_state_seeds, program_id)?;
+
+    // Update the escrow amount (panic if not enough amount available)
+    let mut token_escrow_data =
+        TokenEscrow::try_from_slice(&token_escrow_pda.data.borrow())?;
+    if token_escrow_data.discriminant != TokenEscrow::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    // Verify that the issuer didn't freeze the escrow
+    ensure_is_unfrozen(&token_escrow_data)?;
+    // Verify that the escrow's spending limit allows this amount to leave
+    consume_spending_limit(&mut token_escrow_data.spending_limit, args.amount)?;
+    token_escrow_data.amount =
+        token_escrow_data.amount.checked_sub(args.amount).unwrap();
+    token_escrow_data.serialize(
+        &mut &mut token_escrow_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Update the vault accounting, keeping track of the escrowed supply
+    let mut token_vault_state_data =
+        TokenVaultState::try_from_slice(&token_vault_state_pda.data.borrow())?;
+    if token_vault_state_data.discriminant != TokenVaultState::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    token_vault_state_data.total_withdrawn = token_vault_state_data
+        .total_withdrawn
+        .checked_add(args.amount)
+        .unwrap();
+    token_vault_state_data.outstanding_amount = token_vault_state_data
+        .outstanding_amount
+        .checked_sub(args.amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    token_vault_state_data.serialize(
+        &mut &mut token_vault_state_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Proceed to transfer from token_vault_pda to destination_token_account (if
+    // everything else succeeded)
+    invoke_signed(
+        &transfer(
+            token_program_id.key,
+            token_vault_pda.key,
+            destination_token_account.key,
+            token_vault_pda.key,
+            args.amount,
+        ),
+        &[
+            token_vault_pda.clone(),
+            destination_token_account.clone(),
+            token_vault_pda.clone(),
+        ],
+        &[&signer_seeds(token_vault_seeds, &[token_vault_bump])],
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Withdrew an Ethereum TokenEscrow intent");
+    msg!(" - authority: {} (slot: {})", authority, args.slot);
+    msg!(" - eth_address: {:02x?}", args.eth_address);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(" - destination_token_account: {}", destination_token_account.key);
+    msg!(
+        " - amount: {} (remaining: {})",
+        args.amount,
+        token_escrow_data.amount
+    );
+    msg!(" - nonce: {} (expiry: {})", args.nonce, args.expiry);
+
+    // Done
+    Ok(())
+}
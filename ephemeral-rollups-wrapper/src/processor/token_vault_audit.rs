@@ -0,0 +1,83 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::token_vault_state::TokenVaultState;
+use crate::token_vault_seeds_generator;
+use crate::token_vault_state_seeds_generator;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::token::is_token_program;
+use crate::util::token::unpack_token_account;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x26, 0x15, 0x14, 0x89, 0x76, 0xF1, 0x22, 0x7D];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [token_vault_pda, token_vault_state_pda] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that the vault has been initialized properly
+    if !is_token_program(token_vault_pda.owner) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    ensure_is_owned_by_program(token_vault_state_pda, program_id)?;
+
+    // Verify the seeds of the vault PDA
+    let token_vault_seeds =
+        token_vault_seeds_generator!(args.validator, args.token_mint);
+    ensure_is_pda(token_vault_pda, token_vault_seeds, program_id)?;
+
+    // Verify the seeds of the vault state PDA
+    let token_vault_state_seeds =
+        token_vault_state_seeds_generator!(args.validator, args.token_mint);
+    ensure_is_pda(token_vault_state_pda, token_vault_state_seeds, program_id)?;
+
+    // Read the vault's actual token balance
+    let token_vault_data = unpack_token_account(&token_vault_pda.data.borrow())?;
+
+    // Read the vault's accounting
+    let token_vault_state_data =
+        TokenVaultState::try_from_slice(&token_vault_state_pda.data.borrow())?;
+    if token_vault_state_data.discriminant != TokenVaultState::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Audited a vault for token mint");
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(" - total_deposited: {}", token_vault_state_data.total_deposited);
+    msg!(" - total_withdrawn: {}", token_vault_state_data.total_withdrawn);
+    msg!(
+        " - outstanding_amount: {} (vault balance: {})",
+        token_vault_state_data.outstanding_amount,
+        token_vault_data.amount
+    );
+
+    // Verify that every escrowed token is backed by the vault
+    if token_vault_data.amount < token_vault_state_data.outstanding_amount {
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    // Done
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use borsh::BorshSerialize;
 use solana_program::account_info::AccountInfo;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::msg;
@@ -6,15 +7,22 @@ use solana_program::program_error::ProgramError;
 use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
 use solana_program::system_program;
-use spl_token::instruction::initialize_account3;
 use spl_token::state::Account;
 
+use crate::state::config::ConfigPause;
+use crate::state::token_vault_state::TokenVaultState;
 use crate::token_vault_seeds_generator;
+use crate::token_vault_state_seeds_generator;
 use crate::util::create::create_pda;
+use crate::util::ensure::ensure_is_allowed_token_program;
 use crate::util::ensure::ensure_is_owned_by_program;
 use crate::util::ensure::ensure_is_pda;
 use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_registered_validator;
 use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::mint::ensure_is_supported_mint;
+use crate::util::token::initialize_account3;
 
 pub const DISCRIMINANT: [u8; 8] =
     [0x70, 0xFE, 0x66, 0x40, 0x47, 0x49, 0x16, 0x0E];
@@ -25,28 +33,54 @@ pub fn process(
     _data: &[u8],
 ) -> ProgramResult {
     // Read instruction inputs
-    let [payer, validator, token_mint, token_vault_pda, token_program_id, system_program_id] =
+    let [payer, validator, token_mint, token_vault_pda, token_vault_state_pda, token_program_id, system_program_id, config_pda, validator_registry_pda] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    // Verify that this family of IX is not currently paused
+    let config_data =
+        ensure_is_unpaused(config_pda, ConfigPause::Create, program_id)?;
+
+    // Verify that the validator is approved and still active
+    ensure_is_registered_validator(
+        validator_registry_pda,
+        validator.key,
+        program_id,
+    )?;
+
     // Verify the programs
-    ensure_is_program_id(token_program_id, &spl_token::ID)?;
+    ensure_is_allowed_token_program(token_program_id, &config_data)?;
     ensure_is_program_id(system_program_id, &system_program::ID)?;
 
+    // Verify that the mint has no extension the vault couldn't handle
+    ensure_is_supported_mint(token_mint)?;
+
     // Verify that the payer is allowed to pay for the rent fees
     ensure_is_signer(payer)?;
 
     // Verify that the vault PDA is currently un-initialized
     ensure_is_owned_by_program(token_vault_pda, &system_program::ID)?;
 
+    // Verify that the vault state PDA is currently un-initialized
+    ensure_is_owned_by_program(token_vault_state_pda, &system_program::ID)?;
+
     // Verify the seeds of the vault PDA
     let token_vault_seeds =
         token_vault_seeds_generator!(validator.key, token_mint.key);
     let token_vault_bump =
         ensure_is_pda(token_vault_pda, token_vault_seeds, program_id)?;
 
+    // Verify the seeds of the vault state PDA
+    let token_vault_state_seeds =
+        token_vault_state_seeds_generator!(validator.key, token_mint.key);
+    let token_vault_state_bump = ensure_is_pda(
+        token_vault_state_pda,
+        token_vault_state_seeds,
+        program_id,
+    )?;
+
     // Initialize the vault PDA
     create_pda(
         payer,
@@ -65,10 +99,32 @@ pub fn process(
             token_vault_pda.key,
             token_mint.key,
             token_vault_pda.key,
-        )?,
+        ),
         &[token_vault_pda.clone(), token_mint.clone()],
     )?;
 
+    // Initialize the vault state PDA
+    create_pda(
+        payer,
+        token_vault_state_pda,
+        token_vault_state_seeds,
+        token_vault_state_bump,
+        TokenVaultState::space(),
+        program_id,
+        system_program_id,
+    )?;
+
+    // Initialize the vault state data, nothing has been escrowed yet
+    let token_vault_state_data = TokenVaultState {
+        discriminant: TokenVaultState::discriminant(),
+        total_deposited: 0,
+        total_withdrawn: 0,
+        outstanding_amount: 0,
+    };
+    token_vault_state_data.serialize(
+        &mut &mut token_vault_state_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
     // Log outcome
     msg!("Ephemeral Rollups Wrapper: Created a new vault for token mint");
     msg!(" - validator: {}", validator.key);
@@ -0,0 +1,111 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::state::token_vault_state::TokenVaultState;
+use crate::token_vault_seeds_generator;
+use crate::token_vault_state_seeds_generator;
+use crate::util::create::create_pda;
+use crate::util::ensure::ensure_is_admin;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::token::is_token_program;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x8B, 0x65, 0x95, 0x5B, 0x16, 0x3E, 0xED, 0x67];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub outstanding_amount: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, admin, token_vault_pda, token_vault_state_pda, system_program_id, config_pda] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify the programs
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the config's admin is the one vouching for the outstanding
+    // amount (reconciled off-chain from every escrowed balance)
+    ensure_is_admin(admin, config_pda, program_id)?;
+
+    // Verify that the vault has been initialized already
+    if !is_token_program(token_vault_pda.owner) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Verify that the vault state PDA is currently un-initialized
+    ensure_is_owned_by_program(token_vault_state_pda, &system_program::ID)?;
+
+    // Verify the seeds of the vault PDA
+    let token_vault_seeds =
+        token_vault_seeds_generator!(args.validator, args.token_mint);
+    ensure_is_pda(token_vault_pda, token_vault_seeds, program_id)?;
+
+    // Verify the seeds of the vault state PDA
+    let token_vault_state_seeds =
+        token_vault_state_seeds_generator!(args.validator, args.token_mint);
+    let token_vault_state_bump = ensure_is_pda(
+        token_vault_state_pda,
+        token_vault_state_seeds,
+        program_id,
+    )?;
+
+    // Initialize the vault state PDA
+    create_pda(
+        payer,
+        token_vault_state_pda,
+        token_vault_state_seeds,
+        token_vault_state_bump,
+        TokenVaultState::space(),
+        program_id,
+        system_program_id,
+    )?;
+
+    // Initialize the vault state data, seeded from the reconciled amount since
+    // the deposits made before the vault state existed were not tracked (the
+    // vault balance itself may include tokens sent to it directly)
+    let token_vault_state_data = TokenVaultState {
+        discriminant: TokenVaultState::discriminant(),
+        total_deposited: args.outstanding_amount,
+        total_withdrawn: 0,
+        outstanding_amount: args.outstanding_amount,
+    };
+    token_vault_state_data.serialize(
+        &mut &mut token_vault_state_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Created the state of an existing vault");
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(
+        " - outstanding_amount: {}",
+        token_vault_state_data.outstanding_amount
+    );
+
+    // Done
+    Ok(())
+}
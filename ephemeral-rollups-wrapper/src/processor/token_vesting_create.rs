@@ -0,0 +1,155 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::state::config::ConfigPause;
+use crate::state::token_vesting::TokenVesting;
+use crate::token_escrow_seeds_generator;
+use crate::token_vesting_seeds_generator;
+use crate::util::create::create_pda;
+use crate::util::ensure::ensure_is_authority;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_registered_validator;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+use crate::util::transfer::debit_token_escrow;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x6A, 0x11, 0xD2, 0x05, 0x42, 0x3E, 0x9A, 0x23];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub authority: Pubkey,
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub slot: u64,
+    pub funder_slot: u64,
+    pub amount: u64,
+    pub start_unix_timestamp: i64,
+    pub cliff_unix_timestamp: i64,
+    pub end_unix_timestamp: i64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, funder, funder_token_escrow_pda, token_vesting_pda, system_program_id, config_pda, validator_registry_pda, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Vesting, program_id)?;
+
+    // Verify that the validator is approved and still active
+    ensure_is_registered_validator(
+        validator_registry_pda,
+        &args.validator,
+        program_id,
+    )?;
+
+    // Verify the programs
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the funder is indeed the one initiating this IX
+    // (or that enough of its multisig signers are)
+    ensure_is_authority(funder, signers, program_id)?;
+
+    // Verify that the schedule is well formed
+    if args.start_unix_timestamp >= args.end_unix_timestamp
+        || args.cliff_unix_timestamp < args.start_unix_timestamp
+        || args.cliff_unix_timestamp > args.end_unix_timestamp
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Verify that the program has proper control of the funder's escrow PDA
+    // (and that it's been initialized)
+    ensure_is_owned_by_program(funder_token_escrow_pda, program_id)?;
+
+    // Verify that the vesting PDA is currently un-initialized
+    ensure_is_owned_by_program(token_vesting_pda, &system_program::ID)?;
+
+    // Verify the seeds of the funder's escrow PDA
+    let funder_token_escrow_seeds = token_escrow_seeds_generator!(
+        funder.key,
+        args.validator,
+        args.token_mint,
+        args.funder_slot
+    );
+    ensure_is_pda(
+        funder_token_escrow_pda,
+        funder_token_escrow_seeds,
+        program_id,
+    )?;
+
+    // Verify the seeds of the vesting PDA
+    let token_vesting_seeds = token_vesting_seeds_generator!(
+        args.authority,
+        funder.key,
+        args.validator,
+        args.token_mint,
+        args.slot
+    );
+    let token_vesting_bump =
+        ensure_is_pda(token_vesting_pda, token_vesting_seeds, program_id)?;
+
+    // Update the funder's escrow amount (fail if not enough amount available)
+    debit_token_escrow(funder_token_escrow_pda, args.amount)?;
+
+    // Initialize the vesting PDA
+    create_pda(
+        payer,
+        token_vesting_pda,
+        token_vesting_seeds,
+        token_vesting_bump,
+        TokenVesting::space(),
+        program_id,
+        system_program_id,
+    )?;
+
+    // Initialize the vesting data, locking the funded amount
+    let token_vesting_data = TokenVesting {
+        discriminant: TokenVesting::discriminant(),
+        total_amount: args.amount,
+        released_amount: 0,
+        start_unix_timestamp: args.start_unix_timestamp,
+        cliff_unix_timestamp: args.cliff_unix_timestamp,
+        end_unix_timestamp: args.end_unix_timestamp,
+    };
+    token_vesting_data.serialize(
+        &mut &mut token_vesting_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Created a new TokenVesting");
+    msg!(" - authority: {} (slot: {})", args.authority, args.slot);
+    msg!(" - funder: {} (slot: {})", funder.key, args.funder_slot);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(" - amount: {}", args.amount);
+    msg!(
+        " - schedule: start {}, cliff {}, end {}",
+        args.start_unix_timestamp,
+        args.cliff_unix_timestamp,
+        args.end_unix_timestamp
+    );
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::consts::DELEGATION_PROGRAM_ID;
+use ephemeral_rollups_sdk::cpi::delegate_account;
+use ephemeral_rollups_sdk::cpi::DelegateAccounts;
+use ephemeral_rollups_sdk::cpi::DelegateConfig;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::state::config::ConfigPause;
+use crate::state::token_vesting::TokenVesting;
+use crate::token_vesting_seeds_generator;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_registered_validator;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0xD7, 0x7E, 0xC9, 0x9B, 0x84, 0xF3, 0x8A, 0x44];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub funder: Pubkey,
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub slot: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, authority, token_vesting_pda, delegation_buffer_pda, delegation_record_pda, delegation_metadata_pda, delegation_program_id, owner_program_id, system_program_id, config_pda, validator_registry_pda] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Delegate, program_id)?;
+
+    // Verify that the validator is approved and still active
+    ensure_is_registered_validator(
+        validator_registry_pda,
+        &args.validator,
+        program_id,
+    )?;
+
+    // Verify the programs
+    ensure_is_program_id(delegation_program_id, &DELEGATION_PROGRAM_ID)?;
+    ensure_is_program_id(owner_program_id, program_id)?;
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the authority user is indeed the one initiating this IX
+    ensure_is_signer(authority)?;
+
+    // Verify that the program has proper control of the PDA (and that it's been
+    // initialized)
+    ensure_is_owned_by_program(token_vesting_pda, program_id)?;
+
+    // Verify the seeds of the vesting PDA
+    let token_vesting_seeds = token_vesting_seeds_generator!(
+        authority.key,
+        args.funder,
+        args.validator,
+        args.token_mint,
+        args.slot
+    );
+    ensure_is_pda(token_vesting_pda, token_vesting_seeds, program_id)?;
+
+    // Verify that the vesting PDA is properly initalized
+    let token_vesting_data =
+        TokenVesting::try_from_slice(&token_vesting_pda.data.borrow())?;
+    if token_vesting_data.discriminant != TokenVesting::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Delegate the vesting, relinquish control on chain (it will become usable
+    // in the Ephem)
+    let accounts = DelegateAccounts {
+        payer,
+        pda: token_vesting_pda,
+        owner_program: owner_program_id,
+        buffer: delegation_buffer_pda,
+        delegation_record: delegation_record_pda,
+        delegation_metadata: delegation_metadata_pda,
+        delegation_program: delegation_program_id,
+        system_program: system_program_id,
+    };
+    // Delegate the vesting, relinquish control on chain (it will become
+    // claimable in the Ephem)
+    delegate_account(accounts, token_vesting_seeds, DelegateConfig::default())?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Delegated a TokenVesting");
+    msg!(" - authority: {} (slot: {})", authority.key, args.slot);
+    msg!(" - funder: {}", args.funder);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(
+        " - released: {} (total: {})",
+        token_vesting_data.released_amount,
+        token_vesting_data.total_amount
+    );
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::state::config::ConfigPause;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_vesting::TokenVesting;
+use crate::token_escrow_seeds_generator;
+use crate::token_vesting_seeds_generator;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x5B, 0x53, 0xF0, 0xF7, 0xDE, 0xDE, 0xCE, 0x3E];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub authority: Pubkey,
+    pub funder: Pubkey,
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub slot: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [token_escrow_pda, token_vesting_pda, config_pda] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Withdraw, program_id)?;
+
+    // Verify that the program has proper control of the PDAs (and that they've
+    // been initialized)
+    ensure_is_owned_by_program(token_escrow_pda, program_id)?;
+    ensure_is_owned_by_program(token_vesting_pda, program_id)?;
+
+    // Verify the seeds of the escrow PDA
+    let token_escrow_seeds = token_escrow_seeds_generator!(
+        args.authority,
+        args.validator,
+        args.token_mint,
+        args.slot
+    );
+    ensure_is_pda(token_escrow_pda, token_escrow_seeds, program_id)?;
+
+    // Verify the seeds of the vesting PDA
+    let token_vesting_seeds = token_vesting_seeds_generator!(
+        args.authority,
+        args.funder,
+        args.validator,
+        args.token_mint,
+        args.slot
+    );
+    ensure_is_pda(token_vesting_pda, token_vesting_seeds, program_id)?;
+
+    // Compute what has been unlocked since the last release
+    let mut token_vesting_data =
+        TokenVesting::try_from_slice(&token_vesting_pda.data.borrow())?;
+    if token_vesting_data.discriminant != TokenVesting::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let unix_timestamp = Clock::get()?.unix_timestamp;
+    let released_amount = token_vesting_data
+        .vested_amount(unix_timestamp)
+        .saturating_sub(token_vesting_data.released_amount);
+
+    // Update the vesting released amount
+    token_vesting_data.released_amount = token_vesting_data
+        .released_amount
+        .checked_add(released_amount)
+        .unwrap();
+    token_vesting_data.serialize(
+        &mut &mut token_vesting_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Materialize the unlocked amount into the spendable escrow balance
+    let mut token_escrow_data =
+        TokenEscrow::try_from_slice(&token_escrow_pda.data.borrow())?;
+    if token_escrow_data.discriminant != TokenEscrow::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    token_escrow_data.amount =
+        token_escrow_data.amount.checked_add(released_amount).unwrap();
+    token_escrow_data.serialize(
+        &mut &mut token_escrow_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Released from TokenVesting");
+    msg!(" - authority: {} (slot: {})", args.authority, args.slot);
+    msg!(" - funder: {}", args.funder);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(
+        " - amount: {} (released: {}, total: {})",
+        released_amount,
+        token_vesting_data.released_amount,
+        token_vesting_data.total_amount
+    );
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use ephemeral_rollups_sdk::consts::MAGIC_PROGRAM_ID;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::config::ConfigPause;
+use crate::state::token_vesting::TokenVesting;
+use crate::token_vesting_seeds_generator;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+use crate::util::ensure::ensure_is_unpaused;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x74, 0x2C, 0x91, 0x57, 0xA1, 0xD3, 0xA6, 0xC7];
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Args {
+    pub funder: Pubkey,
+    pub validator: Pubkey,
+    pub token_mint: Pubkey,
+    pub slot: u64,
+}
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let [payer, authority, token_vesting_pda, magic_context_pda, magic_program_id, config_pda] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let args = Args::try_from_slice(data)?;
+
+    // Verify that this family of IX is not currently paused
+    ensure_is_unpaused(config_pda, ConfigPause::Undelegate, program_id)?;
+
+    // Verify the programs
+    ensure_is_program_id(magic_program_id, &MAGIC_PROGRAM_ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the authority user is indeed the one initiating this IX
+    ensure_is_signer(authority)?;
+
+    // Verify that the program has proper control of the PDA (and that it's been
+    // initialized)
+    ensure_is_owned_by_program(token_vesting_pda, program_id)?;
+
+    // Verify the seeds of the vesting PDA
+    let token_vesting_seeds = token_vesting_seeds_generator!(
+        authority.key,
+        args.funder,
+        args.validator,
+        args.token_mint,
+        args.slot
+    );
+    ensure_is_pda(token_vesting_pda, token_vesting_seeds, program_id)?;
+
+    // Verify that the vesting PDA is properly initalized
+    let token_vesting_data =
+        TokenVesting::try_from_slice(&token_vesting_pda.data.borrow())?;
+    if token_vesting_data.discriminant != TokenVesting::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Request undelegation inside the ER
+    commit_and_undelegate_accounts(
+        payer,
+        vec![token_vesting_pda],
+        magic_context_pda,
+        magic_program_id,
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Requested undelegation of a TokenVesting");
+    msg!(" - authority: {} (slot: {})", authority.key, args.slot);
+    msg!(" - funder: {}", args.funder);
+    msg!(" - validator: {}", args.validator);
+    msg!(" - token_mint: {}", args.token_mint);
+    msg!(
+        " - released: {} (total: {})",
+        token_vesting_data.released_amount,
+        token_vesting_data.total_amount
+    );
+
+    // Done
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::state::validator_registry::ValidatorRegistry;
+use crate::util::create::create_pda;
+use crate::util::ensure::ensure_is_admin;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+use crate::util::ensure::ensure_is_program_id;
+use crate::util::ensure::ensure_is_signer;
+use crate::validator_registry_seeds_generator;
+
+pub const DISCRIMINANT: [u8; 8] =
+    [0x79, 0xC6, 0x3B, 0x49, 0x21, 0x0D, 0x3D, 0x6F];
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    // Read instruction inputs
+    let [payer, admin, config_pda, validator_registry_pda, system_program_id] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Verify the programs
+    ensure_is_program_id(system_program_id, &system_program::ID)?;
+
+    // Verify that the payer is allowed to pay for the rent fees
+    ensure_is_signer(payer)?;
+
+    // Verify that the config's admin is indeed the one initiating this IX
+    ensure_is_admin(admin, config_pda, program_id)?;
+
+    // Verify that the registry PDA is currently un-initialized
+    ensure_is_owned_by_program(validator_registry_pda, &system_program::ID)?;
+
+    // Verify the seeds of the registry PDA
+    let validator_registry_seeds = validator_registry_seeds_generator!();
+    let validator_registry_bump = ensure_is_pda(
+        validator_registry_pda,
+        validator_registry_seeds,
+        program_id,
+    )?;
+
+    // Initialize the registry PDA
+    create_pda(
+        payer,
+        validator_registry_pda,
+        validator_registry_seeds,
+        validator_registry_bump,
+        ValidatorRegistry::space(),
+        program_id,
+        system_program_id,
+    )?;
+
+    // Initialize the registry data, no validator approved yet
+    let validator_registry_data = ValidatorRegistry {
+        discriminant: ValidatorRegistry::discriminant(),
+        validators: vec![],
+    };
+    validator_registry_data.serialize(
+        &mut &mut validator_registry_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // Log outcome
+    msg!("Ephemeral Rollups Wrapper: Created the ValidatorRegistry");
+    msg!(" - admin: {}", admin.key);
+
+    // Done
+    Ok(())
+}
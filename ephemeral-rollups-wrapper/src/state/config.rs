@@ -0,0 +1,76 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::config_seeds_generator;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Config {
+    pub discriminant: u64,
+    pub admin: Pubkey,
+    pub paused: u64,
+    pub allowed_token_programs: Vec<Pubkey>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigPause {
+    Create,
+    Deposit,
+    Withdraw,
+    Transfer,
+    Delegate,
+    Undelegate,
+    Market,
+    Pool,
+    Vesting,
+}
+
+impl Config {
+    pub fn discriminant() -> u64 {
+        0x6E2F0A93D14B7C85
+    }
+
+    pub fn max_allowed_token_programs() -> usize {
+        4
+    }
+
+    pub fn paused_error() -> u32 {
+        0x7061_7573
+    }
+
+    pub fn space() -> usize {
+        size_of::<u64>()
+            + size_of::<Pubkey>()
+            + size_of::<u64>()
+            + size_of::<u32>()
+            + Config::max_allowed_token_programs() * size_of::<Pubkey>()
+    }
+
+    pub fn generate_pda(program_id: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(config_seeds_generator!(), program_id).0
+    }
+
+    pub fn is_paused(
+        &self,
+        config_pause: ConfigPause,
+    ) -> bool {
+        self.paused & config_pause.flag() != 0
+    }
+}
+
+impl ConfigPause {
+    pub fn flag(self) -> u64 {
+        1 << (self as u64)
+    }
+}
+
+pub const CONFIG_SEEDS_PREFIX: &[u8] = b"config";
+
+#[macro_export]
+macro_rules! config_seeds_generator {
+    () => {
+        &[$crate::state::config::CONFIG_SEEDS_PREFIX]
+    };
+}
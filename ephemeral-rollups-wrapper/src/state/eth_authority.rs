@@ -0,0 +1,29 @@
+use solana_program::pubkey::Pubkey;
+
+use crate::eth_authority_seeds_generator;
+
+// Escrows of an Ethereum wallet are owned by this off-curve key, which can
+// never sign a transaction, so they can only be moved by the wallet's
+// secp256k1 signature
+pub fn eth_authority_generate_pda(
+    eth_address: &[u8; 20],
+    program_id: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        eth_authority_seeds_generator!(eth_address),
+        program_id,
+    )
+    .0
+}
+
+pub const ETH_AUTHORITY_SEEDS_PREFIX: &[u8] = b"eth_authority";
+
+#[macro_export]
+macro_rules! eth_authority_seeds_generator {
+    ($eth_address:expr) => {
+        &[
+            $crate::state::eth_authority::ETH_AUTHORITY_SEEDS_PREFIX,
+            $eth_address.as_slice(),
+        ]
+    };
+}
@@ -5,10 +5,12 @@ use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
 
 use crate::lamport_escrow_seeds_generator;
+use crate::state::spending_limit::SpendingLimit;
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 pub struct LamportEscrow {
     pub discriminant: u64,
+    pub spending_limit: SpendingLimit,
 }
 
 impl LamportEscrow {
@@ -17,7 +19,7 @@ impl LamportEscrow {
     }
 
     pub fn space() -> usize {
-        size_of::<u64>()
+        size_of::<u64>() + SpendingLimit::space()
     }
 
     pub fn generate_pda(
@@ -0,0 +1,122 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::market_seeds_generator;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Market {
+    pub discriminant: u64,
+    pub authority: Pubkey,
+    pub base_lot_size: u64,
+    pub min_order_lots: u64,
+    pub base_locked: u64,
+    pub quote_locked: u64,
+    pub next_order_id: u64,
+    pub orders: Vec<MarketOrder>,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct MarketOrder {
+    pub id: u64,
+    pub maker: Pubkey,
+    pub maker_base_slot: u64,
+    pub maker_quote_slot: u64,
+    pub side: MarketOrderSide,
+    pub price: u64,
+    pub quantity: u64,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize,
+)]
+pub enum MarketOrderSide {
+    Bid,
+    Ask,
+}
+
+impl Market {
+    pub fn discriminant() -> u64 {
+        0x5D3B1C6E0A27F4B9
+    }
+
+    pub fn max_orders() -> usize {
+        128
+    }
+
+    pub fn space() -> usize {
+        size_of::<u64>()
+            + size_of::<Pubkey>()
+            + size_of::<u64>()
+            + size_of::<u64>()
+            + size_of::<u64>()
+            + size_of::<u64>()
+            + size_of::<u64>()
+            + size_of::<u32>()
+            + Market::max_orders() * MarketOrder::space()
+    }
+
+    pub fn generate_pda(
+        validator: &Pubkey,
+        base_token_mint: &Pubkey,
+        quote_token_mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Pubkey {
+        Pubkey::find_program_address(
+            market_seeds_generator!(
+                validator,
+                base_token_mint,
+                quote_token_mint
+            ),
+            program_id,
+        )
+        .0
+    }
+
+    pub fn quote_amount(
+        &self,
+        quantity: u64,
+        price: u64,
+    ) -> Option<u64> {
+        if quantity.checked_rem(self.base_lot_size) != Some(0) {
+            return None;
+        }
+        (quantity / self.base_lot_size).checked_mul(price)
+    }
+}
+
+impl MarketOrder {
+    pub fn space() -> usize {
+        size_of::<u64>()
+            + size_of::<Pubkey>()
+            + size_of::<u64>()
+            + size_of::<u64>()
+            + size_of::<u8>()
+            + size_of::<u64>()
+            + size_of::<u64>()
+    }
+
+    // Bids lock quote tokens, asks lock base tokens
+    pub fn locked_slot(&self) -> u64 {
+        match self.side {
+            MarketOrderSide::Bid => self.maker_quote_slot,
+            MarketOrderSide::Ask => self.maker_base_slot,
+        }
+    }
+}
+
+pub const MARKET_SEEDS_PREFIX: &[u8] = b"market";
+
+#[macro_export]
+macro_rules! market_seeds_generator {
+    ($validator:expr, $base_token_mint:expr, $quote_token_mint:expr) => {
+        &[
+            $crate::state::market::MARKET_SEEDS_PREFIX,
+            &$validator.to_bytes(),
+            &$base_token_mint.to_bytes(),
+            &$quote_token_mint.to_bytes(),
+        ]
+    };
+}
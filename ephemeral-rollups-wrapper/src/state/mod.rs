@@ -1,4 +1,17 @@
 pub mod bubblegum_escrow;
+pub mod config;
+pub mod eth_authority;
 pub mod lamport_escrow;
+pub mod market;
+pub mod multisig;
+pub mod nonce_account;
+pub mod pool;
+pub mod recovery;
+pub mod spending_limit;
+pub mod split_config;
 pub mod token_escrow;
+pub mod token_fee;
 pub mod token_vault;
+pub mod token_vault_state;
+pub mod token_vesting;
+pub mod validator_registry;
@@ -0,0 +1,81 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::multisig_seeds_generator;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Multisig {
+    pub discriminant: u64,
+    pub threshold: u8,
+    pub signers: Vec<Pubkey>,
+}
+
+impl Multisig {
+    pub fn discriminant() -> u64 {
+        0xC47A2E9D5B1F8036
+    }
+
+    pub fn max_signers() -> usize {
+        11
+    }
+
+    pub fn space() -> usize {
+        size_of::<u64>()
+            + size_of::<u8>()
+            + size_of::<u32>()
+            + Multisig::max_signers() * size_of::<Pubkey>()
+    }
+
+    pub fn generate_pda(
+        creator: &Pubkey,
+        slot: u64,
+        program_id: &Pubkey,
+    ) -> Pubkey {
+        Pubkey::find_program_address(
+            multisig_seeds_generator!(creator, slot),
+            program_id,
+        )
+        .0
+    }
+
+    pub fn is_valid(&self) -> bool {
+        if self.threshold == 0
+            || usize::from(self.threshold) > self.signers.len()
+            || self.signers.len() > Multisig::max_signers()
+        {
+            return false;
+        }
+        for (index, signer) in self.signers.iter().enumerate() {
+            if self.signers[..index].contains(signer) {
+                return false;
+            }
+        }
+        true
+    }
+
+    pub fn count_signatures(
+        &self,
+        signers: &[&Pubkey],
+    ) -> usize {
+        self.signers
+            .iter()
+            .filter(|multisig_signer| signers.contains(multisig_signer))
+            .count()
+    }
+}
+
+pub const MULTISIG_SEEDS_PREFIX: &[u8] = b"multisig";
+
+#[macro_export]
+macro_rules! multisig_seeds_generator {
+    ($creator:expr, $slot:expr) => {
+        &[
+            $crate::state::multisig::MULTISIG_SEEDS_PREFIX,
+            &$creator.to_bytes(),
+            &$slot.to_le_bytes(),
+        ]
+    };
+}
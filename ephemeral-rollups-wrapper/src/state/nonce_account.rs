@@ -0,0 +1,56 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::nonce_account_seeds_generator;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct NonceAccount {
+    pub discriminant: u64,
+    pub nonce: u64,
+}
+
+impl NonceAccount {
+    pub fn discriminant() -> u64 {
+        0x51C0E7A93F2D84B6
+    }
+
+    pub fn space() -> usize {
+        size_of::<u64>() + size_of::<u64>()
+    }
+
+    pub fn generate_pda(
+        authority: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Pubkey {
+        Pubkey::find_program_address(
+            nonce_account_seeds_generator!(authority),
+            program_id,
+        )
+        .0
+    }
+
+    pub fn next_nonce(
+        &self,
+        nonce: u64,
+    ) -> Option<u64> {
+        if nonce < self.nonce {
+            return None;
+        }
+        nonce.checked_add(1)
+    }
+}
+
+pub const NONCE_ACCOUNT_SEEDS_PREFIX: &[u8] = b"nonce_account";
+
+#[macro_export]
+macro_rules! nonce_account_seeds_generator {
+    ($authority:expr) => {
+        &[
+            $crate::state::nonce_account::NONCE_ACCOUNT_SEEDS_PREFIX,
+            &$authority.to_bytes(),
+        ]
+    };
+}
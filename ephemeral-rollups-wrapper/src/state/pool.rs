@@ -0,0 +1,153 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::pool_seeds_generator;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Pool {
+    pub discriminant: u64,
+    pub authority: Pubkey,
+    pub fee_basis_points: u16,
+    pub min_position_shares: u64,
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    pub total_shares: u64,
+    pub positions: Vec<PoolPosition>,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct PoolPosition {
+    pub provider: Pubkey,
+    pub shares: u64,
+}
+
+impl Pool {
+    pub fn discriminant() -> u64 {
+        0x2E8C47A19B05D36F
+    }
+
+    pub fn max_positions() -> usize {
+        64
+    }
+
+    pub fn basis_points_denominator() -> u16 {
+        10_000
+    }
+
+    pub fn max_fee_basis_points() -> u16 {
+        1_000
+    }
+
+    pub fn space() -> usize {
+        size_of::<u64>()
+            + size_of::<Pubkey>()
+            + size_of::<u16>()
+            + size_of::<u64>()
+            + size_of::<u64>()
+            + size_of::<u64>()
+            + size_of::<u64>()
+            + size_of::<u32>()
+            + Pool::max_positions() * PoolPosition::space()
+    }
+
+    pub fn generate_pda(
+        validator: &Pubkey,
+        token_mint_a: &Pubkey,
+        token_mint_b: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Pubkey {
+        Pubkey::find_program_address(
+            pool_seeds_generator!(validator, token_mint_a, token_mint_b),
+            program_id,
+        )
+        .0
+    }
+
+    pub fn compute_shares_minted(
+        &self,
+        amount_a: u64,
+        amount_b: u64,
+    ) -> Option<u64> {
+        if self.total_shares == 0 {
+            let product = u128::from(amount_a).checked_mul(amount_b.into())?;
+            return u64::try_from(isqrt(product)).ok();
+        }
+        let shares_a = u128::from(amount_a)
+            .checked_mul(self.total_shares.into())?
+            .checked_div(self.reserve_a.into())?;
+        let shares_b = u128::from(amount_b)
+            .checked_mul(self.total_shares.into())?
+            .checked_div(self.reserve_b.into())?;
+        u64::try_from(shares_a.min(shares_b)).ok()
+    }
+
+    pub fn compute_amounts_redeemed(
+        &self,
+        shares: u64,
+    ) -> Option<(u64, u64)> {
+        let amount_a = u128::from(shares)
+            .checked_mul(self.reserve_a.into())?
+            .checked_div(self.total_shares.into())?;
+        let amount_b = u128::from(shares)
+            .checked_mul(self.reserve_b.into())?
+            .checked_div(self.total_shares.into())?;
+        Some((u64::try_from(amount_a).ok()?, u64::try_from(amount_b).ok()?))
+    }
+
+    pub fn compute_amount_out(
+        &self,
+        amount_in: u64,
+        reserve_in: u64,
+        reserve_out: u64,
+    ) -> Option<u64> {
+        let amount_in_after_fee = u128::from(amount_in)
+            .checked_mul(
+                Pool::basis_points_denominator()
+                    .checked_sub(self.fee_basis_points)?
+                    .into(),
+            )?
+            .checked_div(Pool::basis_points_denominator().into())?;
+        let amount_out = u128::from(reserve_out)
+            .checked_mul(amount_in_after_fee)?
+            .checked_div(
+                u128::from(reserve_in).checked_add(amount_in_after_fee)?,
+            )?;
+        u64::try_from(amount_out).ok()
+    }
+}
+
+impl PoolPosition {
+    pub fn space() -> usize {
+        size_of::<Pubkey>() + size_of::<u64>()
+    }
+}
+
+fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut current = value;
+    let mut next = (current + value / current) / 2;
+    while next < current {
+        current = next;
+        next = (current + value / current) / 2;
+    }
+    current
+}
+
+pub const POOL_SEEDS_PREFIX: &[u8] = b"pool";
+
+#[macro_export]
+macro_rules! pool_seeds_generator {
+    ($validator:expr, $token_mint_a:expr, $token_mint_b:expr) => {
+        &[
+            $crate::state::pool::POOL_SEEDS_PREFIX,
+            &$validator.to_bytes(),
+            &$token_mint_a.to_bytes(),
+            &$token_mint_b.to_bytes(),
+        ]
+    };
+}
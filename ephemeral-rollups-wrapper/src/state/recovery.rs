@@ -0,0 +1,101 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::recovery_seeds_generator;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Recovery {
+    pub discriminant: u64,
+    pub threshold: u8,
+    pub guardians: Vec<Pubkey>,
+    pub delay_seconds: i64,
+    pub pending: bool,
+    pub proposed_authority: Pubkey,
+    pub proposed_at: i64,
+}
+
+impl Recovery {
+    pub fn discriminant() -> u64 {
+        0x3E8B5C17D9A2F640
+    }
+
+    pub fn max_guardians() -> usize {
+        11
+    }
+
+    pub fn min_delay_seconds() -> i64 {
+        86_400
+    }
+
+    pub fn space() -> usize {
+        size_of::<u64>()
+            + size_of::<u8>()
+            + size_of::<u32>()
+            + Recovery::max_guardians() * size_of::<Pubkey>()
+            + size_of::<i64>()
+            + size_of::<bool>()
+            + size_of::<Pubkey>()
+            + size_of::<i64>()
+    }
+
+    pub fn generate_pda(
+        authority: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Pubkey {
+        Pubkey::find_program_address(
+            recovery_seeds_generator!(authority),
+            program_id,
+        )
+        .0
+    }
+
+    pub fn is_valid(&self) -> bool {
+        if self.threshold == 0
+            || usize::from(self.threshold) > self.guardians.len()
+            || self.guardians.len() > Recovery::max_guardians()
+            || self.delay_seconds < Recovery::min_delay_seconds()
+        {
+            return false;
+        }
+        for (index, guardian) in self.guardians.iter().enumerate() {
+            if self.guardians[..index].contains(guardian) {
+                return false;
+            }
+        }
+        true
+    }
+
+    pub fn count_approvals(
+        &self,
+        guardians: &[&Pubkey],
+    ) -> usize {
+        self.guardians
+            .iter()
+            .filter(|recovery_guardian| guardians.contains(recovery_guardian))
+            .count()
+    }
+
+    pub fn is_executable(
+        &self,
+        unix_timestamp: i64,
+    ) -> bool {
+        self.pending
+            && unix_timestamp
+                >= self.proposed_at.saturating_add(self.delay_seconds)
+    }
+}
+
+pub const RECOVERY_SEEDS_PREFIX: &[u8] = b"recovery";
+
+#[macro_export]
+macro_rules! recovery_seeds_generator {
+    ($authority:expr) => {
+        &[
+            $crate::state::recovery::RECOVERY_SEEDS_PREFIX,
+            &$authority.to_bytes(),
+        ]
+    };
+}
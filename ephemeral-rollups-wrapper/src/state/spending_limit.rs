@@ -0,0 +1,65 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct SpendingLimit {
+    pub max_per_transaction: u64,
+    pub max_per_window: u64,
+    pub window_seconds: i64,
+    pub window_start: i64,
+    pub window_spent: u64,
+}
+
+impl SpendingLimit {
+    pub fn unlimited() -> SpendingLimit {
+        SpendingLimit {
+            max_per_transaction: 0,
+            max_per_window: 0,
+            window_seconds: 0,
+            window_start: 0,
+            window_spent: 0,
+        }
+    }
+
+    pub fn exceeded_error() -> u32 {
+        0x6C69_6D74
+    }
+
+    pub fn space() -> usize {
+        size_of::<u64>()
+            + size_of::<u64>()
+            + size_of::<i64>()
+            + size_of::<i64>()
+            + size_of::<u64>()
+    }
+
+    // A zero maximum means that no limit is enforced, the window restarts
+    // from the first spend made after the previous window fully elapsed
+    pub fn consume(
+        &mut self,
+        amount: u64,
+        unix_timestamp: i64,
+    ) -> bool {
+        if self.max_per_transaction != 0 && amount > self.max_per_transaction {
+            return false;
+        }
+        if self.max_per_window == 0 {
+            return true;
+        }
+        if unix_timestamp
+            >= self.window_start.saturating_add(self.window_seconds)
+        {
+            self.window_start = unix_timestamp;
+            self.window_spent = 0;
+        }
+        match self.window_spent.checked_add(amount) {
+            Some(window_spent) if window_spent <= self.max_per_window => {
+                self.window_spent = window_spent;
+                true
+            },
+            _ => false,
+        }
+    }
+}
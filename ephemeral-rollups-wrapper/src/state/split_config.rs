@@ -0,0 +1,110 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::split_config_seeds_generator;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct SplitConfig {
+    pub discriminant: u64,
+    pub recipients: Vec<SplitConfigRecipient>,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SplitConfigRecipient {
+    pub destination_authority: Pubkey,
+    pub destination_slot: u64,
+    pub share_basis_points: u16,
+}
+
+impl SplitConfig {
+    pub fn discriminant() -> u64 {
+        0x7B19E4C6A2D05F38
+    }
+
+    pub fn max_recipients() -> usize {
+        16
+    }
+
+    pub fn total_basis_points() -> u16 {
+        10_000
+    }
+
+    pub fn space() -> usize {
+        size_of::<u64>()
+            + size_of::<u32>()
+            + SplitConfig::max_recipients() * SplitConfigRecipient::space()
+    }
+
+    pub fn generate_pda(
+        creator: &Pubkey,
+        slot: u64,
+        program_id: &Pubkey,
+    ) -> Pubkey {
+        Pubkey::find_program_address(
+            split_config_seeds_generator!(creator, slot),
+            program_id,
+        )
+        .0
+    }
+
+    pub fn is_valid(&self) -> bool {
+        if self.recipients.is_empty()
+            || self.recipients.len() > SplitConfig::max_recipients()
+        {
+            return false;
+        }
+        let mut total_basis_points = 0u32;
+        for recipient in &self.recipients {
+            if recipient.share_basis_points == 0 {
+                return false;
+            }
+            total_basis_points += u32::from(recipient.share_basis_points);
+        }
+        total_basis_points == u32::from(SplitConfig::total_basis_points())
+    }
+
+    pub fn compute_amounts(
+        &self,
+        amount: u64,
+    ) -> Option<Vec<u64>> {
+        let mut amounts = self
+            .recipients
+            .iter()
+            .map(|recipient| {
+                let share = u128::from(amount)
+                    .checked_mul(recipient.share_basis_points.into())?
+                    .checked_div(SplitConfig::total_basis_points().into())?;
+                u64::try_from(share).ok()
+            })
+            .collect::<Option<Vec<u64>>>()?;
+        // The rounding remainder always goes to the first recipient
+        let distributed = amounts
+            .iter()
+            .try_fold(0u64, |total, share| total.checked_add(*share))?;
+        let first = amounts.first_mut()?;
+        *first = first.checked_add(amount.checked_sub(distributed)?)?;
+        Some(amounts)
+    }
+}
+
+impl SplitConfigRecipient {
+    pub fn space() -> usize {
+        size_of::<Pubkey>() + size_of::<u64>() + size_of::<u16>()
+    }
+}
+
+pub const SPLIT_CONFIG_SEEDS_PREFIX: &[u8] = b"split_config";
+
+#[macro_export]
+macro_rules! split_config_seeds_generator {
+    ($creator:expr, $slot:expr) => {
+        &[
+            $crate::state::split_config::SPLIT_CONFIG_SEEDS_PREFIX,
+            &$creator.to_bytes(),
+            &$slot.to_le_bytes(),
+        ]
+    };
+}
@@ -4,12 +4,15 @@ use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
 
+use crate::state::spending_limit::SpendingLimit;
 use crate::token_escrow_seeds_generator;
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 pub struct TokenEscrow {
     pub discriminant: u64,
     pub amount: u64,
+    pub spending_limit: SpendingLimit,
+    pub frozen: bool,
 }
 
 impl TokenEscrow {
@@ -17,8 +20,15 @@ impl TokenEscrow {
         0xA48DAD00222D65A5
     }
 
+    pub fn frozen_error() -> u32 {
+        0x6672_7A6E
+    }
+
     pub fn space() -> usize {
-        size_of::<u64>() + size_of::<u64>()
+        size_of::<u64>()
+            + size_of::<u64>()
+            + SpendingLimit::space()
+            + size_of::<bool>()
     }
 
     pub fn generate_pda(
@@ -0,0 +1,73 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::token_fee_seeds_generator;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct TokenFee {
+    pub discriminant: u64,
+    pub fee_basis_points: u16,
+    pub fee_minimum: u64,
+    pub collector_authority: Pubkey,
+    pub collector_slot: u64,
+}
+
+impl TokenFee {
+    pub fn discriminant() -> u64 {
+        0xD2A5174B8E3C60F9
+    }
+
+    pub fn max_fee_basis_points() -> u16 {
+        10_000
+    }
+
+    pub fn space() -> usize {
+        size_of::<u64>()
+            + size_of::<u16>()
+            + size_of::<u64>()
+            + size_of::<Pubkey>()
+            + size_of::<u64>()
+    }
+
+    pub fn generate_pda(
+        validator: &Pubkey,
+        token_mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Pubkey {
+        Pubkey::find_program_address(
+            token_fee_seeds_generator!(validator, token_mint),
+            program_id,
+        )
+        .0
+    }
+
+    pub fn compute_fee(
+        &self,
+        amount: u64,
+    ) -> Option<u64> {
+        let fee = u128::from(amount)
+            .checked_mul(self.fee_basis_points.into())?
+            .checked_div(TokenFee::max_fee_basis_points().into())?;
+        let fee = u64::try_from(fee).ok()?.max(self.fee_minimum);
+        if fee > amount {
+            return None;
+        }
+        Some(fee)
+    }
+}
+
+pub const TOKEN_FEE_SEEDS_PREFIX: &[u8] = b"token_fee";
+
+#[macro_export]
+macro_rules! token_fee_seeds_generator {
+    ($validator:expr, $token_mint:expr) => {
+        &[
+            $crate::state::token_fee::TOKEN_FEE_SEEDS_PREFIX,
+            &$validator.to_bytes(),
+            &$token_mint.to_bytes(),
+        ]
+    };
+}
@@ -0,0 +1,53 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::token_vault_state_seeds_generator;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct TokenVaultState {
+    pub discriminant: u64,
+    pub total_deposited: u64,
+    pub total_withdrawn: u64,
+    pub outstanding_amount: u64,
+}
+
+impl TokenVaultState {
+    pub fn discriminant() -> u64 {
+        0x1F9B3D7E64A2C058
+    }
+
+    pub fn space() -> usize {
+        size_of::<u64>()
+            + size_of::<u64>()
+            + size_of::<u64>()
+            + size_of::<u64>()
+    }
+
+    pub fn generate_pda(
+        validator: &Pubkey,
+        token_mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Pubkey {
+        Pubkey::find_program_address(
+            token_vault_state_seeds_generator!(validator, token_mint),
+            program_id,
+        )
+        .0
+    }
+}
+
+pub const TOKEN_VAULT_STATE_SEEDS_PREFIX: &[u8] = b"token_vault_state";
+
+#[macro_export]
+macro_rules! token_vault_state_seeds_generator {
+    ($validator:expr, $token_mint:expr) => {
+        &[
+            $crate::state::token_vault_state::TOKEN_VAULT_STATE_SEEDS_PREFIX,
+            &$validator.to_bytes(),
+            &$token_mint.to_bytes(),
+        ]
+    };
+}
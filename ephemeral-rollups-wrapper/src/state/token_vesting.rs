@@ -0,0 +1,94 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::token_vesting_seeds_generator;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct TokenVesting {
+    pub discriminant: u64,
+    pub total_amount: u64,
+    pub released_amount: u64,
+    pub start_unix_timestamp: i64,
+    pub cliff_unix_timestamp: i64,
+    pub end_unix_timestamp: i64,
+}
+
+impl TokenVesting {
+    pub fn discriminant() -> u64 {
+        0x8B61F0D23C4E97A5
+    }
+
+    pub fn space() -> usize {
+        size_of::<u64>()
+            + size_of::<u64>()
+            + size_of::<u64>()
+            + size_of::<i64>()
+            + size_of::<i64>()
+            + size_of::<i64>()
+    }
+
+    pub fn generate_pda(
+        authority: &Pubkey,
+        funder: &Pubkey,
+        validator: &Pubkey,
+        token_mint: &Pubkey,
+        slot: u64,
+        program_id: &Pubkey,
+    ) -> Pubkey {
+        Pubkey::find_program_address(
+            token_vesting_seeds_generator!(
+                authority, funder, validator, token_mint, slot
+            ),
+            program_id,
+        )
+        .0
+    }
+
+    pub fn vested_amount(
+        &self,
+        unix_timestamp: i64,
+    ) -> u64 {
+        if unix_timestamp < self.cliff_unix_timestamp {
+            return 0;
+        }
+        if unix_timestamp >= self.end_unix_timestamp {
+            return self.total_amount;
+        }
+        let elapsed = u128::try_from(
+            unix_timestamp.saturating_sub(self.start_unix_timestamp),
+        )
+        .unwrap_or(0);
+        let duration = u128::try_from(
+            self.end_unix_timestamp.saturating_sub(self.start_unix_timestamp),
+        )
+        .unwrap_or(1)
+        .max(1);
+        let vested = u128::from(self.total_amount) * elapsed / duration;
+        u64::try_from(vested).unwrap_or(self.total_amount)
+    }
+}
+
+pub const TOKEN_VESTING_SEEDS_PREFIX: &[u8] = b"token_vesting";
+
+#[macro_export]
+macro_rules! token_vesting_seeds_generator {
+    (
+        $authority:expr,
+        $funder:expr,
+        $validator:expr,
+        $token_mint:expr,
+        $slot:expr
+    ) => {
+        &[
+            $crate::state::token_vesting::TOKEN_VESTING_SEEDS_PREFIX,
+            &$authority.to_bytes(),
+            &$funder.to_bytes(),
+            &$validator.to_bytes(),
+            &$token_mint.to_bytes(),
+            &$slot.to_le_bytes(),
+        ]
+    };
+}
@@ -0,0 +1,78 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::validator_registry_seeds_generator;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct ValidatorRegistry {
+    pub discriminant: u64,
+    pub validators: Vec<ValidatorRegistryEntry>,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct ValidatorRegistryEntry {
+    pub validator: Pubkey,
+    pub label: [u8; 32],
+    pub status: ValidatorStatus,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize,
+)]
+pub enum ValidatorStatus {
+    Active,
+    Retired,
+}
+
+impl ValidatorRegistry {
+    pub fn discriminant() -> u64 {
+        0x3A7C5E19F2B0D864
+    }
+
+    pub fn max_validators() -> usize {
+        64
+    }
+
+    pub fn space() -> usize {
+        size_of::<u64>()
+            + size_of::<u32>()
+            + ValidatorRegistry::max_validators()
+                * ValidatorRegistryEntry::space()
+    }
+
+    pub fn generate_pda(program_id: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            validator_registry_seeds_generator!(),
+            program_id,
+        )
+        .0
+    }
+
+    pub fn is_active(
+        &self,
+        validator: &Pubkey,
+    ) -> bool {
+        self.validators.iter().any(|entry| {
+            entry.validator == *validator
+                && entry.status == ValidatorStatus::Active
+        })
+    }
+}
+
+impl ValidatorRegistryEntry {
+    pub fn space() -> usize {
+        size_of::<Pubkey>() + size_of::<[u8; 32]>() + size_of::<u8>()
+    }
+}
+
+pub const VALIDATOR_REGISTRY_SEEDS_PREFIX: &[u8] = b"validator_registry";
+
+#[macro_export]
+macro_rules! validator_registry_seeds_generator {
+    () => {
+        &[$crate::state::validator_registry::VALIDATOR_REGISTRY_SEEDS_PREFIX]
+    };
+}
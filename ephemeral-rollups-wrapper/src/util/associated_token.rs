@@ -0,0 +1,51 @@
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+// Discriminator of the associated token program's "CreateIdempotent" IX
+const CREATE_IDEMPOTENT_DISCRIMINATOR: u8 = 1;
+
+pub fn get_associated_token_address(
+    wallet: &Pubkey,
+    token_mint: &Pubkey,
+    token_program_id: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            &wallet.to_bytes(),
+            &token_program_id.to_bytes(),
+            &token_mint.to_bytes(),
+        ],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+// Creates the associated token account, succeeding without any change if it
+// already exists for the same wallet and mint
+pub fn create_associated_token_account_idempotent(
+    payer: &Pubkey,
+    wallet: &Pubkey,
+    token_mint: &Pubkey,
+    token_program_id: &Pubkey,
+) -> Instruction {
+    let associated_token_account =
+        get_associated_token_address(wallet, token_mint, token_program_id);
+    Instruction {
+        program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(associated_token_account, false),
+            AccountMeta::new_readonly(*wallet, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(*token_program_id, false),
+        ],
+        data: vec![CREATE_IDEMPOTENT_DISCRIMINATOR],
+    }
+}
@@ -10,5 +10,5 @@ pub fn close_pda<'a, 'info>(
         spill.lamports().checked_add(pda.lamports()).unwrap();
     **pda.lamports.borrow_mut() = 0;
     pda.assign(&system_program::ID);
-    pda.realloc(0, false).map_err(Into::into)
+    pda.realloc(0, false)
 }
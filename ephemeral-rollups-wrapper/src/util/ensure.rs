@@ -1,7 +1,20 @@
+use borsh::BorshDeserialize;
+use ephemeral_rollups_sdk::consts::DELEGATION_PROGRAM_ID;
+use ephemeral_rollups_sdk::pda::delegation_record_pda_from_delegated_account;
 use solana_program::account_info::AccountInfo;
+use solana_program::bpf_loader_upgradeable;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
+use crate::config_seeds_generator;
+use crate::state::config::Config;
+use crate::state::config::ConfigPause;
+use crate::state::multisig::Multisig;
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::validator_registry::ValidatorRegistry;
+use crate::util::token::is_token_program;
+use crate::validator_registry_seeds_generator;
+
 pub fn ensure_is_signer(account: &AccountInfo) -> Result<(), ProgramError> {
     if !account.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
@@ -22,6 +35,18 @@ pub fn ensure_is_program_id(
     Ok(())
 }
 
+pub fn ensure_is_token_program(
+    token_program_id: &AccountInfo
+) -> Result<(), ProgramError> {
+    if !is_token_program(token_program_id.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !token_program_id.executable {
+        return Err(ProgramError::InvalidArgument);
+    }
+    Ok(())
+}
+
 pub fn ensure_is_owned_by_program(
     account: &AccountInfo,
     program_id: &Pubkey,
@@ -43,3 +68,159 @@ pub fn ensure_is_pda(
     }
     Ok(pda.1)
 }
+
+pub fn ensure_is_authority(
+    authority: &AccountInfo,
+    signers: &[AccountInfo],
+    program_id: &Pubkey,
+) -> Result<(), ProgramError> {
+    if authority.is_signer {
+        return Ok(());
+    }
+    if authority.owner.ne(program_id) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let multisig_data =
+        Multisig::deserialize(&mut &authority.data.borrow()[..])?;
+    if multisig_data.discriminant != Multisig::discriminant() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let signers = signers
+        .iter()
+        .filter(|signer| signer.is_signer)
+        .map(|signer| signer.key)
+        .collect::<Vec<_>>();
+    if multisig_data.count_signatures(&signers)
+        < usize::from(multisig_data.threshold)
+    {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
+pub fn ensure_is_unpaused(
+    config_pda: &AccountInfo,
+    config_pause: ConfigPause,
+    program_id: &Pubkey,
+) -> Result<Config, ProgramError> {
+    ensure_is_owned_by_program(config_pda, program_id)?;
+    ensure_is_pda(config_pda, config_seeds_generator!(), program_id)?;
+    let config_data = Config::deserialize(&mut &config_pda.data.borrow()[..])?;
+    if config_data.discriminant != Config::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if config_data.is_paused(config_pause) {
+        return Err(ProgramError::Custom(Config::paused_error()));
+    }
+    Ok(config_data)
+}
+
+pub fn ensure_is_allowed_token_program(
+    token_program_id: &AccountInfo,
+    config_data: &Config,
+) -> Result<(), ProgramError> {
+    ensure_is_token_program(token_program_id)?;
+    if !config_data.allowed_token_programs.contains(token_program_id.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    Ok(())
+}
+
+pub fn ensure_is_admin(
+    admin: &AccountInfo,
+    config_pda: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<Config, ProgramError> {
+    ensure_is_signer(admin)?;
+    ensure_is_owned_by_program(config_pda, program_id)?;
+    ensure_is_pda(config_pda, config_seeds_generator!(), program_id)?;
+    let config_data = Config::deserialize(&mut &config_pda.data.borrow()[..])?;
+    if config_data.discriminant != Config::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if config_data.admin != *admin.key {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(config_data)
+}
+
+// The upgradeable loader's ProgramData starts with a u32 tag (3), the deploy
+// slot and the optional upgrade authority
+pub fn ensure_is_upgrade_authority(
+    authority: &AccountInfo,
+    program_data: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<(), ProgramError> {
+    ensure_is_signer(authority)?;
+    let program_data_key = Pubkey::find_program_address(
+        &[program_id.as_ref()],
+        &bpf_loader_upgradeable::ID,
+    )
+    .0;
+    if program_data.key != &program_data_key {
+        return Err(ProgramError::InvalidArgument);
+    }
+    ensure_is_owned_by_program(program_data, &bpf_loader_upgradeable::ID)?;
+    let data = program_data.data.borrow();
+    if data.get(..4) != Some(&3u32.to_le_bytes()[..]) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if data.get(12) != Some(&1)
+        || data.get(13..45) != Some(authority.key.as_ref())
+    {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
+// The delegation record only exists while the account is delegated, the ER
+// clones it from the chain along with the account itself
+pub fn ensure_is_undelegated(
+    account: &AccountInfo,
+    delegation_record_pda: &AccountInfo,
+) -> Result<(), ProgramError> {
+    if delegation_record_pda.key
+        != &delegation_record_pda_from_delegated_account(account.key)
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if delegation_record_pda.owner == &DELEGATION_PROGRAM_ID
+        || !delegation_record_pda.data_is_empty()
+    {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    Ok(())
+}
+
+pub fn ensure_is_registered_validator(
+    validator_registry_pda: &AccountInfo,
+    validator: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<(), ProgramError> {
+    ensure_is_owned_by_program(validator_registry_pda, program_id)?;
+    ensure_is_pda(
+        validator_registry_pda,
+        validator_registry_seeds_generator!(),
+        program_id,
+    )?;
+    let validator_registry_data = ValidatorRegistry::deserialize(
+        &mut &validator_registry_pda.data.borrow()[..],
+    )?;
+    if validator_registry_data.discriminant != ValidatorRegistry::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if !validator_registry_data.is_active(validator) {
+        return Err(ProgramError::InvalidArgument);
+    }
+    Ok(())
+}
+
+pub fn ensure_is_unfrozen(
+    token_escrow_data: &TokenEscrow,
+) -> Result<(), ProgramError> {
+    if token_escrow_data.frozen {
+        return Err(ProgramError::Custom(TokenEscrow::frozen_error()));
+    }
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::token_escrow::TokenEscrow;
+use crate::state::token_fee::TokenFee;
+use crate::token_escrow_seeds_generator;
+use crate::token_fee_seeds_generator;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+
+pub fn load_token_fee(
+    token_fee_pda: &AccountInfo,
+    fee_collector_token_escrow_pda: &AccountInfo,
+    validator: &Pubkey,
+    token_mint: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Option<TokenFee>, ProgramError> {
+    // Verify the seeds of the fee PDA
+    ensure_is_pda(
+        token_fee_pda,
+        token_fee_seeds_generator!(validator, token_mint),
+        program_id,
+    )?;
+    // No fee schedule was ever set for this mint, transfers are free
+    if token_fee_pda.owner != program_id {
+        return Ok(None);
+    }
+    let token_fee_data =
+        TokenFee::try_from_slice(&token_fee_pda.data.borrow())?;
+    if token_fee_data.discriminant != TokenFee::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    // Verify that the collector escrow is the one from the fee schedule
+    ensure_is_owned_by_program(fee_collector_token_escrow_pda, program_id)?;
+    ensure_is_pda(
+        fee_collector_token_escrow_pda,
+        token_escrow_seeds_generator!(
+            token_fee_data.collector_authority,
+            validator,
+            token_mint,
+            token_fee_data.collector_slot
+        ),
+        program_id,
+    )?;
+    Ok(Some(token_fee_data))
+}
+
+pub fn load_relayer_fee(
+    relayer_token_escrow_pda: &AccountInfo,
+    relayer_token_escrow_seeds: &[&[u8]],
+    relayer_fee: u64,
+    max_relayer_fee: u64,
+    program_id: &Pubkey,
+) -> Result<u64, ProgramError> {
+    // The relayer can't charge more than what the user signed for
+    if relayer_fee > max_relayer_fee {
+        return Err(ProgramError::InvalidArgument);
+    }
+    // The relayer didn't ask for anything, its escrow is left untouched
+    if relayer_fee == 0 {
+        return Ok(0);
+    }
+    // Verify that the relayer escrow holds the same mint
+    ensure_is_owned_by_program(relayer_token_escrow_pda, program_id)?;
+    ensure_is_pda(
+        relayer_token_escrow_pda,
+        relayer_token_escrow_seeds,
+        program_id,
+    )?;
+    Ok(relayer_fee)
+}
+
+pub fn collect_token_fee(
+    fee_collector_token_escrow_pda: &AccountInfo,
+    fee: u64,
+) -> ProgramResult {
+    if fee == 0 {
+        return Ok(());
+    }
+    let mut fee_collector_token_escrow_data = TokenEscrow::try_from_slice(
+        &fee_collector_token_escrow_pda.data.borrow(),
+    )?;
+    if fee_collector_token_escrow_data.discriminant
+        != TokenEscrow::discriminant()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    fee_collector_token_escrow_data.amount =
+        fee_collector_token_escrow_data.amount.checked_add(fee).unwrap();
+    fee_collector_token_escrow_data.serialize(
+        &mut &mut fee_collector_token_escrow_pda
+            .try_borrow_mut_data()?
+            .as_mut(),
+    )?;
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::sysvar::Sysvar;
+
+use crate::state::spending_limit::SpendingLimit;
+
+pub fn consume_spending_limit(
+    spending_limit: &mut SpendingLimit,
+    amount: u64,
+) -> ProgramResult {
+    let unix_timestamp = Clock::get()?.unix_timestamp;
+    if !spending_limit.consume(amount, unix_timestamp) {
+        return Err(ProgramError::Custom(SpendingLimit::exceeded_error()));
+    }
+    Ok(())
+}
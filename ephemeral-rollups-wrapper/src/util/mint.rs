@@ -0,0 +1,121 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::program_error::ProgramError;
+use solana_program::program_option::COption;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use spl_token::state::Account;
+use spl_token::state::Mint;
+
+use crate::util::token::TOKEN_2022_PROGRAM_ID;
+
+// Token-2022 mints with extensions are padded to the size of a token account,
+// followed by an account type byte and the extensions as type-length-value
+const MINT_ACCOUNT_TYPE: u8 = 1;
+const MINT_EXTENSIONS_START: usize = Account::LEN + 1;
+const EXTENSION_HEADER_SIZE: usize = 4;
+const EXTENSION_TYPE_UNINITIALIZED: u16 = 0;
+const EXTENSION_TYPE_PERMANENT_DELEGATE: u16 = 12;
+const EXTENSION_TYPE_METADATA_POINTER: u16 = 18;
+const EXTENSION_TYPE_TOKEN_METADATA: u16 = 19;
+
+// Vaults are plain token accounts moving tokens with plain transfers, so only
+// mint extensions that don't require any account extension nor
+// transfer_checked can be escrowed
+const SUPPORTED_EXTENSION_TYPES: [u16; 3] = [
+    EXTENSION_TYPE_PERMANENT_DELEGATE,
+    EXTENSION_TYPE_METADATA_POINTER,
+    EXTENSION_TYPE_TOKEN_METADATA,
+];
+
+// Both token programs share the same base mint layout, Token-2022 mints may
+// have extensions appended after it
+fn unpack_mint(token_mint: &AccountInfo) -> Result<Mint, ProgramError> {
+    if token_mint.owner != &spl_token::ID
+        && token_mint.owner != &TOKEN_2022_PROGRAM_ID
+    {
+        return Err(ProgramError::IllegalOwner);
+    }
+    let data = token_mint.data.borrow();
+    let mint = Mint::unpack_from_slice(
+        data.get(..Mint::LEN).ok_or(ProgramError::InvalidAccountData)?,
+    )?;
+    if !mint.is_initialized {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    Ok(mint)
+}
+
+pub fn load_freeze_authority(
+    token_mint: &AccountInfo
+) -> Result<COption<Pubkey>, ProgramError> {
+    Ok(unpack_mint(token_mint)?.freeze_authority)
+}
+
+// Only Token-2022 mints can have extensions, returns each (type, value)
+fn unpack_mint_extensions(
+    token_mint: &AccountInfo
+) -> Result<Vec<(u16, Vec<u8>)>, ProgramError> {
+    unpack_mint(token_mint)?;
+    let mut extensions = Vec::new();
+    if token_mint.owner != &TOKEN_2022_PROGRAM_ID {
+        return Ok(extensions);
+    }
+    let data = token_mint.data.borrow();
+    if data.len() <= Mint::LEN {
+        return Ok(extensions);
+    }
+    if data.get(Account::LEN) != Some(&MINT_ACCOUNT_TYPE) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let mut offset = MINT_EXTENSIONS_START;
+    while let Some(header) = data.get(offset..offset + EXTENSION_HEADER_SIZE) {
+        let extension_type = u16::from_le_bytes([header[0], header[1]]);
+        if extension_type == EXTENSION_TYPE_UNINITIALIZED {
+            break;
+        }
+        let extension_size =
+            usize::from(u16::from_le_bytes([header[2], header[3]]));
+        offset += EXTENSION_HEADER_SIZE;
+        let extension = data
+            .get(offset..offset + extension_size)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        extensions.push((extension_type, extension.to_vec()));
+        offset += extension_size;
+    }
+    Ok(extensions)
+}
+
+pub fn ensure_is_supported_mint(
+    token_mint: &AccountInfo
+) -> Result<(), ProgramError> {
+    for (extension_type, _) in unpack_mint_extensions(token_mint)? {
+        if !SUPPORTED_EXTENSION_TYPES.contains(&extension_type) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+    Ok(())
+}
+
+pub fn load_permanent_delegate(
+    token_mint: &AccountInfo
+) -> Result<Option<Pubkey>, ProgramError> {
+    let Some((_, extension)) = unpack_mint_extensions(token_mint)?
+        .into_iter()
+        .find(|(extension_type, _)| {
+            *extension_type == EXTENSION_TYPE_PERMANENT_DELEGATE
+        })
+    else {
+        return Ok(None);
+    };
+    // An all-zero delegate means that it was removed
+    let permanent_delegate = Pubkey::try_from(extension.as_slice())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    if permanent_delegate == Pubkey::default() {
+        return Ok(None);
+    }
+    Ok(Some(permanent_delegate))
+}
+
+pub fn load_decimals(token_mint: &AccountInfo) -> Result<u8, ProgramError> {
+    Ok(unpack_mint(token_mint)?.decimals)
+}
@@ -1,4 +1,13 @@
+pub mod associated_token;
 pub mod close;
 pub mod create;
 pub mod ensure;
+pub mod fee;
+pub mod limit;
+pub mod mint;
+pub mod nonce;
+pub mod realloc;
+pub mod signature;
 pub mod signer;
+pub mod token;
+pub mod transfer;
@@ -0,0 +1,47 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::nonce_account_seeds_generator;
+use crate::state::nonce_account::NonceAccount;
+use crate::util::ensure::ensure_is_owned_by_program;
+use crate::util::ensure::ensure_is_pda;
+
+pub fn consume_nonce(
+    nonce_account_pda: &AccountInfo,
+    authority: &Pubkey,
+    nonce: u64,
+    expiry: i64,
+    program_id: &Pubkey,
+) -> ProgramResult {
+    // Verify that the signed intent is still valid
+    if Clock::get()?.unix_timestamp > expiry {
+        return Err(ProgramError::InvalidArgument);
+    }
+    // Verify that the program has proper control of the nonce PDA (and that
+    // it's been initialized, or delegated alongside the escrows)
+    ensure_is_owned_by_program(nonce_account_pda, program_id)?;
+    ensure_is_pda(
+        nonce_account_pda,
+        nonce_account_seeds_generator!(authority),
+        program_id,
+    )?;
+    let mut nonce_account_data =
+        NonceAccount::try_from_slice(&nonce_account_pda.data.borrow())?;
+    if nonce_account_data.discriminant != NonceAccount::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    // Nonces only ever go up, any nonce below the counter was used or skipped
+    nonce_account_data.nonce = nonce_account_data
+        .next_nonce(nonce)
+        .ok_or(ProgramError::InvalidArgument)?;
+    nonce_account_data.serialize(
+        &mut &mut nonce_account_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+    Ok(())
+}
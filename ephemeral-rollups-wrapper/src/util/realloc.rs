@@ -0,0 +1,28 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke;
+use solana_program::rent::Rent;
+use solana_program::system_instruction::transfer;
+use solana_program::sysvar::Sysvar;
+
+pub fn realloc_pda<'info>(
+    payer: &AccountInfo<'info>,
+    pda: &AccountInfo<'info>,
+    data_len: usize,
+    system_program: &AccountInfo<'info>,
+) -> ProgramResult {
+    // Transfer the lamports for the rent exemption of the added space (the
+    // PDA's existing lamports may be escrowed funds and are left untouched)
+    let rent = Rent::get()?;
+    let rent_exempt_missing_amount = rent
+        .minimum_balance(data_len)
+        .saturating_sub(rent.minimum_balance(pda.data_len()));
+    if rent_exempt_missing_amount.gt(&0) {
+        invoke(
+            &transfer(payer.key, pda.key, rent_exempt_missing_amount),
+            &[payer.clone(), pda.clone(), system_program.clone()],
+        )?;
+    }
+    // Grow the data, the added bytes are zeroed
+    pda.realloc(data_len, true)
+}
@@ -0,0 +1,134 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::ed25519_program;
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::secp256k1_program;
+use solana_program::sysvar::instructions::load_current_index_checked;
+use solana_program::sysvar::instructions::load_instruction_at_checked;
+
+// Layout of the Ed25519 precompile data: a signature count, a padding byte,
+// then for each signature 7 little-endian u16 offsets/indexes
+const ED25519_OFFSETS_START: usize = 2;
+const ED25519_OFFSETS_SIZE: usize = 14;
+
+// Layout of the secp256k1 precompile data: a signature count, then for each
+// signature 11 bytes of little-endian u16 offsets and u8 IX indexes
+const SECP256K1_OFFSETS_START: usize = 1;
+const SECP256K1_OFFSETS_SIZE: usize = 11;
+
+// Prefix that Ethereum wallets add to any message signed with personal_sign
+const EIP191_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n";
+
+// The precompile IX must immediately precede the current IX
+fn load_precompile_instruction(
+    instructions_sysvar: &AccountInfo,
+    precompile_program_id: &Pubkey,
+) -> Result<(u16, Instruction), ProgramError> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    if current_index == 0 {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let precompile_index = current_index - 1;
+    let precompile_instruction = load_instruction_at_checked(
+        usize::from(precompile_index),
+        instructions_sysvar,
+    )?;
+    if precompile_instruction.program_id != *precompile_program_id {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok((precompile_index, precompile_instruction))
+}
+
+pub fn ensure_is_ed25519_signed(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<(), ProgramError> {
+    let (_, ed25519_instruction) =
+        load_precompile_instruction(instructions_sysvar, &ed25519_program::ID)?;
+    // Only a single signature, entirely contained in the precompile IX
+    let data = &ed25519_instruction.data;
+    if data.len() < ED25519_OFFSETS_START + ED25519_OFFSETS_SIZE || data[0] != 1
+    {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let read_u16 = |index: usize| {
+        let offset = ED25519_OFFSETS_START + index * 2;
+        usize::from(u16::from_le_bytes([data[offset], data[offset + 1]]))
+    };
+    let current_instruction = usize::from(u16::MAX);
+    if read_u16(1) != current_instruction
+        || read_u16(3) != current_instruction
+        || read_u16(6) != current_instruction
+    {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    // The verified public key and message must be the expected ones
+    let public_key_offset = read_u16(2);
+    let message_offset = read_u16(4);
+    let message_size = read_u16(5);
+    if data.get(public_key_offset..public_key_offset + 32)
+        != Some(signer.as_ref())
+    {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if data.get(message_offset..message_offset + message_size) != Some(message)
+    {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
+pub fn eip191_message(payload: &[u8]) -> Vec<u8> {
+    let mut message = Vec::new();
+    message.extend_from_slice(EIP191_PREFIX);
+    message.extend_from_slice(payload.len().to_string().as_bytes());
+    message.extend_from_slice(payload);
+    message
+}
+
+pub fn ensure_is_secp256k1_signed(
+    instructions_sysvar: &AccountInfo,
+    eth_address: &[u8; 20],
+    message: &[u8],
+) -> Result<(), ProgramError> {
+    let (secp256k1_index, secp256k1_instruction) = load_precompile_instruction(
+        instructions_sysvar,
+        &secp256k1_program::ID,
+    )?;
+    // Only a single signature, entirely contained in the precompile IX
+    let data = &secp256k1_instruction.data;
+    if data.len() < SECP256K1_OFFSETS_START + SECP256K1_OFFSETS_SIZE
+        || data[0] != 1
+    {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let read_u8 = |offset: usize| data[SECP256K1_OFFSETS_START + offset];
+    let read_u16 = |offset: usize| {
+        let offset = SECP256K1_OFFSETS_START + offset;
+        usize::from(u16::from_le_bytes([data[offset], data[offset + 1]]))
+    };
+    let current_instruction = u8::try_from(secp256k1_index)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    if read_u8(2) != current_instruction
+        || read_u8(5) != current_instruction
+        || read_u8(10) != current_instruction
+    {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    // The recovered address and the message must be the expected ones
+    let eth_address_offset = read_u16(3);
+    let message_offset = read_u16(6);
+    let message_size = read_u16(8);
+    if data.get(eth_address_offset..eth_address_offset + 20)
+        != Some(eth_address.as_slice())
+    {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if data.get(message_offset..message_offset + message_size) != Some(message)
+    {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
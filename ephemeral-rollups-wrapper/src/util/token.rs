@@ -0,0 +1,141 @@
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::IsInitialized;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey;
+use solana_program::pubkey::Pubkey;
+use spl_token::instruction::TokenInstruction;
+use spl_token::state::Account;
+
+pub const TOKEN_2022_PROGRAM_ID: Pubkey =
+    pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+// Token vaults can be owned by either token program, Token-2022 accepts the
+// same IX layouts as the original token program for the IX built below (but
+// spl_token's builders refuse any other program ID)
+pub fn is_token_program(token_program_id: &Pubkey) -> bool {
+    token_program_id == &spl_token::ID
+        || token_program_id == &TOKEN_2022_PROGRAM_ID
+}
+
+// Both token programs share the same base token account layout, Token-2022
+// accounts may have extensions appended after it
+pub fn unpack_token_account(data: &[u8]) -> Result<Account, ProgramError> {
+    let token_account = Account::unpack_from_slice(
+        data.get(..Account::LEN).ok_or(ProgramError::InvalidAccountData)?,
+    )?;
+    if !token_account.is_initialized() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    Ok(token_account)
+}
+
+pub fn initialize_account3(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    token_mint: &Pubkey,
+    owner: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *token_program_id,
+        accounts: vec![
+            AccountMeta::new(*account, false),
+            AccountMeta::new_readonly(*token_mint, false),
+        ],
+        data: TokenInstruction::InitializeAccount3 { owner: *owner }.pack(),
+    }
+}
+
+pub fn approve(
+    token_program_id: &Pubkey,
+    source: &Pubkey,
+    delegate: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    Instruction {
+        program_id: *token_program_id,
+        accounts: vec![
+            AccountMeta::new(*source, false),
+            AccountMeta::new_readonly(*delegate, false),
+            AccountMeta::new_readonly(*owner, true),
+        ],
+        data: TokenInstruction::Approve { amount }.pack(),
+    }
+}
+
+pub fn transfer(
+    token_program_id: &Pubkey,
+    source: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    Instruction {
+        program_id: *token_program_id,
+        accounts: vec![
+            AccountMeta::new(*source, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: TokenInstruction::Transfer { amount }.pack(),
+    }
+}
+
+pub fn transfer_checked(
+    token_program_id: &Pubkey,
+    source: &Pubkey,
+    token_mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+    decimals: u8,
+) -> Instruction {
+    Instruction {
+        program_id: *token_program_id,
+        accounts: vec![
+            AccountMeta::new(*source, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: TokenInstruction::TransferChecked { amount, decimals }.pack(),
+    }
+}
+
+pub fn mint_to(
+    token_program_id: &Pubkey,
+    token_mint: &Pubkey,
+    account: &Pubkey,
+    mint_authority: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    Instruction {
+        program_id: *token_program_id,
+        accounts: vec![
+            AccountMeta::new(*token_mint, false),
+            AccountMeta::new(*account, false),
+            AccountMeta::new_readonly(*mint_authority, true),
+        ],
+        data: TokenInstruction::MintTo { amount }.pack(),
+    }
+}
+
+pub fn burn(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    token_mint: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    Instruction {
+        program_id: *token_program_id,
+        accounts: vec![
+            AccountMeta::new(*account, false),
+            AccountMeta::new(*token_mint, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: TokenInstruction::Burn { amount }.pack(),
+    }
+}
@@ -0,0 +1,93 @@
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::program_error::ProgramError;
+
+use crate::state::token_escrow::TokenEscrow;
+use crate::util::ensure::ensure_is_unfrozen;
+use crate::util::fee::collect_token_fee;
+use crate::util::limit::consume_spending_limit;
+
+// Take an amount out of an escrow whose PDA was verified by the caller (fail
+// if frozen, over its spending limit or not enough amount available), returns
+// the updated amount
+pub fn debit_token_escrow(
+    token_escrow_pda: &AccountInfo,
+    amount: u64,
+) -> Result<u64, ProgramError> {
+    let mut token_escrow_data =
+        TokenEscrow::try_from_slice(&token_escrow_pda.data.borrow())?;
+    if token_escrow_data.discriminant != TokenEscrow::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    // Verify that the issuer didn't freeze the escrow
+    ensure_is_unfrozen(&token_escrow_data)?;
+    // Verify that the escrow's spending limit allows this amount to leave
+    consume_spending_limit(&mut token_escrow_data.spending_limit, amount)?;
+    token_escrow_data.amount = token_escrow_data
+        .amount
+        .checked_sub(amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    token_escrow_data.serialize(
+        &mut &mut token_escrow_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+    Ok(token_escrow_data.amount)
+}
+
+// Add an amount to an escrow whose PDA was verified by the caller, returns
+// the updated amount
+pub fn credit_token_escrow(
+    token_escrow_pda: &AccountInfo,
+    amount: u64,
+) -> Result<u64, ProgramError> {
+    let mut token_escrow_data =
+        TokenEscrow::try_from_slice(&token_escrow_pda.data.borrow())?;
+    if token_escrow_data.discriminant != TokenEscrow::discriminant() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    token_escrow_data.amount = token_escrow_data
+        .amount
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    token_escrow_data.serialize(
+        &mut &mut token_escrow_pda.try_borrow_mut_data()?.as_mut(),
+    )?;
+    Ok(token_escrow_data.amount)
+}
+
+// Move an amount between two escrows whose PDAs were verified by the caller,
+// splitting the fee off to the collector, and debiting the relayer's
+// compensation on top of the amount if any, returns the updated amounts
+pub fn transfer_token_escrow_amount(
+    source_token_escrow_pda: &AccountInfo,
+    destination_token_escrow_pda: &AccountInfo,
+    fee_collector_token_escrow_pda: &AccountInfo,
+    relayer_credit: Option<(&AccountInfo, u64)>,
+    amount: u64,
+    fee: u64,
+) -> Result<(u64, u64), ProgramError> {
+    let relayer_fee = relayer_credit.map_or(0, |(_, relayer_fee)| relayer_fee);
+    let debited_amount = amount
+        .checked_add(relayer_fee)
+        .ok_or(ProgramError::InsufficientFunds)?;
+
+    // Update the source escrow amount (fail if not enough amount available)
+    let source_amount =
+        debit_token_escrow(source_token_escrow_pda, debited_amount)?;
+
+    // Update the destination escrow amount (if everything else suceeded)
+    let destination_amount = credit_token_escrow(
+        destination_token_escrow_pda,
+        amount.checked_sub(fee).ok_or(ProgramError::InsufficientFunds)?,
+    )?;
+
+    // Update the collector escrow amount with the fee that was split off
+    collect_token_fee(fee_collector_token_escrow_pda, fee)?;
+
+    // Update the relayer escrow amount with its compensation
+    if let Some((relayer_token_escrow_pda, relayer_fee)) = relayer_credit {
+        collect_token_fee(relayer_token_escrow_pda, relayer_fee)?;
+    }
+
+    Ok((source_amount, destination_amount))
+}